@@ -11,6 +11,9 @@ const NC_VERSION: &str = "3.0.9";
 fn main() {
     let nc_src = csource::NcCSource::new(NC_VERSION);
 
+    // make the targeted version available to the crate
+    println!("cargo:rustc-env=LIBNOTCURSES_SYS_NC_VERSION={}", NC_VERSION);
+
     // vendor the C source code?
     if cfg!(feature = "vendor_csource") {
         nc_src.vendor_csource();
@@ -68,14 +68,7 @@ mod core_impls {
 
     impl From<c_api::NcAlpha_u32> for NcAlpha {
         fn from(alpha: c_api::NcAlpha_u32) -> Self {
-            use {c_api::*, NcAlpha::*};
-            match alpha {
-                NCALPHA_BLEND => Blend,
-                NCALPHA_HIGHCONTRAST => HighContrast,
-                NCALPHA_OPAQUE => Opaque,
-                NCALPHA_TRANSPARENT => Transparent,
-                _ => Self::default(),
-            }
+            Self::from_bits(alpha)
         }
     }
 
@@ -92,6 +85,45 @@ mod core_impls {
     }
 }
 
+/// # Conversions
+impl NcAlpha {
+    /// The number of bits the alpha is shifted to the left inside an
+    /// [`NcChannel`][crate::NcChannel].
+    pub const SHIFT: u32 = c_api::NCALPHA_SHIFT;
+
+    /// Returns the alpha from its unshifted bits, as found in an
+    /// [`NcChannel`][crate::NcChannel].
+    ///
+    /// The context dependent bits are ignored.
+    pub const fn from_bits(bits: c_api::NcAlpha_u32) -> Self {
+        Self::from_shifted(((bits & c_api::NCALPHA_MASK) >> Self::SHIFT) as u8)
+    }
+
+    /// Returns the unshifted bits of the alpha, ready to be combined
+    /// with an [`NcChannel`][crate::NcChannel].
+    pub const fn bits(&self) -> c_api::NcAlpha_u32 {
+        *self as c_api::NcAlpha_u32
+    }
+
+    /// Returns the alpha from its 2 bits shifted down to the LSBs.
+    ///
+    /// Only the 2 least significant bits are considered.
+    pub const fn from_shifted(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => Self::Opaque,
+            0b01 => Self::Blend,
+            0b10 => Self::Transparent,
+            _ => Self::HighContrast,
+        }
+    }
+
+    /// Returns the 2 bits of the alpha shifted down to the LSBs.
+    pub const fn shifted(&self) -> u8 {
+        (self.bits() >> Self::SHIFT) as u8
+    }
+}
+
+/// # Methods
 impl NcAlpha {
     /// Displays the short name identifier of the alpha value.
     pub fn display_short(&self) -> &str {
@@ -131,6 +163,13 @@ pub(crate) mod c_api {
     /// `type in C: no data type`
     pub type NcAlpha_u32 = u32;
 
+    /// The number of bits the [`NcAlpha_u32`] is shifted to the left inside an
+    /// [`NcChannel_u32`][crate::c_api::NcChannel_u32].
+    pub const NCALPHA_SHIFT: u32 = 28;
+
+    /// The mask of all the [`NcAlpha_u32`] bits, unshifted.
+    pub const NCALPHA_MASK: NcAlpha_u32 = ffi::NC_BG_ALPHA_MASK;
+
     /// [`NcAlpha_u32`] bits indicating [`NcCell`]'s foreground or background color
     /// will be a composite between its color and the `NcCell`s' corresponding
     /// colors underneath it.
@@ -33,6 +33,7 @@ impl NcBlitter {
     /// The inner Cell's dimensions `(height, width)` using the current blitter.
     ///
     /// Default & Pixel returns `None`.
+    #[doc(alias = "cell_ratio")]
     pub const fn cell_size(&self) -> Option<(u8, u8)> {
         use NcBlitter::*;
         match self {
@@ -46,6 +47,20 @@ impl NcBlitter {
             _ => None,
         }
     }
+
+    /// Returns `true` if the blitter needs UTF-8 support in the terminal.
+    ///
+    /// Default returns `false`, since it's chosen depending on the support.
+    pub const fn requires_utf8(&self) -> bool {
+        use NcBlitter::*;
+        matches![self, Half | Quadrant | Sextant | Braille | _4x1 | _8x1]
+    }
+
+    /// Returns `true` if the blitter needs bitmap graphics support in the
+    /// terminal.
+    pub const fn requires_pixel(&self) -> bool {
+        matches![self, NcBlitter::Pixel]
+    }
 }
 
 impl NcBlitter {
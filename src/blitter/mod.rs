@@ -90,7 +90,14 @@ impl NcBlitter {
 
 mod core_impls {
     use super::{c_api, NcBlitter};
-    use core::fmt;
+    use crate::{
+        names::{lex, NcNames},
+        NcError,
+    };
+    use core::{fmt, str::FromStr};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
 
     impl Default for NcBlitter {
         fn default() -> Self {
@@ -119,6 +126,32 @@ mod core_impls {
         }
     }
 
+    /// The names an [`NcBlitter`] can be parsed from.
+    const NAMES: &NcNames<NcBlitter> = &[
+        (NcBlitter::Default, &["default"]),
+        (NcBlitter::Ascii, &["ascii", "1x1"]),
+        (NcBlitter::Half, &["half", "2x1"]),
+        (NcBlitter::Quadrant, &["quadrant", "quad", "2x2"]),
+        (NcBlitter::Sextant, &["sextant", "sex", "3x2"]),
+        (NcBlitter::Braille, &["braille", "4x2"]),
+        (NcBlitter::Pixel, &["pixel"]),
+        (NcBlitter::_4x1, &["4x1"]),
+        (NcBlitter::_8x1, &["8x1"]),
+    ];
+
+    impl FromStr for NcBlitter {
+        type Err = NcError;
+
+        /// Parses the name of a blitter, case-insensitively.
+        ///
+        /// Accepts the names used by [`Display`][fmt::Display], the ones
+        /// used by notcurses (e.g. `"quad"`, `"sex"`) and the `rows`x`cols`
+        /// of the inner cell (e.g. `"2x2"`).
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            lex(NAMES, s).ok_or_else(|| NcError::new_msg(&format!["NcBlitter::from_str({:?})", s]))
+        }
+    }
+
     impl fmt::Debug for NcBlitter {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             write!(f, "NcBlitter::{}", self)
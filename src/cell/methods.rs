@@ -196,7 +196,7 @@ impl NcCell {
     ///
     /// *C style function: [nccell_bg_palindex()][c_api::nccell_bg_palindex].*
     pub fn bg_palindex(&self) -> NcPaletteIndex {
-        c_api::nccell_bg_palindex(self)
+        c_api::nccell_bg_palindex(self).into()
     }
 
     /// Is the background [`NcChannel`] using an [`NcPaletteIndex`] indexed
@@ -232,7 +232,7 @@ impl NcCell {
     ///
     /// *C style function: [nccell_fg_palindex()][c_api::nccell_fg_palindex].*
     pub fn fg_palindex(&self) -> NcPaletteIndex {
-        c_api::nccell_fg_palindex(self)
+        c_api::nccell_fg_palindex(self).into()
     }
 
     /// Is the foreground [`NcChannel`] using an [`NcPaletteIndex`] indexed
@@ -253,8 +253,8 @@ impl NcCell {
     /// Sets the background [`NcAlpha`].
     ///
     /// *C style function: [nccell_set_bg_alpha()][c_api::nccell_set_bg_alpha].*
-    pub fn set_bg_alpha(&mut self, alpha: impl Into<NcAlpha>) {
-        c_api::nccell_set_bg_alpha(self, alpha.into());
+    pub fn set_bg_alpha(&mut self, alpha: NcAlpha) {
+        c_api::nccell_set_bg_alpha(self, alpha);
     }
 
     /// Indicates to use the "default color" for the background [`NcChannel`].
@@ -287,8 +287,8 @@ impl NcCell {
     /// Sets the foreground [`NcAlpha`].
     ///
     /// *C style function: [nccell_set_fg_alpha()][c_api::nccell_set_fg_alpha].*
-    pub fn set_fg_alpha(&mut self, alpha: impl Into<NcAlpha>) {
-        c_api::nccell_set_fg_alpha(self, alpha.into());
+    pub fn set_fg_alpha(&mut self, alpha: NcAlpha) {
+        c_api::nccell_set_fg_alpha(self, alpha);
     }
 
     /// Indicates to use the "default color" for the foreground [`NcChannel`].
@@ -334,6 +334,19 @@ impl NcCell {
         c_api::nccellcmp(plane1, cell1, plane2, cell2)
     }
 
+    /// Returns true if both cells have the same attributes and [`NcChannel`]s,
+    /// and canonically equivalent `EGC`s under Unicode NFC normalization.
+    ///
+    /// Unlike [`compare`][NcCell#method.compare], `EGC`s that are visually
+    /// identical but composed differently are considered equal.
+    ///
+    /// *C style function: [nccellcmp_normalized()][c_api::nccellcmp_normalized].*
+    #[cfg(feature = "unicode-normalization")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode-normalization")))]
+    pub fn eq_unicode(&self, plane: &NcPlane, other: &NcCell, other_plane: &NcPlane) -> bool {
+        !c_api::nccellcmp_normalized(plane, self, other_plane, other)
+    }
+
     /// Saves the [`NcStyle`] and the [`NcChannels`], and returns the duplicatd `EGC`.
     ///
     /// *C style function: [nccell_fg_alpha()][c_api::nccell_fg_alpha].*
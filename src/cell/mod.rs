@@ -11,11 +11,11 @@
 //W# nccell_release
 //   ncstrwidth_valid
 //
-// functions manually reimplemented: 50
+// functions manually reimplemented: 51
 // ------------------------------------------
 // (X) wont:  2
-// (+) done: 48
-// (W) wrap: 44
+// (+) done: 49
+// (W) wrap: 45
 // (#) test: 30
 // ------------------------------------------
 //W# nccell_bg_alpha
@@ -61,6 +61,7 @@
 //W+ nccell_wide_left_p
 //W+ nccell_wide_right_p
 //W+ nccellcmp
+//W+ nccellcmp_normalized         // not in the original C API
 //W+ nccells_ascii_box
 //W+ nccells_double_box
 //W+ nccells_rounded_box
@@ -4,10 +4,10 @@
 
 use crate::{
     c_api::{
-        self, nccell_release, NcAlpha_u32, NcChannel_u32, NcChannels_u64, NcResult_i32, NcRgb_u32,
-        NcStyle_u16,
+        self, nccell_release, NcAlpha_u32, NcChannel_u32, NcChannels_u64, NcPaletteIndex_u8,
+        NcResult_i32, NcRgb_u32, NcStyle_u16,
     },
-    cstring, NcCell, NcPaletteIndex, NcPlane,
+    cstring, NcCell, NcPlane,
 };
 
 #[cfg(feature = "libc")]
@@ -239,7 +239,7 @@ pub fn nccell_bg_default_p(cell: &NcCell) -> bool {
 // Palette ---------------------------------------------------------------------
 
 /// Is the foreground [`NcChannel_u32`] of this [`NcCell`] using an
-/// [`NcPaletteIndex`] indexed [`NcPalette`][crate::NcPalette] color?
+/// [`NcPaletteIndex_u8`] indexed [`NcPalette`][crate::NcPalette] color?
 ///
 /// *Method: NcCell.[fg_palindex_p()][NcCell#method.fg_palindex_p].*
 #[inline]
@@ -248,7 +248,7 @@ pub fn nccell_fg_palindex_p(cell: &NcCell) -> bool {
 }
 
 /// Is the background [`NcChannel_u32`] of this [`NcCell`] using an
-/// [`NcPaletteIndex`] indexed [`NcPalette`][crate::NcPalette] color?
+/// [`NcPaletteIndex_u8`] indexed [`NcPalette`][crate::NcPalette] color?
 ///
 /// *Method: NcCell.[bg_palindex_p()][NcCell#method.bg_palindex_p].*
 #[inline]
@@ -256,25 +256,25 @@ pub fn nccell_bg_palindex_p(cell: &NcCell) -> bool {
     c_api::ncchannels_bg_palindex_p(cell.channels)
 }
 
-/// Gets the [`NcPaletteIndex`] of the foreground [`NcChannel_u32`] of the [`NcCell`].
+/// Gets the [`NcPaletteIndex_u8`] of the foreground [`NcChannel_u32`] of the [`NcCell`].
 ///
 /// *Method: NcCell.[fg_palindex()][NcCell#method.fg_palindex].*
 #[inline]
 #[allow(clippy::unnecessary_cast)]
-pub const fn nccell_fg_palindex(cell: &NcCell) -> NcPaletteIndex {
-    ((cell.channels & 0xff00000000 as NcChannels_u64) >> 32) as NcPaletteIndex
+pub const fn nccell_fg_palindex(cell: &NcCell) -> NcPaletteIndex_u8 {
+    ((cell.channels & 0xff00000000 as NcChannels_u64) >> 32) as NcPaletteIndex_u8
 }
 
-/// Gets the [`NcPaletteIndex`] of the background [`NcChannel_u32`] of the [`NcCell`].
+/// Gets the [`NcPaletteIndex_u8`] of the background [`NcChannel_u32`] of the [`NcCell`].
 ///
 /// *Method: NcCell.[bg_palindex()][NcCell#method.bg_palindex].*
 #[inline]
 #[allow(clippy::unnecessary_cast)]
-pub const fn nccell_bg_palindex(cell: &NcCell) -> NcPaletteIndex {
-    (cell.channels & 0xff) as NcPaletteIndex
+pub const fn nccell_bg_palindex(cell: &NcCell) -> NcPaletteIndex_u8 {
+    (cell.channels & 0xff) as NcPaletteIndex_u8
 }
 
-/// Sets an [`NcCell`]'s foreground [`NcPaletteIndex`].
+/// Sets an [`NcCell`]'s foreground [`NcPaletteIndex_u8`].
 ///
 /// Also sets [`NcChannels_u64::FG_PALETTE`] and [`NCALPHA_OPAQUE`],
 /// and clears out [`NcChannels_u64::FG_DEFAULT_MASK`].
@@ -287,11 +287,11 @@ pub const fn nccell_bg_palindex(cell: &NcCell) -> NcPaletteIndex {
 ///
 /// *Method: NcCell.[set_fg_palindex()][NcCell#method.set_fg_palindex].*
 #[inline]
-pub fn nccell_set_fg_palindex(cell: &mut NcCell, index: impl Into<NcPaletteIndex>) {
+pub fn nccell_set_fg_palindex(cell: &mut NcCell, index: impl Into<NcPaletteIndex_u8>) {
     c_api::ncchannels_set_fg_palindex(&mut cell.channels, index.into())
 }
 
-/// Sets an [`NcCell`]'s background [`NcPaletteIndex`].
+/// Sets an [`NcCell`]'s background [`NcPaletteIndex_u8`].
 ///
 /// Also sets [`NcChannels_u64::BG_PALETTE`] and [`NCALPHA_OPAQUE`],
 /// and clears out [`NcChannels_u64::BG_DEFAULT_MASK`].
@@ -304,7 +304,7 @@ pub fn nccell_set_fg_palindex(cell: &mut NcCell, index: impl Into<NcPaletteIndex
 ///
 /// *Method: NcCell.[set_bg_palindex()][NcCell#method.set_bg_palindex].*
 #[inline]
-pub fn nccell_set_bg_palindex(cell: &mut NcCell, index: impl Into<NcPaletteIndex>) {
+pub fn nccell_set_bg_palindex(cell: &mut NcCell, index: impl Into<NcPaletteIndex_u8>) {
     c_api::ncchannels_set_bg_palindex(&mut cell.channels, index.into())
 }
 
@@ -441,6 +441,46 @@ pub fn nccellcmp(plane1: &NcPlane, cell1: &NcCell, plane2: &NcPlane, cell2: &NcC
     }
 }
 
+/// Returns true if the two cells have distinct `EGC`s, attributes,
+/// or [`NcChannel_u32`]s, comparing the `EGC`s under Unicode NFC normalization.
+///
+/// Unlike [`nccellcmp`], `EGC`s that are canonically equivalent but composed
+/// differently (e.g. `"é"` as a single codepoint, and as an `"e"` followed by
+/// a combining acute accent) are considered equal.
+///
+/// *Method: NcCell.[eq_unicode()][NcCell#method.eq_unicode].*
+//
+// Not in the C API
+#[cfg(feature = "unicode-normalization")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode-normalization")))]
+pub fn nccellcmp_normalized(
+    plane1: &NcPlane,
+    cell1: &NcCell,
+    plane2: &NcPlane,
+    cell2: &NcCell,
+) -> bool {
+    use core::ffi::CStr;
+    use unicode_normalization::UnicodeNormalization;
+
+    if cell1.stylemask != cell2.stylemask {
+        return true;
+    }
+    if cell1.channels != cell2.channels {
+        return true;
+    }
+    let (egc1, egc2) = unsafe {
+        (
+            CStr::from_ptr(c_api::nccell_extended_gcluster(plane1, cell1)).to_bytes(),
+            CStr::from_ptr(c_api::nccell_extended_gcluster(plane2, cell2)).to_bytes(),
+        )
+    };
+    match (core::str::from_utf8(egc1), core::str::from_utf8(egc2)) {
+        (Ok(egc1), Ok(egc2)) => !egc1.nfc().eq(egc2.nfc()),
+        // invalid UTF-8 can only be compared bitwise
+        _ => egc1 != egc2,
+    }
+}
+
 /// Initializes (zeroes out) an [`NcCell`].
 ///
 /// *Method: NcCell.[init()][NcCell#method.init].*
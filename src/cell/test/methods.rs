@@ -71,3 +71,27 @@ fn constructors() -> crate::NcResult<()> {
     unsafe { nc.stop()? };
     Ok(())
 }
+
+#[test]
+#[serial]
+#[cfg(feature = "unicode-normalization")]
+fn eq_unicode() -> crate::NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 10, 10)?;
+
+    // precomposed "é" vs. "e" + combining acute accent
+    let mut c1 = NcCell::from_str(plane, "\u{e9}")?;
+    let mut c2 = NcCell::from_str(plane, "e\u{301}")?;
+    assert![NcCell::compare(plane, &c1, plane, &c2)];
+    assert![c1.eq_unicode(plane, &c2, plane)];
+
+    // a different style makes them differ
+    c2.styles_set(NcStyle::Bold);
+    assert![!c1.eq_unicode(plane, &c2, plane)];
+
+    c1.release(plane);
+    c2.release(plane);
+    plane.destroy()?;
+    unsafe { nc.stop()? };
+    Ok(())
+}
@@ -162,8 +162,8 @@ impl NcChannel {
     /// Sets the [`NcAlpha`].
     ///
     /// *C style function: [ncchannel_set_alpha()][c_api::ncchannel_set_alpha].*
-    pub fn set_alpha(&mut self, alpha: impl Into<NcAlpha>) -> Self {
-        c_api::ncchannel_set_alpha(&mut self.0, alpha.into());
+    pub fn set_alpha(&mut self, alpha: NcAlpha) -> Self {
+        c_api::ncchannel_set_alpha(&mut self.0, alpha);
         *self
     }
 
@@ -294,6 +294,38 @@ impl NcChannel {
         c_api::ncchannel_set_not_default(&mut self.0).into()
     }
 
+    // Blending
+
+    /// Linearly interpolates the RGB components between this channel and
+    /// `other`, with `t` clamped to `0.0..=1.0`.
+    ///
+    /// *C style function: [ncchannel_lerp()][c_api::ncchannel_lerp].*
+    //
+    // Not in the C API
+    pub fn lerp(&self, other: impl Into<NcChannel>, t: f32) -> NcChannel {
+        c_api::ncchannel_lerp(self.0, other.into().0, t).into()
+    }
+
+    /// Composites the `over` channel on top of this one, the way the renderer
+    /// does, incrementing `blends` unless `over` is transparent.
+    ///
+    /// *C style function: [ncchannel_blend()][c_api::ncchannel_blend].*
+    //
+    // Not in the C API
+    pub fn blend(&self, over: impl Into<NcChannel>, blends: &mut u32) -> NcChannel {
+        c_api::ncchannel_blend(self.0, over.into().0, blends).into()
+    }
+
+    /// Returns the white or black [`NcRgb`] that best contrasts with
+    /// this channel used as a background.
+    ///
+    /// *C style function: [ncchannel_highcontrast()][c_api::ncchannel_highcontrast].*
+    //
+    // Not in the C API
+    pub fn highcontrast(&self) -> NcRgb {
+        c_api::ncchannel_highcontrast(self.0).into()
+    }
+
     // NcPaletteIndex
 
     /// Extracts the [`NcPaletteIndex`] from the [`NcChannel`].
@@ -303,7 +335,7 @@ impl NcChannel {
     ///
     /// *C style function: [ncchannel_palindex()][c_api::ncchannel_palindex].*
     pub fn palindex(&self) -> NcPaletteIndex {
-        c_api::ncchannel_palindex(self.0)
+        c_api::ncchannel_palindex(self.0).into()
     }
 
     /// Is this NcChannel using palette-indexed color rather a than RGB?
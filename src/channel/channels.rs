@@ -5,6 +5,9 @@ use crate::{
     error, NcAlpha, NcChannel, NcPaletteIndex, NcResult, NcRgb,
 };
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 /// 64 bits containing a foreground and background [`NcChannel`]
 ///
 /// At render time, both 24-bit [`NcRgb`] values are quantized down to terminal
@@ -194,9 +197,9 @@ impl NcChannels {
     /// foreground and background channels.
     pub fn from_rgb_alpha(
         fg_rgb: impl Into<NcRgb>,
-        fg_alpha: impl Into<NcAlpha>,
+        fg_alpha: NcAlpha,
         bg_rgb: impl Into<NcRgb>,
-        bg_alpha: impl Into<NcAlpha>,
+        bg_alpha: NcAlpha,
     ) -> Self {
         Self::combine(
             NcChannel::from_rgb(fg_rgb).set_alpha(fg_alpha),
@@ -206,8 +209,8 @@ impl NcChannels {
 
     /// New `NcChannels`, expects [`NcRgb`] & [`NcAlpha`] for both
     /// channels.
-    pub fn from_rgb_alpha_both(rgb: impl Into<NcRgb>, alpha: impl Into<NcAlpha>) -> Self {
-        let channel = NcChannel::new().set(rgb.into()).set_alpha(alpha.into());
+    pub fn from_rgb_alpha_both(rgb: impl Into<NcRgb>, alpha: NcAlpha) -> Self {
+        let channel = NcChannel::new().set(rgb.into()).set_alpha(alpha);
         Self::combine(channel, channel)
     }
 
@@ -237,6 +240,31 @@ impl NcChannels {
         *self = c_api::ncchannels_reverse(self.0).into();
         *self
     }
+
+    // Interpolate
+
+    /// Linearly interpolates both the foreground and background RGB components
+    /// between these channels and `other`, with `t` clamped to `0.0..=1.0`.
+    ///
+    /// *C style function: [ncchannels_lerp()][c_api::ncchannels_lerp].*
+    //
+    // Not in the C API
+    pub fn lerp(&self, other: impl Into<NcChannels>, t: f32) -> NcChannels {
+        c_api::ncchannels_lerp(self.0, other.into().0, t).into()
+    }
+
+    /// Returns `steps` `NcChannels` evenly interpolated from these channels
+    /// to `to`, both included.
+    ///
+    /// *C style function: [ncchannels_gradient_steps()][c_api::ncchannels_gradient_steps].*
+    //
+    // Not in the C API
+    pub fn gradient_steps(&self, to: impl Into<NcChannels>, steps: u32) -> Vec<NcChannels> {
+        c_api::ncchannels_gradient_steps(self.0, to.into().0, steps)
+            .into_iter()
+            .map(NcChannels::from)
+            .collect()
+    }
 }
 
 /// # NcChannels methods
@@ -305,15 +333,15 @@ impl NcChannels {
     /// Sets the foreground [`NcAlpha`].
     ///
     /// *C style function: [ncchannels_set_fg_alpha()][c_api::ncchannels_set_fg_alpha].*
-    pub fn set_fg_alpha(&mut self, alpha: impl Into<NcAlpha>) -> NcResult<()> {
-        error![c_api::ncchannels_set_fg_alpha(&mut self.0, alpha.into())]
+    pub fn set_fg_alpha(&mut self, alpha: NcAlpha) -> NcResult<()> {
+        error![c_api::ncchannels_set_fg_alpha(&mut self.0, alpha)]
     }
 
     /// Sets the background [`NcAlpha`].
     ///
     /// *C style function: [ncchannels_set_bg_alpha()][c_api::ncchannels_set_bg_alpha].*
-    pub fn set_bg_alpha(&mut self, alpha: impl Into<NcAlpha>) -> NcResult<()> {
-        error![c_api::ncchannels_set_bg_alpha(&mut self.0, alpha.into())]
+    pub fn set_bg_alpha(&mut self, alpha: NcAlpha) -> NcResult<()> {
+        error![c_api::ncchannels_set_bg_alpha(&mut self.0, alpha)]
     }
 
     // NcRgb
@@ -532,14 +560,14 @@ impl NcChannels {
     ///
     /// *C style function: [channels_fg_palindex()][c_api::ncchannels_fg_palindex].*
     pub fn fg_palindex(&self) -> NcPaletteIndex {
-        c_api::ncchannels_fg_palindex(self.0)
+        c_api::ncchannels_fg_palindex(self.0).into()
     }
 
     /// Gets the [`NcPaletteIndex`] from the background [`NcChannel`].
     ///
     /// *C style function: [channels_bg_palindex()][c_api::ncchannels_bg_palindex].*
     pub fn bg_palindex(&self) -> NcPaletteIndex {
-        c_api::ncchannels_bg_palindex(self.0)
+        c_api::ncchannels_bg_palindex(self.0).into()
     }
 
     /// Is the foreground of using an [*indexed*][NcPaletteIndex]
@@ -13,17 +13,20 @@
 //   - `channels_set_bg_rgb8_clipped()`
 // -----------------------------------------------------------------------------
 //
-// functions manually reimplemented: 46
+// functions manually reimplemented: 51
 // ------------------------------------------
 // (X) wont:  9
-// (+) done: 39
-// (#) test: 21
-// (W) wrap: 37
+// (+) done: 44
+// (#) test: 24
+// (W) wrap: 42
 // ------------------------------------------
 //W# channel_alpha
 //W# channel_b
+//W# channel_blend                   // not in the original C API
 //W# channel_default_p
 //W# channel_g
+//W+ channel_highcontrast            // not in the original C API
+//W# channel_lerp                    // not in the original C API
 //W# channel_palindex_p
 //W# channel_r
 // # channel_rgb8                    // unneeded method
@@ -47,6 +50,8 @@
 //W# channels_fg_palindex_p
 //W+ channels_fg_rgb
 // + channels_fg_rgb8                // unneeded method
+//W# channels_gradient_steps         // not in the original C API
+//W+ channels_lerp                   // not in the original C API
 //W# channels_set_bchannel
 //W+ channels_set_bg_alpha
 //W+ channels_set_bg_default
@@ -1,10 +1,12 @@
 //! `ncchannel*_*` reimplemented functions.
 
-use crate::{
-    c_api::{self, NcAlpha_u32, NcChannel_u32, NcChannels_u64, NcResult_i32, NcRgb_u32},
-    NcPaletteIndex,
+use crate::c_api::{
+    self, NcAlpha_u32, NcChannel_u32, NcChannels_u64, NcPaletteIndex_u8, NcResult_i32, NcRgb_u32,
 };
 
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
 #[allow(unused_imports)]
 use crate::{NcChannel, NcChannels};
 
@@ -597,23 +599,23 @@ pub fn ncchannels_set_not_default(channels: &mut NcChannels_u64) -> NcChannels_u
 
 // Palette ---------------------------------------------------------------------
 
-/// Gets the [`NcPaletteIndex`] from the [`NcChannel_u32`].
+/// Gets the [`NcPaletteIndex_u8`] from the [`NcChannel_u32`].
 ///
 /// The channel must be palette-indexed, or the return value is meaningless.
 /// Verify palette indexing with [`ncchannel_palindex_p`].
 ///
 /// *Method: NcChannel.[palindex()][NcChannel#method.palindex]*
-pub fn ncchannel_palindex(channel: impl Into<NcChannel_u32>) -> NcPaletteIndex {
-    (channel.into() & 0xFF) as NcPaletteIndex
+pub fn ncchannel_palindex(channel: impl Into<NcChannel_u32>) -> NcPaletteIndex_u8 {
+    (channel.into() & 0xFF) as NcPaletteIndex_u8
 }
 
-/// Sets the [`NcPaletteIndex`] of the [`NcChannel_u32`], and the channel into
+/// Sets the [`NcPaletteIndex_u8`] of the [`NcChannel_u32`], and the channel into
 /// palette indexed mode.
 ///
 /// Note: Unlike the original C function, this one can't fail.
 ///
 /// *Method: NcChannel.[set_palindex()][NcChannel#method.set_palindex]*
-pub fn ncchannel_set_palindex(channel: &mut NcChannel_u32, index: impl Into<NcPaletteIndex>) {
+pub fn ncchannel_set_palindex(channel: &mut NcChannel_u32, index: impl Into<NcPaletteIndex_u8>) {
     ncchannel_set_alpha(channel, c_api::NCALPHA_OPAQUE);
     *channel &= 0xFF000000;
     *channel |= c_api::NC_BGDEFAULT_MASK | c_api::NC_BG_PALETTE | index.into() as NcChannel_u32;
@@ -628,23 +630,23 @@ pub fn ncchannel_palindex_p(channel: impl Into<NcChannel_u32>) -> bool {
     !ncchannel_default_p(channel) && (channel & c_api::NC_BG_PALETTE) != 0
 }
 
-/// Gets the [`NcPaletteIndex`] from the foreground [`NcChannel_u32`].
+/// Gets the [`NcPaletteIndex_u8`] from the foreground [`NcChannel_u32`].
 ///
 /// *Method: NcChannels.[fg_palindex()][NcChannels#method.fg_palindex]*
 #[inline]
-pub fn ncchannels_fg_palindex(channels: impl Into<NcChannels_u64>) -> NcPaletteIndex {
+pub fn ncchannels_fg_palindex(channels: impl Into<NcChannels_u64>) -> NcPaletteIndex_u8 {
     ncchannel_palindex(ncchannels_fchannel(channels.into()))
 }
 
-/// Gets the [`NcPaletteIndex`] from the background [`NcChannel_u32`].
+/// Gets the [`NcPaletteIndex_u8`] from the background [`NcChannel_u32`].
 ///
 /// *Method: NcChannels.[bg_palindex()][NcChannels#method.bg_palindex]*
 #[inline]
-pub fn ncchannels_bg_palindex(channels: impl Into<NcChannels_u64>) -> NcPaletteIndex {
+pub fn ncchannels_bg_palindex(channels: impl Into<NcChannels_u64>) -> NcPaletteIndex_u8 {
     ncchannel_palindex(ncchannels_bchannel(channels.into()))
 }
 
-/// Is the foreground of an [`NcChannels_u64`] using an [indexed][`NcPaletteIndex`]
+/// Is the foreground of an [`NcChannels_u64`] using an [indexed][`NcPaletteIndex_u8`]
 /// [`NcPalette`][crate::NcPalette] color?
 ///
 /// *Method: NcChannels.[fg_palindex_p()][NcChannels#method.fg_palindex_p]*
@@ -653,7 +655,7 @@ pub fn ncchannels_fg_palindex_p(channels: impl Into<NcChannels_u64>) -> bool {
     ncchannel_palindex_p(ncchannels_fchannel(channels.into()))
 }
 
-/// Is the background of an [`NcChannels_u64`] using an [indexed][`NcPaletteIndex`]
+/// Is the background of an [`NcChannels_u64`] using an [indexed][`NcPaletteIndex_u8`]
 /// [`NcPalette`][crate::NcPalette] color?
 ///
 /// *Method: NcChannels.[bg_palindex_p()][NcChannels#method.bg_palindex_p]*
@@ -663,28 +665,181 @@ pub fn ncchannels_bg_palindex_p(channels: impl Into<NcChannels_u64>) -> bool {
 }
 
 /// Sets the foreground of an [`NcChannels_u64`] as using an
-/// [*indexed*][`NcPaletteIndex`] [`NcPalette`][crate::NcPalette] color.
+/// [*indexed*][`NcPaletteIndex_u8`] [`NcPalette`][crate::NcPalette] color.
 ///
 /// Note: Unlike the original C function, this one can't fail.
 ///
 /// *Method: NcChannels.[set_fg_palindex()][NcChannels#method.set_fg_palindex]*
 #[inline]
 #[allow(clippy::unnecessary_cast)]
-pub fn ncchannels_set_fg_palindex(channels: &mut NcChannels_u64, index: impl Into<NcPaletteIndex>) {
+pub fn ncchannels_set_fg_palindex(
+    channels: &mut NcChannels_u64,
+    index: impl Into<NcPaletteIndex_u8>,
+) {
     let mut channel = ncchannels_fchannel(*channels);
     ncchannel_set_palindex(&mut channel, index.into());
     *channels = (channel as NcChannels_u64) << 32 | (*channels & 0xffffffff as NcChannels_u64)
 }
 
 /// Sets the background of an [`NcChannels_u64`] as using an
-/// [*indexed*][`NcPaletteIndex`] [`NcPalette`][crate::NcPalette] color.
+/// [*indexed*][`NcPaletteIndex_u8`] [`NcPalette`][crate::NcPalette] color.
 ///
 /// Note: Unlike the original C function, this one can't fail.
 ///
 /// *Method: NcChannels.[set_bg_palindex()][NcChannels#method.set_bg_palindex]*
 #[inline]
-pub fn ncchannels_set_bg_palindex(channels: &mut NcChannels_u64, index: impl Into<NcPaletteIndex>) {
+pub fn ncchannels_set_bg_palindex(
+    channels: &mut NcChannels_u64,
+    index: impl Into<NcPaletteIndex_u8>,
+) {
     let mut channel = ncchannels_bchannel(*channels);
     ncchannel_set_palindex(&mut channel, index.into());
     ncchannels_set_bchannel(channels, channel);
 }
+
+// Blending --------------------------------------------------------------------
+
+/// Linearly interpolates the RGB components of two [`NcChannel_u32`]s.
+///
+/// `t` is clamped to the `0.0..=1.0` range, where `0.0` returns the RGB of
+/// `a` and `1.0` the RGB of `b`. The result is marked as NOT using the
+/// "default color", and retains the alpha of `a`.
+///
+/// Both channels should be RGB, or the return value is meaningless.
+///
+/// *Method: NcChannel.[lerp()][NcChannel#method.lerp]*
+//
+// Not in the C API
+#[inline]
+pub fn ncchannel_lerp(
+    a: impl Into<NcChannel_u32>,
+    b: impl Into<NcChannel_u32>,
+    t: f32,
+) -> NcChannel_u32 {
+    let (a, b) = (a.into(), b.into());
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let mix = |c0: u8, c1: u8| (c0 as f32 + (c1 as f32 - c0 as f32) * t + 0.5) as u8;
+
+    let mut ret = a;
+    ncchannel_set_rgb8(
+        &mut ret,
+        mix(ncchannel_r(a), ncchannel_r(b)),
+        mix(ncchannel_g(a), ncchannel_g(b)),
+        mix(ncchannel_b(a), ncchannel_b(b)),
+    );
+    ret
+}
+
+/// Linearly interpolates both the foreground and background RGB components
+/// of two [`NcChannels_u64`].
+///
+/// See [`ncchannel_lerp`] for the details.
+///
+/// *Method: NcChannels.[lerp()][NcChannels#method.lerp]*
+//
+// Not in the C API
+#[inline]
+pub fn ncchannels_lerp(
+    a: impl Into<NcChannels_u64>,
+    b: impl Into<NcChannels_u64>,
+    t: f32,
+) -> NcChannels_u64 {
+    let (a, b) = (a.into(), b.into());
+    let mut ret = a;
+    ncchannels_set_fchannel(
+        &mut ret,
+        ncchannel_lerp(ncchannels_fchannel(a), ncchannels_fchannel(b), t),
+    );
+    ncchannels_set_bchannel(
+        &mut ret,
+        ncchannel_lerp(ncchannels_bchannel(a), ncchannels_bchannel(b), t),
+    );
+    ret
+}
+
+/// Returns `steps` [`NcChannels_u64`] evenly interpolated from `from` to `to`,
+/// both included.
+///
+/// Returns an empty `Vec` if `steps` is 0, and just `from` if it is 1.
+///
+/// *Method: NcChannels.[gradient_steps()][NcChannels#method.gradient_steps]*
+//
+// Not in the C API
+pub fn ncchannels_gradient_steps(
+    from: impl Into<NcChannels_u64>,
+    to: impl Into<NcChannels_u64>,
+    steps: u32,
+) -> Vec<NcChannels_u64> {
+    let (from, to) = (from.into(), to.into());
+    match steps {
+        0 => Vec::new(),
+        1 => vec![from],
+        _ => (0..steps)
+            .map(|step| ncchannels_lerp(from, to, step as f32 / (steps - 1) as f32))
+            .collect(),
+    }
+}
+
+/// Composites the `over` [`NcChannel_u32`] on top of the `under` one,
+/// the same way the renderer does when descending through the planes.
+///
+/// `blends` is the number of channels already averaged into `under`,
+/// and it's incremented unless `over` is `NCALPHA_TRANSPARENT`, in which
+/// case `under` is returned unchanged.
+///
+/// - When no channels have been blended yet, the color of `over`
+///   (default, palette-indexed or RGB) is copied.
+/// - Otherwise, if neither channel uses the "default color", the RGB
+///   components are averaged, weighted by the number of previous blends.
+///
+/// The resulting channel takes the alpha of `over`.
+///
+/// *Method: NcChannel.[blend()][NcChannel#method.blend]*
+//
+// Not in the C API
+pub fn ncchannel_blend(
+    under: impl Into<NcChannel_u32>,
+    over: impl Into<NcChannel_u32>,
+    blends: &mut u32,
+) -> NcChannel_u32 {
+    let (mut c1, c2) = (under.into(), over.into());
+    if ncchannel_alpha(c2) == c_api::NCALPHA_TRANSPARENT {
+        return c1;
+    }
+    if *blends == 0 {
+        if ncchannel_default_p(c2) {
+            ncchannel_set_default(&mut c1);
+        } else if ncchannel_palindex_p(c2) {
+            ncchannel_set_palindex(&mut c1, ncchannel_palindex(c2));
+        } else {
+            ncchannel_set(&mut c1, ncchannel_rgb(c2));
+        }
+    } else if !ncchannel_default_p(c2) && !ncchannel_default_p(c1) {
+        let avg = |c1: u8, c2: u8| ((c1 as u32 * *blends + c2 as u32) / (*blends + 1)) as u8;
+        let r = avg(ncchannel_r(c1), ncchannel_r(c2));
+        let g = avg(ncchannel_g(c1), ncchannel_g(c2));
+        let b = avg(ncchannel_b(c1), ncchannel_b(c2));
+        ncchannel_set_rgb8(&mut c1, r, g, b);
+    }
+    ncchannel_set_alpha(&mut c1, ncchannel_alpha(c2));
+    *blends += 1;
+    c1
+}
+
+/// Returns the white or black [`NcRgb_u32`] that best contrasts with the
+/// RGB of the `bchannel`, as used for `NCALPHA_HIGHCONTRAST` foregrounds.
+///
+/// *Method: NcChannel.[highcontrast()][NcChannel#method.highcontrast]*
+//
+// Not in the C API
+#[inline]
+pub fn ncchannel_highcontrast(bchannel: impl Into<NcChannel_u32>) -> NcRgb_u32 {
+    let bchannel = bchannel.into();
+    let sum =
+        ncchannel_r(bchannel) as u32 + ncchannel_g(bchannel) as u32 + ncchannel_b(bchannel) as u32;
+    if sum < 320 {
+        0xffffff
+    } else {
+        0
+    }
+}
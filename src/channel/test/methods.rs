@@ -1,10 +1,41 @@
 //! Test `NcChannel*` methods and associated functions.
 
-// use crate::{NcChannel, NcChannels};
-//
-// use serial_test::serial;
-//
-// #[test]
-// #[serial]
-// fn () {
-// }
+use crate::{c_api, NcAlpha, NcChannel, NcChannels};
+
+use serial_test::serial;
+
+/// converts between the alpha and its bit representations
+#[test]
+#[serial]
+fn alpha_bits() {
+    for alpha in [
+        NcAlpha::Opaque,
+        NcAlpha::Blend,
+        NcAlpha::Transparent,
+        NcAlpha::HighContrast,
+    ] {
+        assert_eq!(alpha, NcAlpha::from_bits(alpha.bits()));
+        assert_eq!(alpha, NcAlpha::from_shifted(alpha.shifted()));
+    }
+    assert_eq!(0b10, NcAlpha::Transparent.shifted());
+    assert_eq!(c_api::NCALPHA_BLEND, NcAlpha::Blend.bits());
+
+    // the context dependent bits are ignored
+    assert_eq!(NcAlpha::Blend, NcAlpha::from_bits(0x_50_112233));
+}
+
+/// sets the alpha of a channel and channels
+#[test]
+#[serial]
+fn channels_set_alpha() {
+    let c = NcChannel::from_rgb(0x112233).set_alpha(NcAlpha::Blend);
+    assert_eq!(NcAlpha::Blend, c.alpha());
+
+    let mut cs = NcChannels::from_rgb(0x112233, 0x445566);
+    assert![cs.set_fg_alpha(NcAlpha::HighContrast).is_ok()];
+    assert_eq!(NcAlpha::HighContrast, cs.fg_alpha());
+
+    // the background can't be high-contrast
+    assert![cs.set_bg_alpha(NcAlpha::HighContrast).is_err()];
+    assert_eq!(NcAlpha::Opaque, cs.bg_alpha());
+}
@@ -226,3 +226,59 @@ fn channels_palette() {
     assert_eq!(true, c_api::ncchannels_fg_palindex_p(channels));
     assert_eq!(true, c_api::ncchannels_bg_palindex_p(channels));
 }
+
+// Blending tests -----------------------------------------------------------
+
+/// interpolates the RGB components of two channels
+#[test]
+#[serial]
+fn channel_lerp() {
+    let a: NcChannel_u32 = 0x_40_000000;
+    let b: NcChannel_u32 = 0x_40_ff8040;
+    assert_eq!(0x_40_000000, c_api::ncchannel_lerp(a, b, 0.0));
+    assert_eq!(0x_40_ff8040, c_api::ncchannel_lerp(a, b, 1.0));
+    assert_eq!(0x_40_804020, c_api::ncchannel_lerp(a, b, 0.5));
+    // t is clamped
+    assert_eq!(0x_40_ff8040, c_api::ncchannel_lerp(a, b, 2.0));
+}
+
+/// computes the steps of a gradient between two channels pairs
+#[test]
+#[serial]
+fn channels_gradient_steps() {
+    let from = c_api::ncchannels_combine(0x_40_000000_u32, 0x_40_ffffff_u32);
+    let to = c_api::ncchannels_combine(0x_40_ffffff_u32, 0x_40_000000_u32);
+
+    assert!(c_api::ncchannels_gradient_steps(from, to, 0).is_empty());
+    assert_eq!(&[from], &c_api::ncchannels_gradient_steps(from, to, 1)[..]);
+
+    let steps = c_api::ncchannels_gradient_steps(from, to, 3);
+    assert_eq!(3, steps.len());
+    assert_eq!(from, steps[0]);
+    assert_eq!(0x808080, c_api::ncchannels_fg_rgb(steps[1]));
+    assert_eq!(0x808080, c_api::ncchannels_bg_rgb(steps[1]));
+    assert_eq!(to, steps[2]);
+}
+
+/// composites channels the way the renderer does
+#[test]
+#[serial]
+fn channel_blend() {
+    let mut blends = 0;
+    let mut c: NcChannel_u32 = 0;
+
+    // the first blend copies the color
+    c = c_api::ncchannel_blend(c, 0x_40_ff0000_u32, &mut blends);
+    assert_eq!(1, blends);
+    assert_eq!(0xff0000, c_api::ncchannel_rgb(c));
+
+    // transparent channels are skipped
+    let transparent = 0x_40_00ff00 | c_api::NCALPHA_TRANSPARENT;
+    assert_eq!(c, c_api::ncchannel_blend(c, transparent, &mut blends));
+    assert_eq!(1, blends);
+
+    // the next ones are averaged
+    c = c_api::ncchannel_blend(c, 0x_40_0000ff_u32, &mut blends);
+    assert_eq!(2, blends);
+    assert_eq!(0x7f007f, c_api::ncchannel_rgb(c));
+}
@@ -0,0 +1,252 @@
+//! `NcDirectBuilder`
+
+use crate::{c_api, cstring, error_ref_mut, NcDirect, NcDirectFlag, NcResult};
+use core::ptr::{null, null_mut};
+
+/// Builder object for [`NcDirect`].
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let ncd = unsafe {
+///     NcDirect::builder()
+///         .inhibit_cbreak(true)
+///         .drain_input(true)
+///         .start()?
+/// };
+/// ncd.putstr(0, "hello\n")?;
+/// unsafe { ncd.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NcDirectBuilder<'a> {
+    pub(crate) termtype: Option<&'a str>,
+    pub(crate) flags: NcDirectFlag,
+}
+
+mod core_impls {
+    use super::{NcDirectBuilder, NcDirectFlag};
+
+    impl<'a> From<NcDirectFlag> for NcDirectBuilder<'a> {
+        fn from(flags: NcDirectFlag) -> NcDirectBuilder<'a> {
+            Self::from_flags(flags)
+        }
+    }
+    impl<'a> From<NcDirectBuilder<'a>> for NcDirectFlag {
+        fn from(builder: NcDirectBuilder) -> NcDirectFlag {
+            builder.flags
+        }
+    }
+}
+
+/// # constructors
+impl<'a> NcDirectBuilder<'a> {
+    /// New `NcDirectBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New builder from pre-existing flags.
+    pub fn from_flags(flags: impl Into<NcDirectFlag>) -> Self {
+        Self { termtype: None, flags: flags.into() }
+    }
+
+    /// Finishes the building and returns a new [`NcDirect`].
+    ///
+    /// # Safety
+    /// You must not create multiple `NcDirect` instances at the same time, on
+    /// the same thread. You must [`stop`][NcDirect#method.stop] the current one
+    /// before creating a new one.
+    ///
+    /// *C style function: [ncdirect_init()][c_api::ncdirect_init].*
+    pub unsafe fn start<'nc>(self) -> NcResult<&'nc mut NcDirect> {
+        let res = match self.termtype {
+            Some(termtype) => {
+                let cs = cstring![termtype];
+                c_api::ncdirect_init(cs.as_ptr(), null_mut(), self.flags.into())
+            }
+            None => c_api::ncdirect_init(null(), null_mut(), self.flags.into()),
+        };
+        error_ref_mut![res, "NcDirectBuilder.start()"]
+    }
+}
+
+/// # methods (chainable)
+impl<'a> NcDirectBuilder<'a> {
+    /// Sets the TERM type, instead of getting it from the environment.
+    pub fn term_type(mut self, term_type: &'a str) -> Self {
+        self.termtype = Some(term_type);
+        self
+    }
+
+    // flags
+
+    /// If `true`, Input may be freely dropped.
+    ///
+    /// This ought be provided when the program does not intend to handle input.
+    /// Otherwise, input can accumulate in internal buffers, eventually preventing
+    /// Notcurses from processing terminal messages.
+    ///
+    /// See `NcDirectFlag::`[`DrainInput`][NcDirectFlag#associatedconstant.DrainInput].
+    pub fn drain_input(mut self, drain: bool) -> Self {
+        self.set_drain_input(drain);
+        self
+    }
+
+    /// If `true`, wont place the terminal into cbreak mode.
+    ///
+    /// See `NcDirectFlag::`[`InhibitCbreak`][NcDirectFlag#associatedconstant.InhibitCbreak].
+    pub fn inhibit_cbreak(mut self, inhibit: bool) -> Self {
+        self.set_inhibit_cbreak(inhibit);
+        self
+    }
+
+    /// If `true`, wont call setlocale().
+    ///
+    /// See `NcDirectFlag::`[`InhibitSetLocale`][NcDirectFlag#associatedconstant.InhibitSetLocale].
+    pub fn inhibit_set_locale(mut self, inhibit: bool) -> Self {
+        self.set_inhibit_set_locale(inhibit);
+        self
+    }
+
+    /// If `true`, wont handle `SIGINT`, `SIGSEGV`, `SIGABRT` nor `SIGQUIT`.
+    ///
+    /// See `NcDirectFlag::`[`NoQuitSigHandlers`][NcDirectFlag#associatedconstant.NoQuitSigHandlers].
+    pub fn no_quit_sig_handlers(mut self, no_quit: bool) -> Self {
+        self.set_no_quit_sig_handlers(no_quit);
+        self
+    }
+
+    /// If `true`, will show detailed information.
+    ///
+    /// See `NcDirectFlag::`[`Verbose`][NcDirectFlag#associatedconstant.Verbose].
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.set_verbose(verbose);
+        self
+    }
+
+    /// If `true`, will show all diagnostics.
+    ///
+    /// See `NcDirectFlag::`[`VeryVerbose`][NcDirectFlag#associatedconstant.VeryVerbose].
+    pub fn very_verbose(mut self, very_verbose: bool) -> Self {
+        self.set_very_verbose(very_verbose);
+        self
+    }
+}
+
+/// # methods (settable)
+impl<'a> NcDirectBuilder<'a> {
+    /// Sets the TERM type, instead of getting it from the environment.
+    pub fn set_term_type(&mut self, term_type: &'a str) {
+        self.termtype = Some(term_type);
+    }
+
+    // flags
+
+    /// If `true`, Input may be freely dropped.
+    ///
+    /// See `NcDirectFlag::`[`DrainInput`][NcDirectFlag#associatedconstant.DrainInput].
+    pub fn set_drain_input(&mut self, drain: bool) {
+        self.set_flag(NcDirectFlag::DrainInput, drain);
+    }
+
+    /// If `true`, wont place the terminal into cbreak mode.
+    ///
+    /// See `NcDirectFlag::`[`InhibitCbreak`][NcDirectFlag#associatedconstant.InhibitCbreak].
+    pub fn set_inhibit_cbreak(&mut self, inhibit: bool) {
+        self.set_flag(NcDirectFlag::InhibitCbreak, inhibit);
+    }
+
+    /// If `true`, wont call setlocale().
+    ///
+    /// See `NcDirectFlag::`[`InhibitSetLocale`][NcDirectFlag#associatedconstant.InhibitSetLocale].
+    pub fn set_inhibit_set_locale(&mut self, inhibit: bool) {
+        self.set_flag(NcDirectFlag::InhibitSetLocale, inhibit);
+    }
+
+    /// If `true`, wont handle `SIGINT`, `SIGSEGV`, `SIGABRT` nor `SIGQUIT`.
+    ///
+    /// See `NcDirectFlag::`[`NoQuitSigHandlers`][NcDirectFlag#associatedconstant.NoQuitSigHandlers].
+    pub fn set_no_quit_sig_handlers(&mut self, no_quit: bool) {
+        self.set_flag(NcDirectFlag::NoQuitSigHandlers, no_quit);
+    }
+
+    /// If `true`, will show detailed information.
+    ///
+    /// See `NcDirectFlag::`[`Verbose`][NcDirectFlag#associatedconstant.Verbose].
+    pub fn set_verbose(&mut self, verbose: bool) {
+        self.set_flag(NcDirectFlag::Verbose, verbose);
+    }
+
+    /// If `true`, will show all diagnostics.
+    ///
+    /// See `NcDirectFlag::`[`VeryVerbose`][NcDirectFlag#associatedconstant.VeryVerbose].
+    pub fn set_very_verbose(&mut self, very_verbose: bool) {
+        self.set_flag(NcDirectFlag::VeryVerbose, very_verbose);
+    }
+
+    fn set_flag(&mut self, flag: NcDirectFlag, set: bool) {
+        if set {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+}
+
+/// # query methods
+impl<'a> NcDirectBuilder<'a> {
+    /// Returns the TERM type, if it has been set.
+    pub fn get_term_type(&self) -> Option<&'a str> {
+        self.termtype
+    }
+
+    /// Returns the flags.
+    pub fn get_flags(&self) -> NcDirectFlag {
+        self.flags
+    }
+
+    /// Returns `true` if it has the [`DrainInput`] flag set.
+    ///
+    /// [`DrainInput`]: NcDirectFlag#associatedconstant.DrainInput
+    pub fn is_drain_input(&self) -> bool {
+        self.flags & NcDirectFlag::DrainInput != NcDirectFlag::None
+    }
+
+    /// Returns `true` if it has the [`InhibitCbreak`] flag set.
+    ///
+    /// [`InhibitCbreak`]: NcDirectFlag#associatedconstant.InhibitCbreak
+    pub fn is_inhibit_cbreak(&self) -> bool {
+        self.flags & NcDirectFlag::InhibitCbreak != NcDirectFlag::None
+    }
+
+    /// Returns `true` if it has the [`InhibitSetLocale`] flag set.
+    ///
+    /// [`InhibitSetLocale`]: NcDirectFlag#associatedconstant.InhibitSetLocale
+    pub fn is_inhibit_set_locale(&self) -> bool {
+        self.flags & NcDirectFlag::InhibitSetLocale != NcDirectFlag::None
+    }
+
+    /// Returns `true` if it has the [`NoQuitSigHandlers`] flag set.
+    ///
+    /// [`NoQuitSigHandlers`]: NcDirectFlag#associatedconstant.NoQuitSigHandlers
+    pub fn is_no_quit_sig_handlers(&self) -> bool {
+        self.flags & NcDirectFlag::NoQuitSigHandlers != NcDirectFlag::None
+    }
+
+    /// Returns `true` if it has the [`Verbose`] flag set.
+    ///
+    /// [`Verbose`]: NcDirectFlag#associatedconstant.Verbose
+    pub fn is_verbose(&self) -> bool {
+        self.flags & NcDirectFlag::Verbose != NcDirectFlag::None
+    }
+
+    /// Returns `true` if it has the [`VeryVerbose`] flag set.
+    ///
+    /// [`VeryVerbose`]: NcDirectFlag#associatedconstant.VeryVerbose
+    pub fn is_very_verbose(&self) -> bool {
+        self.flags & NcDirectFlag::VeryVerbose != NcDirectFlag::None
+    }
+}
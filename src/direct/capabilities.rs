@@ -0,0 +1,113 @@
+//! `NcDirectCapabilities`
+
+use crate::{c_api, NcDirect};
+
+/// The capabilities of the terminal of an [`NcDirect`].
+///
+/// Created by [`NcDirect.direct_capabilities`][NcDirect#method.direct_capabilities].
+///
+/// Its `Display` implementation summarizes it in a line, marking each
+/// capability with a `+` if supported or a `-` if not, e.g.:
+/// `256 colors rgb+ ccc- fade+ utf8+ half+ quad+ sex- braille+ pixel- img+ vid-`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NcDirectCapabilities {
+    /// The size of the palette for indexed colors.
+    pub colors: u32,
+    /// Can we directly specify RGB values?
+    pub truecolor: bool,
+    /// Can we set the "hardware" palette?
+    pub change_colors: bool,
+    /// Can we fade?
+    pub fade: bool,
+    /// Is our encoding UTF-8?
+    pub utf8: bool,
+    /// Can we reliably use Unicode halfblocks?
+    pub halfblocks: bool,
+    /// Can we reliably use Unicode quadrants?
+    pub quadrants: bool,
+    /// Can we reliably use Unicode sextants?
+    pub sextants: bool,
+    /// Can we reliably use Unicode braille?
+    pub braille: bool,
+    /// Is there support for pixel graphics?
+    pub pixel: bool,
+    /// Can we load images?
+    pub images: bool,
+    /// Can we load videos?
+    pub videos: bool,
+}
+
+/// # Constructors
+impl NcDirectCapabilities {
+    /// Returns the capabilities of the terminal of `ncd`.
+    ///
+    /// Checking the pixel support requires querying the terminal, so this must
+    /// not be called concurrently with either input or rasterization.
+    pub fn from_direct(ncd: &NcDirect) -> Self {
+        Self {
+            colors: c_api::ncdirect_capabilities(ncd).colors,
+            truecolor: c_api::ncdirect_cantruecolor(ncd),
+            change_colors: c_api::ncdirect_canchangecolor(ncd),
+            fade: c_api::ncdirect_canfade(ncd),
+            utf8: ncd.canutf8(),
+            halfblocks: c_api::ncdirect_canhalfblock(ncd),
+            quadrants: c_api::ncdirect_canquadrant(ncd),
+            sextants: c_api::ncdirect_cansextant(ncd),
+            braille: c_api::ncdirect_canbraille(ncd),
+            pixel: ncd.check_pixel_support().unwrap_or(false),
+            images: c_api::ncdirect_canopen_images(ncd),
+            videos: c_api::ncdirect_canopen_videos(ncd),
+        }
+    }
+}
+
+mod core_impls {
+    use super::NcDirectCapabilities;
+    use core::fmt;
+
+    impl fmt::Display for NcDirectCapabilities {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let sign = |supported: bool| if supported { '+' } else { '-' };
+            write!(f, "{} colors", self.colors)?;
+            for (name, supported) in [
+                ("rgb", self.truecolor),
+                ("ccc", self.change_colors),
+                ("fade", self.fade),
+                ("utf8", self.utf8),
+                ("half", self.halfblocks),
+                ("quad", self.quadrants),
+                ("sex", self.sextants),
+                ("braille", self.braille),
+                ("pixel", self.pixel),
+                ("img", self.images),
+                ("vid", self.videos),
+            ] {
+                write!(f, " {}{}", name, sign(supported))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NcDirectCapabilities;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    #[test]
+    fn direct_capabilities_display() {
+        let mut caps =
+            NcDirectCapabilities { colors: 256, truecolor: true, utf8: true, ..Default::default() };
+        assert_eq![
+            "256 colors rgb+ ccc- fade- utf8+ half- quad- sex- braille- pixel- img- vid-",
+            format!["{}", caps]
+        ];
+        caps.pixel = true;
+        assert_eq![
+            "256 colors rgb+ ccc- fade- utf8+ half- quad- sex- braille- pixel+ img- vid-",
+            format!["{}", caps]
+        ];
+    }
+}
@@ -1,12 +1,20 @@
 //! `NcDirect` methods and associated functions.
 
-use core::ptr::{null, null_mut};
+use core::{
+    ffi::{c_void, CStr},
+    ptr::{null, null_mut},
+};
+
+#[cfg(feature = "std")]
+use crate::NcDirectWriter;
 
 use crate::{
-    c_api::{self, ffi::wchar_t},
-    cstring, error, error_ref_mut, rstring_free, NcAlign, NcBlitter, NcCapabilities, NcChannels,
-    NcDirect, NcDirectFlag, NcError, NcFd, NcInput, NcPaletteIndex, NcPlane, NcResult, NcRgb,
-    NcScale, NcStyle, NcTime,
+    c_api::{self, ffi::wchar_t, NcResult_i32},
+    cstring, error,
+    error::{catch_panic, scoped},
+    error_ref_mut, rstring_free, NcAlign, NcBlitter, NcCapabilities, NcChannels, NcDirect,
+    NcDirectBuilder, NcDirectCapabilities, NcDirectFlag, NcError, NcFd, NcInput, NcPaletteIndex,
+    NcPlane, NcResult, NcRgb, NcScale, NcStyle, NcTime, NcVisual, NcVisualOptions,
 };
 
 #[cfg(not(feature = "std"))]
@@ -46,6 +54,14 @@ impl NcDirect {
         error_ref_mut![res, "Initializing NcDirect"]
     }
 
+    /// Returns a new [`NcDirectBuilder`], for choosing the options of a new
+    /// `NcDirect`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn builder<'a>() -> NcDirectBuilder<'a> {
+        NcDirectBuilder::new()
+    }
+
     /// Releases this `NcDirect` and any associated resources.
     ///
     /// # Safety
@@ -70,7 +86,7 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_flush()][c_api::ncdirect_flush].*
     pub fn flush(&self) -> NcResult<()> {
-        error![unsafe { c_api::ncdirect_flush(self) }, "NcDirect.clear()"]
+        error![unsafe { c_api::ncdirect_flush(self) }, "NcDirect.flush()"]
     }
 
     /// Takes the result of [`render_frame`][NcDirect#method.render_frame]
@@ -90,7 +106,8 @@ impl NcDirect {
     /// The image may be arbitrarily many rows -- the output will scroll --
     /// but will only occupy the column of the cursor, and those to the right.
     ///
-    /// To actually write (and free) this, invoke ncdirect_raster_frame().
+    /// To actually write (and free) this, invoke
+    /// [`raster_frame`][NcDirect#method.raster_frame].
     ///
     /// `max_y' and 'max_x` (cell geometry, *not* pixel), if greater than 0,
     /// are used for scaling; the terminal's geometry is otherwise used.
@@ -131,8 +148,8 @@ impl NcDirect {
     /// will only occupy the column of the cursor, and those to the right.
     ///
     /// The render/raster process can be split by using
-    /// [`render_frame`][#method.render_frame] and
-    /// [`raster_frame`][#method.raster_frame].
+    /// [`render_frame`][NcDirect#method.render_frame] and
+    /// [`raster_frame`][NcDirect#method.raster_frame].
     ///
     /// *C style function: [ncdirect_render_image()][c_api::ncdirect_render_image].*
     pub fn render_image(
@@ -160,6 +177,62 @@ impl NcDirect {
             )
         ]
     }
+
+    /// Streams the frames of a media file using the specified blitter and
+    /// scaling, calling `on_frame` after each frame has been written.
+    ///
+    /// Each frame is drawn over the previous one. `on_frame` is responsible
+    /// for the timing, e.g. by sleeping between frames. Its first error is
+    /// returned, and an error is returned as well if it panics.
+    ///
+    /// Once it returns `Ok(false)` or an error it isn't called again, but
+    /// notcurses may still write the remaining frames, since it doesn't stop
+    /// the stream on the return value of the callback.
+    ///
+    /// *C style function: [ncdirect_stream()][c_api::ncdirect_stream].*
+    pub fn stream<F>(
+        &mut self,
+        filename: &str,
+        align: impl Into<NcAlign>,
+        blitter: impl Into<NcBlitter>,
+        scale: impl Into<NcScale>,
+        on_frame: F,
+    ) -> NcResult<()>
+    where
+        F: FnMut(&mut NcVisual) -> NcResult<bool>,
+    {
+        let (align, blitter, scale) = (align.into(), blitter.into(), scale.into());
+        let mut options = NcVisualOptions::builder()
+            .halign(align)
+            .blitter(blitter)
+            .scale(scale)
+            .build();
+        let mut state = NcDirectStreamState { on_frame, stopped: false, error: None };
+        let cs = cstring![filename];
+
+        let res = unsafe {
+            c_api::ncdirect_stream(
+                self,
+                cs.as_ptr(),
+                Some(ncdirect_streamer::<F>),
+                &mut options,
+                &mut state as *mut NcDirectStreamState<F> as *mut c_void,
+            )
+        };
+        if let Some(error) = state.error {
+            return Err(error);
+        }
+        if res < c_api::NCRESULT_OK {
+            return Err(NcError::with_msg(
+                res,
+                &format!(
+                    "NcDirect.stream({:?}, {:?}, {:?}, {:?})",
+                    filename, align, blitter, scale
+                ),
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// ## NcDirect methods: `NcPaletteIndex`, `NcRgb`, `NcStyle` & default color
@@ -170,7 +243,7 @@ impl NcDirect {
     pub fn set_fg_palindex(&mut self, index: impl Into<NcPaletteIndex>) -> NcResult<()> {
         let index = index.into();
         error![
-            unsafe { c_api::ncdirect_set_fg_palindex(self, index as i32) },
+            unsafe { c_api::ncdirect_set_fg_palindex(self, index.0 as i32) },
             &format!("NcDirect.set_fg_palindex({})", index)
         ]
     }
@@ -181,7 +254,7 @@ impl NcDirect {
     pub fn set_bg_palindex(&mut self, index: impl Into<NcPaletteIndex>) -> NcResult<()> {
         let index = index.into();
         error![
-            unsafe { c_api::ncdirect_set_bg_palindex(self, index as i32) },
+            unsafe { c_api::ncdirect_set_bg_palindex(self, index.0 as i32) },
             &format!("NcDirect.set_fg_palindex({})", index)
         ]
     }
@@ -220,7 +293,7 @@ impl NcDirect {
     pub fn set_bg_rgb(&mut self, rgb: impl Into<NcRgb>) -> NcResult<()> {
         error![
             unsafe { c_api::ncdirect_set_bg_rgb(self, rgb.into().into()) },
-            "NcDirect.set_bg_rgb({:?})"
+            "NcDirect.set_bg_rgb()"
         ]
     }
 
@@ -264,6 +337,36 @@ impl NcDirect {
         ]
     }
 
+    /// Adds the specified `styles`.
+    ///
+    /// Named like [`NcPlane.on_styles`][NcPlane#method.on_styles]. Same as
+    /// [`styles_on`][NcDirect#method.styles_on].
+    ///
+    /// *C style function: [ncdirect_on_styles()][c_api::ncdirect_on_styles].*
+    pub fn on_styles(&mut self, styles: impl Into<NcStyle>) -> NcResult<()> {
+        self.styles_on(styles)
+    }
+
+    /// Removes the specified `styles`.
+    ///
+    /// Named like [`NcPlane.off_styles`][NcPlane#method.off_styles]. Same as
+    /// [`styles_off`][NcDirect#method.styles_off].
+    ///
+    /// *C style function: [ncdirect_off_styles()][c_api::ncdirect_off_styles].*
+    pub fn off_styles(&mut self, styles: impl Into<NcStyle>) -> NcResult<()> {
+        self.styles_off(styles)
+    }
+
+    /// Sets just the specified `styles`.
+    ///
+    /// Named like [`NcPlane.set_styles`][NcPlane#method.set_styles]. Same as
+    /// [`styles_set`][NcDirect#method.styles_set].
+    ///
+    /// *C style function: [ncdirect_set_styles()][c_api::ncdirect_set_styles].*
+    pub fn set_styles(&mut self, styles: impl Into<NcStyle>) -> NcResult<()> {
+        self.styles_set(styles)
+    }
+
     /// Returns an [`NcStyle`] with the supported curses-style attributes.
     ///
     /// The attribute is only indicated as supported if the terminal can support
@@ -276,6 +379,14 @@ impl NcDirect {
         unsafe { c_api::ncdirect_supported_styles(self).into() }
     }
 
+    /// Returns `true` if all the specified `styles` are supported.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn supports_styles(&self, styles: impl Into<NcStyle>) -> bool {
+        let styles = styles.into();
+        self.supported_styles() & styles == styles
+    }
+
     /// Indicates to use the "default color" for the foreground.
     ///
     /// *C style function: [ncdirect_set_fg_default()][c_api::ncdirect_set_fg_default].*
@@ -389,11 +500,23 @@ impl NcDirect {
 
     /// Returns the [`NcCapabilities`].
     ///
+    /// See also [`direct_capabilities`][NcDirect#method.direct_capabilities].
+    ///
     /// *C style function: [ncdirect_capabilities()][c_api::ncdirect_capabilities].*
     pub fn capabilities(&self) -> NcCapabilities {
         c_api::ncdirect_capabilities(self)
     }
 
+    /// Returns the [`NcDirectCapabilities`], gathering all the `can*` queries.
+    ///
+    /// Checking the pixel support requires querying the terminal, so this must
+    /// not be called concurrently with either input or rasterization.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn direct_capabilities(&self) -> NcDirectCapabilities {
+        NcDirectCapabilities::from_direct(self)
+    }
+
     /// Checks for pixel support.
     ///
     /// Returns `false` for no support, or `true` if pixel output is supported.
@@ -502,12 +625,15 @@ impl NcDirect {
     /// If the terminal doesn't reply, or doesn't reply in a way we understand,
     /// the results might be detrimental.
     ///
+    /// Returns an error if the position can't be acquired, which can be checked
+    /// beforehand with [`canget_cursor`][NcDirect#method.canget_cursor].
+    ///
     /// *C style function: [ncdirect_cursor_yx()][c_api::ncdirect_cursor_yx].*
     pub fn cursor_yx(&mut self) -> NcResult<(u32, u32)> {
         let (mut y, mut x) = (0, 0);
         error![
             unsafe { c_api::ncdirect_cursor_yx(self, &mut y, &mut x) },
-            "",
+            "NcDirect.cursor_yx()",
             (y, x)
         ]
     }
@@ -518,7 +644,10 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_cursor_push()][c_api::ncdirect_cursor_push].*
     pub fn cursor_push(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::ncdirect_cursor_push(self) }]
+        error![
+            unsafe { c_api::ncdirect_cursor_push(self) },
+            "NcDirect.cursor_push()"
+        ]
     }
 
     /// Pops the cursor location from the terminal's stack.
@@ -527,7 +656,25 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_cursor_pop()][c_api::ncdirect_cursor_pop].*
     pub fn cursor_pop(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::ncdirect_cursor_pop(self) }]
+        error![
+            unsafe { c_api::ncdirect_cursor_pop(self) },
+            "NcDirect.cursor_pop()"
+        ]
+    }
+
+    /// Pushes the cursor location, calls `f`, and pops the cursor location
+    /// back, returning the result of `f`.
+    ///
+    /// The cursor location is popped even if `f` returns an error, which is
+    /// then returned, with the error of popping appended to its message,
+    /// if any.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with_cursor_saved<T>(
+        &mut self,
+        f: impl FnOnce(&mut NcDirect) -> NcResult<T>,
+    ) -> NcResult<T> {
+        scoped(self, NcDirect::cursor_push, NcDirect::cursor_pop, f)
     }
 
     /// Gets the current number of rows.
@@ -641,23 +788,96 @@ impl NcDirect {
         ]
     }
 
-    /// Reads a (heap-allocated) line of text using the Readline library.
+    /// Outputs the `egc` according to the `channels`, and returns the number
+    /// of columns it occupies.
+    ///
+    /// The number of bytes converted from the `egc` can be optionally written
+    /// to `sbytes`.
     ///
-    /// Initializes Readline the first time it's called.
+    /// *C style function: [ncdirect_putegc()][c_api::ncdirect_putegc].*
+    pub fn putegc(
+        &mut self,
+        channels: impl Into<NcChannels>,
+        egc: &str,
+        sbytes: Option<&mut usize>,
+    ) -> NcResult<u32> {
+        let channels = channels.into();
+        let cs = cstring![egc];
+        let mut bytes = 0;
+        let res = unsafe { c_api::ncdirect_putegc(self, channels.into(), cs.as_ptr(), &mut bytes) };
+        if let Some(sbytes) = sbytes {
+            *sbytes = bytes as usize;
+        }
+        error![
+            res,
+            &format!("NcDirect.putegc({:0X}, {:?})", channels, egc),
+            res as u32
+        ]
+    }
+
+    /// Draws a progress bar `width` columns wide, filled up to `frac`,
+    /// according to the `channels`.
+    ///
+    /// `frac` is clamped between 0 and 1. The cursor is moved back to where the
+    /// bar starts, so that calling this again redraws it in place.
+    ///
+    /// Returns an error if `frac` is not finite.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn progress_bar(
+        &mut self,
+        frac: f64,
+        width: u32,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<()> {
+        if !frac.is_finite() {
+            return Err(NcError::new_msg(&format![
+                "NcDirect.progress_bar({}, {})",
+                frac, width
+            ]));
+        }
+        if width == 0 {
+            return Ok(());
+        }
+        self.putstr(channels, &super::progress::progress_bar(frac, width))?;
+        self.cursor_left(width as i32)?;
+        self.flush()
+    }
+
+    /// Returns an [`std::io::Write`] adapter that writes raw bytes to the
+    /// output, without any styling.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn raw_writer(&mut self) -> NcDirectWriter<'_> {
+        NcDirectWriter::new(self)
+    }
+
+    /// Reads a line of text using the Readline library, after showing the
+    /// `prompt`.
+    ///
+    /// Initializes Readline the first time it's called. The prompt is shown
+    /// with the current colors and styles.
+    ///
+    /// Invalid UTF-8 sequences in the line are replaced with `U+FFFD`.
+    /// Returns an error at the end of the input.
     ///
     /// For input to be echoed to the terminal, it is necessary that the flag
-    /// [`NcDirectFlag::INHIBIT_CBREAK`][0] be provided to the constructor.
+    /// [`NcDirectFlag::InhibitCbreak`][0] be provided to the constructor.
     ///
-    /// [0]: NcDirectFlag#associatedconstant.INHIBIT_CBREAK
+    /// [0]: NcDirectFlag#associatedconstant.InhibitCbreak
     ///
     /// *C style function: [ncdirect_readline()][c_api::ncdirect_readline].*
-    //
-    // CHECK if memory leak still reported by valgrind
     pub fn readline(&mut self, prompt: &str) -> NcResult<String> {
         let cs = cstring![prompt];
         let res = unsafe { c_api::ncdirect_readline(self, cs.as_ptr()) };
         if !res.is_null() {
-            Ok(rstring_free![res])
+            let line = unsafe { CStr::from_ptr(res) }
+                .to_string_lossy()
+                .into_owned();
+            unsafe { c_api::ffi::free(res as *mut c_void) };
+            Ok(line)
         } else {
             Err(NcError::with_msg(
                 c_api::NCRESULT_ERR,
@@ -812,3 +1032,43 @@ impl NcDirect {
         )]
     }
 }
+
+/// The state of a stream, passed to [`ncdirect_streamer`] as its curry.
+struct NcDirectStreamState<F> {
+    on_frame: F,
+    stopped: bool,
+    error: Option<NcError>,
+}
+
+/// The C stream callback that calls the closure of [`NcDirect::stream`].
+///
+/// Returns a positive number to stop the stream on purpose, and saves the
+/// first error returned by the closure, or by its panics. Once stopped, the
+/// closure isn't called again.
+unsafe extern "C" fn ncdirect_streamer<F>(
+    visual: *mut NcVisual,
+    _options: *mut NcVisualOptions,
+    _time: *const NcTime,
+    curry: *mut c_void,
+) -> NcResult_i32
+where
+    F: FnMut(&mut NcVisual) -> NcResult<bool>,
+{
+    let state = &mut *(curry as *mut NcDirectStreamState<F>);
+    if state.stopped {
+        return 1;
+    }
+    let on_frame = &mut state.on_frame;
+    match catch_panic("NcDirect.stream() on_frame", || on_frame(&mut *visual)) {
+        Ok(true) => c_api::NCRESULT_OK,
+        Ok(false) => {
+            state.stopped = true;
+            1
+        }
+        Err(error) => {
+            state.stopped = true;
+            state.error = Some(error);
+            c_api::NCRESULT_ERR
+        }
+    }
+}
@@ -9,7 +9,7 @@
 // (w)  1 : safely wrapped ffi function
 // (r) 11 : static function manually reimplemented
 //
-// (m) 54 : method implemented
+// (m) 56 : method implemented
 //
 // (t)  0 : unit test done for the function
 // (T)  0 : unit test done also for the method
@@ -60,7 +60,7 @@
 // fm  ncplane_off_styles
 // fm  ncdirect_palette_size
 //X    ncdirect_printf_aligned
-// f   ncdirect_putegc
+// fm  ncdirect_putegc
 // fm  ncdirect_putstr
 // fm  ncdirect_raster_frame
 // fm  ncdirect_readline
@@ -69,7 +69,7 @@
 // fm  ncdirect_rounded_box
 // fm  ncdirect_set_styles
 // fm  ncdirect_stop
-// f   ncdirect_stream
+// fm  ncdirect_stream
 // f   ncdirect_styles
 // f   ncdirect_supported_styles
 // fm  ncdirect_vline_interp
@@ -81,8 +81,23 @@
 #[cfg(test)]
 mod test;
 
+mod builder;
+mod capabilities;
 mod methods;
+mod progress;
 pub(crate) mod reimplemented;
+mod table;
+mod visual;
+#[cfg(feature = "std")]
+mod writer;
+
+pub use builder::NcDirectBuilder;
+pub use capabilities::NcDirectCapabilities;
+pub use progress::NcDirectSpinner;
+pub use table::NcDirectTable;
+pub use visual::NcDirectVisual;
+#[cfg(feature = "std")]
+pub use writer::NcDirectWriter;
 
 use c_api::NcDirectFlag_u64;
 
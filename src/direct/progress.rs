@@ -0,0 +1,143 @@
+//! `NcDirectSpinner` and progress bars.
+
+use crate::{text_layout::str_width, NcChannels, NcDirect, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The default frames of an [`NcDirectSpinner`].
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// The partial blocks of a progress bar, by eighths.
+const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// A spinner that an [`NcDirect`] redraws in place, followed by a message.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let ncd = unsafe { NcDirect::new()? };
+/// let mut spinner = NcDirectSpinner::new();
+/// for file in ["a.txt", "b.txt"] {
+///     spinner.tick(ncd, &format!["copying {}", file])?;
+///     // …
+/// }
+/// spinner.finish(ncd, "done")?;
+/// unsafe { ncd.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NcDirectSpinner {
+    frames: Vec<String>,
+    index: usize,
+    channels: NcChannels,
+    // the width of the last drawn line, in columns
+    drawn: u32,
+}
+
+/// # Constructors
+impl NcDirectSpinner {
+    /// New spinner made of braille patterns.
+    pub fn new() -> Self {
+        Self::with_frames(&SPINNER_FRAMES)
+    }
+
+    /// New spinner made of `frames`, which should have the same width.
+    ///
+    /// If `frames` is empty, the spinner draws just the messages.
+    pub fn with_frames(frames: &[&str]) -> Self {
+        Self {
+            frames: frames.iter().map(|frame| frame.to_string()).collect(),
+            index: 0,
+            channels: NcChannels::default(),
+            drawn: 0,
+        }
+    }
+}
+
+/// # Methods
+impl NcDirectSpinner {
+    /// Sets the channels of the spinner and its messages.
+    pub fn set_channels(&mut self, channels: impl Into<NcChannels>) {
+        self.channels = channels.into();
+    }
+
+    /// Draws the next frame followed by the `message`, over the last one.
+    ///
+    /// The cursor is left at the start of the line.
+    pub fn tick(&mut self, ncd: &mut NcDirect, message: &str) -> NcResult<()> {
+        let line = match self.frames.get(self.index) {
+            Some(frame) => [frame.as_str(), " ", message].concat(),
+            None => message.to_string(),
+        };
+        self.index = (self.index + 1) % self.frames.len().max(1);
+        self.redraw(ncd, &line)?;
+        ncd.flush()
+    }
+
+    /// Draws the final `message` over the last frame, and ends the line.
+    pub fn finish(&mut self, ncd: &mut NcDirect, message: &str) -> NcResult<()> {
+        self.redraw(ncd, message)?;
+        self.drawn = 0;
+        self.index = 0;
+        ncd.putstr(0, "\n")?;
+        ncd.flush()
+    }
+
+    /// Draws `line`, blanking what's left of the last one, and moves the
+    /// cursor back to the start of the line.
+    fn redraw(&mut self, ncd: &mut NcDirect, line: &str) -> NcResult<()> {
+        let width = str_width(line);
+        let blank = " ".repeat(self.drawn.saturating_sub(width) as usize);
+        ncd.putstr(self.channels, &[line, &blank].concat())?;
+        let drawn = width.max(self.drawn);
+        if drawn > 0 {
+            ncd.cursor_left(drawn as i32)?;
+        }
+        self.drawn = width;
+        Ok(())
+    }
+}
+
+/// Returns a progress bar `width` columns wide, filled up to `frac`, with
+/// a precision of an eighth of a column.
+///
+/// `frac` is clamped between 0 and 1.
+pub(crate) fn progress_bar(frac: f64, width: u32) -> String {
+    let eighths = (frac.clamp(0.0, 1.0) * width as f64 * 8.0 + 0.5) as u32;
+    let (full, partial) = (eighths / 8, eighths % 8);
+    let mut bar = "█".repeat(full as usize);
+    if full < width {
+        bar.push(EIGHTHS[partial as usize]);
+        bar.push_str(&" ".repeat((width - full - 1) as usize));
+    }
+    bar
+}
+
+mod core_impls {
+    use super::NcDirectSpinner;
+
+    impl Default for NcDirectSpinner {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::progress_bar;
+
+    #[test]
+    fn direct_progress_bar() {
+        assert_eq!["    ", progress_bar(0.0, 4)];
+        assert_eq!["██▌ ", progress_bar(0.625, 4)];
+        assert_eq!["████", progress_bar(1.5, 4)];
+        assert_eq!["", progress_bar(0.5, 0)];
+    }
+}
@@ -0,0 +1,222 @@
+//! `NcDirectTable`
+
+use crate::{text_layout::str_width, NcAlign, NcChannels, NcDirect, NcError, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// A table of text, rendered by an [`NcDirect`] with its columns aligned.
+///
+/// The columns are as wide as their widest cell, measured in terminal columns,
+/// and are joined with a separator. The header is followed by a rule, unless
+/// it's disabled.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let ncd = unsafe { NcDirect::new()? };
+/// let mut table = NcDirectTable::new(&["name", "size"])
+///     .align(1, NcAlign::Right)
+///     .header_channels(NcChannels::from_rgb(0x88aa00, 0));
+/// table.push_row(&["Cargo.toml", "2315"])?;
+/// table.push_row(&["README.md", "815"])?;
+/// table.render(ncd)?;
+/// unsafe { ncd.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NcDirectTable {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    aligns: Vec<NcAlign>,
+    separator: String,
+    rule: bool,
+    header_channels: NcChannels,
+    channels: NcChannels,
+    separator_channels: NcChannels,
+}
+
+/// # Constructors
+impl NcDirectTable {
+    /// New table with a column for each cell of the `header`.
+    ///
+    /// The columns are aligned to the left, and separated by `" │ "`.
+    pub fn new(header: &[&str]) -> Self {
+        Self {
+            header: header.iter().map(|cell| cell.to_string()).collect(),
+            rows: Vec::new(),
+            aligns: vec![NcAlign::Left; header.len()],
+            separator: " │ ".to_string(),
+            rule: true,
+            header_channels: NcChannels::default(),
+            channels: NcChannels::default(),
+            separator_channels: NcChannels::default(),
+        }
+    }
+}
+
+/// # Methods (chainable)
+impl NcDirectTable {
+    /// Sets the alignment of the `column`.
+    ///
+    /// Columns out of the table are ignored.
+    pub fn align(mut self, column: usize, align: impl Into<NcAlign>) -> Self {
+        if let Some(a) = self.aligns.get_mut(column) {
+            *a = align.into();
+        }
+        self
+    }
+
+    /// Sets the separator between columns.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Sets whether to draw a rule below the header.
+    pub fn rule(mut self, rule: bool) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Sets the channels of the header.
+    pub fn header_channels(mut self, channels: impl Into<NcChannels>) -> Self {
+        self.header_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the rows.
+    pub fn channels(mut self, channels: impl Into<NcChannels>) -> Self {
+        self.channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the separators and the rule.
+    pub fn separator_channels(mut self, channels: impl Into<NcChannels>) -> Self {
+        self.separator_channels = channels.into();
+        self
+    }
+}
+
+/// # Methods
+impl NcDirectTable {
+    /// Returns the number of columns.
+    pub fn columns(&self) -> usize {
+        self.header.len()
+    }
+
+    /// Returns the number of rows, not counting the header.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if there are no rows, not counting the header.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Adds a row of `cells`.
+    ///
+    /// Returns an error if there's not a cell for each column.
+    pub fn push_row(&mut self, cells: &[&str]) -> NcResult<()> {
+        if cells.len() != self.columns() {
+            return Err(NcError::new_msg(&format![
+                "NcDirectTable.push_row({:?}): expected {} cells",
+                cells,
+                self.columns()
+            ]));
+        }
+        self.rows
+            .push(cells.iter().map(|cell| cell.to_string()).collect());
+        Ok(())
+    }
+
+    /// Returns the width of each column, in terminal columns.
+    pub fn widths(&self) -> Vec<u32> {
+        let mut widths: Vec<u32> = self.header.iter().map(|cell| str_width(cell)).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(str_width(cell));
+            }
+        }
+        widths
+    }
+
+    /// Writes the table, starting at the current cursor position.
+    pub fn render(&self, ncd: &mut NcDirect) -> NcResult<()> {
+        let widths = self.widths();
+
+        self.render_row(ncd, &self.header, &widths, self.header_channels)?;
+        if self.rule {
+            let joint: String = self
+                .separator
+                .chars()
+                .map(|c| if c.is_whitespace() { '─' } else { '┼' })
+                .collect();
+            let rule: Vec<String> = widths.iter().map(|w| "─".repeat(*w as usize)).collect();
+            ncd.putstr(self.separator_channels, &rule.join(&joint))?;
+            ncd.putstr(0, "\n")?;
+        }
+        for row in &self.rows {
+            self.render_row(ncd, row, &widths, self.channels)?;
+        }
+        ncd.flush()
+    }
+
+    fn render_row(
+        &self,
+        ncd: &mut NcDirect,
+        cells: &[String],
+        widths: &[u32],
+        channels: NcChannels,
+    ) -> NcResult<()> {
+        for (column, (cell, width)) in cells.iter().zip(widths).enumerate() {
+            if column > 0 {
+                ncd.putstr(self.separator_channels, &self.separator)?;
+            }
+            ncd.putstr(channels, &pad(cell, *width, self.aligns[column]))?;
+        }
+        ncd.putstr(0, "\n")
+    }
+}
+
+/// Returns `text` padded with spaces to `width` columns, with `align`.
+fn pad(text: &str, width: u32, align: NcAlign) -> String {
+    let fill = width.saturating_sub(str_width(text)) as usize;
+    let (left, right) = match align {
+        NcAlign::Center => (fill / 2, fill - fill / 2),
+        NcAlign::Right => (fill, 0),
+        _ => (0, fill),
+    };
+    format!["{}{}{}", " ".repeat(left), text, " ".repeat(right)]
+}
+
+#[cfg(test)]
+mod test {
+    use super::pad;
+    use crate::{NcAlign, NcDirectTable};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    #[test]
+    fn direct_table() {
+        let mut table = NcDirectTable::new(&["name", "size"]).align(1, NcAlign::Right);
+        assert![table.push_row(&["Cargo.toml"]).is_err()];
+        table.push_row(&["Cargo.toml", "2315"]).unwrap();
+        table.push_row(&["lib.rs", "7"]).unwrap();
+        assert_eq![2, table.len()];
+        assert_eq![vec![10, 4], table.widths()];
+
+        assert_eq!["   7", pad("7", 4, NcAlign::Right)];
+        assert_eq!["  ab  ", pad("ab", 6, NcAlign::Center)];
+        assert_eq!["name", pad("name", 2, NcAlign::Left)];
+    }
+}
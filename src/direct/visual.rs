@@ -0,0 +1,118 @@
+//! `NcDirectVisual`
+
+use crate::{
+    c_api, cstring, error, error_ref_mut, NcAlign, NcDirect, NcError, NcPlane, NcResult, NcVisual,
+    NcVisualGeometry, NcVisualOptions,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+/// Media loaded from disk for an [`NcDirect`], but not yet rendered.
+///
+/// Its geometry can be inspected before rendering it, and it can be rendered
+/// multiple times, in different ways. It's freed when dropped.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let ncd = unsafe { NcDirect::new()? };
+/// let mut visual = NcDirectVisual::from_file(ncd, "image.png")?;
+/// let options = NcVisualOptions::builder().blitter(NcBlitter::Half).build();
+/// let geom = visual.geom(ncd, &options)?;
+/// if geom.rcell_yx.map_or(false, |(rows, _)| rows <= ncd.dim_y()) {
+///     visual.display(ncd, &options, NcAlign::Center)?;
+/// }
+/// # drop(visual);
+/// unsafe { ncd.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct NcDirectVisual {
+    visual: *mut NcVisual,
+}
+
+/// # Constructors
+impl NcDirectVisual {
+    /// Loads media from `file`, without rendering it.
+    ///
+    /// *C style function: [ncdirectf_from_file()][c_api::ncdirectf_from_file].*
+    pub fn from_file(ncd: &mut NcDirect, file: &str) -> NcResult<NcDirectVisual> {
+        let cs = cstring![file];
+        let visual = unsafe { c_api::ncdirectf_from_file(ncd, cs.as_ptr()) };
+        if visual.is_null() {
+            return Err(NcError::new_msg(&format![
+                "NcDirectVisual::from_file(ncd, {:?})",
+                file
+            ]));
+        }
+        Ok(Self { visual })
+    }
+}
+
+/// # Methods
+impl NcDirectVisual {
+    /// Returns the loaded visual, e.g. for decoding its next frame.
+    pub fn visual(&mut self) -> &mut NcVisual {
+        unsafe { &mut *self.visual }
+    }
+
+    /// Returns the geometry of a potential render with `options`.
+    ///
+    /// *C style function: [ncdirectf_geom()][c_api::ncdirectf_geom].*
+    pub fn geom(
+        &mut self,
+        ncd: &mut NcDirect,
+        options: &NcVisualOptions,
+    ) -> NcResult<NcVisualGeometry> {
+        let mut geom = c_api::NcVGeom::new();
+        let res = unsafe { c_api::ncdirectf_geom(ncd, self.visual, options, &mut geom) };
+        error![res, "NcDirectVisual.geom()", geom.into()]
+    }
+
+    /// Renders the visual with `options`, but doesn't write the result.
+    ///
+    /// To actually write (and free) the returned plane, call
+    /// [`NcDirect.raster_frame`][NcDirect#method.raster_frame].
+    ///
+    /// *C style function: [ncdirectf_render()][c_api::ncdirectf_render].*
+    pub fn render<'a>(
+        &mut self,
+        ncd: &mut NcDirect,
+        options: &NcVisualOptions,
+    ) -> NcResult<&'a mut NcPlane> {
+        error_ref_mut![
+            unsafe { c_api::ncdirectf_render(ncd, self.visual, options) },
+            "NcDirectVisual.render()"
+        ]
+    }
+
+    /// Renders the visual with `options`, and writes it with `align`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn display(
+        &mut self,
+        ncd: &mut NcDirect,
+        options: &NcVisualOptions,
+        align: impl Into<NcAlign>,
+    ) -> NcResult<()> {
+        let frame = self.render(ncd, options)?;
+        ncd.raster_frame(frame, align)
+    }
+}
+
+mod core_impls {
+    use super::NcDirectVisual;
+    use crate::c_api;
+
+    impl Drop for NcDirectVisual {
+        /// Frees the visual.
+        ///
+        /// *C style function: [ncdirectf_free()][c_api::ncdirectf_free].*
+        fn drop(&mut self) {
+            unsafe { c_api::ncdirectf_free(self.visual) };
+        }
+    }
+}
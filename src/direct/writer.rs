@@ -0,0 +1,49 @@
+//! `NcDirectWriter`
+
+use crate::NcDirect;
+use std::io::{self, Error, ErrorKind};
+
+/// An [`io::Write`] adapter that writes raw bytes to the output of an
+/// [`NcDirect`].
+///
+/// The bytes are written as they are, without any styling, to the standard
+/// output, which is the one used by the `NcDirect` constructors of this crate.
+/// The `NcDirect` is flushed before each write, and the standard output after
+/// it, which keeps them ordered with the rest of the output.
+///
+/// Created by [`NcDirect.raw_writer`][NcDirect#method.raw_writer].
+#[derive(Debug)]
+pub struct NcDirectWriter<'ncd> {
+    ncd: &'ncd mut NcDirect,
+}
+
+impl<'ncd> NcDirectWriter<'ncd> {
+    /// New writer for `ncd`.
+    pub(crate) fn new(ncd: &'ncd mut NcDirect) -> Self {
+        Self { ncd }
+    }
+
+    /// Returns the `NcDirect` being written to.
+    pub fn direct(&mut self) -> &mut NcDirect {
+        self.ncd
+    }
+}
+
+impl<'ncd> io::Write for NcDirectWriter<'ncd> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.ncd
+            .flush()
+            .map_err(|e| Error::new(ErrorKind::Other, e))?;
+        let mut stdout = io::stdout().lock();
+        let written = stdout.write(buf)?;
+        stdout.flush()?;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.ncd
+            .flush()
+            .map_err(|e| Error::new(ErrorKind::Other, e))?;
+        io::stdout().flush()
+    }
+}
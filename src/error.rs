@@ -1,7 +1,10 @@
 //! Error handling with `NcError`, `NcResult` & `NcResult_i32`
 
 #[cfg(not(feature = "std"))]
-use alloc::string::{String, ToString};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 
 /// The result type for the Rust methods API.
 pub type NcResult<T> = Result<T, NcError>;
@@ -38,6 +41,46 @@ impl NcError {
     }
 }
 
+/// Calls `f`, returning an error if it panics.
+///
+/// Closures called from C callbacks must go through it, since unwinding
+/// across the FFI boundary is undefined behaviour.
+#[cfg(feature = "std")]
+pub(crate) fn catch_panic<T>(msg: &str, f: impl FnOnce() -> NcResult<T>) -> NcResult<T> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+        .unwrap_or_else(|_| Err(NcError::new_msg(&format!["{}: panicked", msg])))
+}
+
+/// Calls `f`. Without `std` panics can't be caught, and they abort once they
+/// reach the FFI boundary.
+#[cfg(not(feature = "std"))]
+pub(crate) fn catch_panic<T>(_msg: &str, f: impl FnOnce() -> NcResult<T>) -> NcResult<T> {
+    f()
+}
+
+/// Calls `enter`, `f`, and then `exit` even if `f` fails, returning the
+/// result of `f`.
+///
+/// If both `f` and `exit` fail, the error of `f` is returned, since it
+/// happened first, with the message of the error of `exit` appended.
+pub(crate) fn scoped<S: ?Sized, T>(
+    this: &mut S,
+    enter: impl FnOnce(&mut S) -> NcResult<()>,
+    exit: impl FnOnce(&mut S) -> NcResult<()>,
+    f: impl FnOnce(&mut S) -> NcResult<T>,
+) -> NcResult<T> {
+    enter(this)?;
+    let res = f(this);
+    match (res, exit(this)) {
+        (Ok(value), Ok(())) => Ok(value),
+        (Ok(_), Err(error)) | (Err(error), Ok(())) => Err(error),
+        (Err(mut error), Err(exit_error)) => {
+            error.msg = format!["{}, and then {}", error.msg, exit_error.msg];
+            Err(error)
+        }
+    }
+}
+
 mod core_impls {
     use super::NcError;
     use core::fmt;
@@ -61,6 +104,64 @@ mod std_impls {
     }
 }
 
+#[cfg(test)]
+mod test {
+    use super::{scoped, NcError, NcResult};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn error_catch_panic() {
+        use super::catch_panic;
+        assert_eq![1, catch_panic("ok", || Ok(1)).unwrap()];
+        let res: NcResult<()> = catch_panic("closure", || panic!["oops"]);
+        assert_eq!["closure: panicked", res.unwrap_err().msg];
+    }
+
+    #[test]
+    fn error_scoped() {
+        let log = |step: &'static str, fail: bool| {
+            move |log: &mut Vec<&str>| -> NcResult<()> {
+                log.push(step);
+                if fail {
+                    Err(NcError::new_msg(step))
+                } else {
+                    Ok(())
+                }
+            }
+        };
+
+        let mut steps = vec![];
+        let res = scoped(&mut steps, log("enter", false), log("exit", false), |_| {
+            Ok(1)
+        });
+        assert_eq![1, res.unwrap()];
+        assert_eq![vec!["enter", "exit"], steps];
+
+        let mut steps = vec![];
+        let res = scoped(&mut steps, log("enter", true), log("exit", false), |_| {
+            Ok(1)
+        });
+        assert_eq!["enter", res.unwrap_err().msg];
+        assert_eq![vec!["enter"], steps];
+
+        let mut steps = vec![];
+        let res = scoped(&mut steps, log("enter", false), log("exit", true), |_| {
+            Ok(1)
+        });
+        assert_eq!["exit", res.unwrap_err().msg];
+
+        let mut steps = vec![];
+        let res: NcResult<()> = scoped(&mut steps, log("enter", false), log("exit", true), |_| {
+            Err(NcError::new_msg("f"))
+        });
+        assert_eq!["f, and then exit", res.unwrap_err().msg];
+        assert_eq![vec!["enter", "exit"], steps];
+    }
+}
+
 pub(crate) mod c_api {
     /// The int value used to return errors.
     ///
@@ -0,0 +1,125 @@
+//! The closures of the [`NcFdPlane`]s and [`NcSubproc`]s, which run in the
+//! context notcurses reads the file descriptor from.
+//!
+//! They're passed to the C callbacks as their curry, and kept in a side table
+//! keyed by the address of their owner, so that they're freed once it's
+//! destroyed.
+//!
+//! Notcurses destroys the owner by cancelling its thread, which must not
+//! happen while a closure is running, since Rust code can't be cancelled.
+//! So they must not block nor do I/O (which are cancellation points), and
+//! they're stopped before destroying the owner, so that they aren't called
+//! again after the one that may be running.
+//!
+//! [`NcSubproc`]: crate::NcSubproc
+
+use core::{
+    ffi::{c_int, c_void},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{
+    c_api::{NCRESULT_ERR, NCRESULT_OK},
+    error::catch_panic,
+    NcFdPlane, NcResult,
+};
+use std::{boxed::Box, sync::Mutex, vec::Vec};
+
+/// A closure receiving the data read.
+pub(crate) type NcFdDataClosure = Box<dyn FnMut(&mut NcFdPlane, &[u8]) -> NcResult<()> + Send>;
+
+/// A closure receiving the `errno` after reaching EOF (0) or an error.
+pub(crate) type NcFdDoneClosure = Box<dyn FnMut(&mut NcFdPlane, i32) -> NcResult<()> + Send>;
+
+/// The closures of an owner.
+pub(crate) struct NcFdClosures {
+    on_data: NcFdDataClosure,
+    on_done: NcFdDoneClosure,
+    stopped: AtomicBool,
+}
+
+/// The curries, with the address of their owner.
+static CURRIES: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+/// Returns a new curry with the closures.
+pub(crate) fn new_curry(on_data: NcFdDataClosure, on_done: NcFdDoneClosure) -> *mut c_void {
+    Box::into_raw(Box::new(NcFdClosures {
+        on_data,
+        on_done,
+        stopped: AtomicBool::new(false),
+    })) as *mut c_void
+}
+
+/// Frees a `curry` that has no owner, e.g. because its creation failed.
+///
+/// # Safety
+/// It must have been returned by [`new_curry`], and not be in use.
+pub(crate) unsafe fn free_curry(curry: *mut c_void) {
+    drop(Box::from_raw(curry as *mut NcFdClosures));
+}
+
+/// Saves the `curry` of the `owner`, for freeing it with [`free`].
+pub(crate) fn insert(owner: *const c_void, curry: *mut c_void) {
+    let mut curries = CURRIES.lock().unwrap_or_else(|e| e.into_inner());
+    curries.push((owner as usize, curry as usize));
+}
+
+/// Stops calling the closures of the `owner`, if any.
+///
+/// It must be called before destroying the owner, so that its thread ends
+/// on its own once the running closure returns.
+pub(crate) fn stop(owner: *const c_void) {
+    let curries = CURRIES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, curry)) = curries.iter().find(|(o, _)| *o == owner as usize) {
+        let closures = *curry as *const NcFdClosures;
+        unsafe { (*closures).stopped.store(true, Ordering::Release) };
+    }
+}
+
+/// Frees the curry of the `owner`, if any.
+///
+/// It must be called after destroying the owner, so that no closure is running.
+pub(crate) fn free(owner: *const c_void) {
+    let mut curries = CURRIES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(i) = curries.iter().position(|(o, _)| *o == owner as usize) {
+        let (_, curry) = curries.swap_remove(i);
+        unsafe { free_curry(curry as *mut c_void) };
+    }
+}
+
+/// The C data callback that calls the data closure of the `curry`.
+pub(crate) unsafe extern "C" fn ncfdplane_data_closure(
+    fdplane: *mut NcFdPlane,
+    buf: *const c_void,
+    len: usize,
+    curry: *mut c_void,
+) -> c_int {
+    // the fields are accessed separately, since `stop` can read `stopped` meanwhile
+    let closures = curry as *mut NcFdClosures;
+    if (*closures).stopped.load(Ordering::Acquire) {
+        return NCRESULT_ERR;
+    }
+    let on_data = &mut (*closures).on_data;
+    let data = if buf.is_null() { &[] } else { core::slice::from_raw_parts(buf as *const u8, len) };
+    match catch_panic("NcFdPlane on_data", || on_data(&mut *fdplane, data)) {
+        Ok(()) => NCRESULT_OK,
+        Err(_) => NCRESULT_ERR,
+    }
+}
+
+/// The C done callback that calls the done closure of the `curry`.
+pub(crate) unsafe extern "C" fn ncfdplane_done_closure(
+    fdplane: *mut NcFdPlane,
+    fderrno: c_int,
+    curry: *mut c_void,
+) -> c_int {
+    let closures = curry as *mut NcFdClosures;
+    if (*closures).stopped.load(Ordering::Acquire) {
+        return NCRESULT_ERR;
+    }
+    let on_done = &mut (*closures).on_done;
+    match catch_panic("NcFdPlane on_done", || on_done(&mut *fdplane, fderrno)) {
+        Ok(()) => NCRESULT_OK,
+        Err(_) => NCRESULT_ERR,
+    }
+}
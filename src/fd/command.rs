@@ -0,0 +1,248 @@
+//! [`NcSubproc`] integration with [`std::process::Command`].
+
+use core::ffi::c_void;
+
+use crate::{c_api, NcError, NcPlane, NcResult, NcSubproc, NcSubprocOptions};
+
+use super::methods::SubprocBin;
+use std::{
+    boxed::Box,
+    env,
+    ffi::OsStr,
+    io, mem,
+    os::unix::process::ExitStatusExt,
+    process::{Command, ExitStatus},
+    string::String,
+    sync::{Arc, Condvar, Mutex},
+    vec::Vec,
+};
+
+/// The shell script that runs the command, after printing its own PID,
+/// which the command keeps.
+const SCRIPT: &str = "printf '%s\\n' \"$$\"; exec \"$@\"";
+
+/// What is known of a subprocess spawned from a `Command`.
+#[derive(Default)]
+pub(crate) struct Spawned {
+    /// Its PID, once read from its output.
+    pid: Option<libc::pid_t>,
+    /// Whether its output has ended.
+    ended: bool,
+}
+
+/// The shared [`Spawned`], notified on every change.
+pub(crate) type Shared = Arc<(Mutex<Spawned>, Condvar)>;
+
+/// The subprocesses spawned from a `Command`, with the address of their
+/// `NcSubproc`.
+static SPAWNED: Mutex<Vec<(usize, Shared)>> = Mutex::new(Vec::new());
+
+/// Forgets the `subproc`, if it was spawned from a `Command`, returning it.
+pub(crate) fn forget(subproc: *const NcSubproc) -> Option<Shared> {
+    let mut spawned = SPAWNED.lock().unwrap_or_else(|e| e.into_inner());
+    let i = spawned.iter().position(|(s, _)| *s == subproc as usize)?;
+    Some(spawned.swap_remove(i).1)
+}
+
+/// Updates the `shared` state with `f`, and notifies it.
+fn update(shared: &Shared, f: impl FnOnce(&mut Spawned)) {
+    let (spawned, cvar) = &**shared;
+    f(&mut spawned.lock().unwrap_or_else(|e| e.into_inner()));
+    cvar.notify_all();
+}
+
+/// # `NcSubproc` `Command` integration
+#[cfg_attr(feature = "nightly", doc(cfg(all(feature = "std", unix))))]
+impl NcSubproc {
+    /// Spawns the `command`, writing its standard output and error to the
+    /// `plane`, which is set to scroll.
+    ///
+    /// The program, the arguments and the environment variables set or
+    /// removed are taken from the command. Its environment must not have been
+    /// cleared, since that can't be known. Its standard I/O configuration is
+    /// ignored, and it errors if it has a current directory. The program,
+    /// the arguments and all the environment must be valid UTF-8.
+    ///
+    /// It's run through `/bin/sh`, in order to know its PID, so a program
+    /// that can't be run makes the shell print an error and exit with 127.
+    ///
+    /// The subprocess has to be [`wait`]ed for or [`kill`]ed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use libnotcurses_sys::*;
+    /// # use std::process::Command;
+    /// # fn main() -> NcResult<()> {
+    /// let nc = unsafe { Nc::new()? };
+    /// let plane = NcPlane::new_child_sized(unsafe { nc.stdplane() }, 0, 0, 20, 80)?;
+    /// let options = NcSubprocOptions::new(core::ptr::null_mut(), 0, 0);
+    /// let subproc = NcSubproc::spawn(plane, Command::new("ls").arg("-l"), &options)?;
+    /// let status = subproc.wait()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// *C style function: [ncsubproc_createvpe()][c_api::ncsubproc_createvpe].*
+    ///
+    /// [`wait`]: NcSubproc#method.wait
+    /// [`kill`]: NcSubproc#method.kill
+    pub fn spawn<'a>(
+        plane: &mut NcPlane,
+        command: &mut Command,
+        options: &NcSubprocOptions,
+    ) -> NcResult<&'a mut Self> {
+        let msg = format!["NcSubproc::spawn(plane, {:?}, options)", command];
+        if command.get_current_dir().is_some() {
+            return Err(NcError::new_msg(&format![
+                "{}: the current directory can't be set",
+                msg
+            ]));
+        }
+        let program = to_str(command.get_program(), &msg)?;
+        let args = command
+            .get_args()
+            .map(|a| to_str(a, &msg))
+            .collect::<NcResult<Vec<_>>>()?;
+
+        // the inherited environment, with the changes of the command
+        let mut vars: Vec<(String, String)> = env::vars_os()
+            .map(|(k, v)| Ok((to_str(&k, &msg)?.into(), to_str(&v, &msg)?.into())))
+            .collect::<NcResult<_>>()?;
+        for (key, value) in command.get_envs() {
+            let key = to_str(key, &msg)?;
+            vars.retain(|(k, _)| k != key);
+            if let Some(value) = value {
+                let value = to_str(value, &msg)?;
+                vars.push((key.into(), value.into()));
+            }
+        }
+        let vars: Vec<String> = vars.iter().map(|(k, v)| format!["{}={}", k, v]).collect();
+        let vars: Vec<&str> = vars.iter().map(String::as_str).collect();
+        let sh_args: Vec<&str> = ["-c", SCRIPT, "sh", program]
+            .into_iter()
+            .chain(args)
+            .collect();
+
+        let shared: Shared = Arc::default();
+        let on_done = {
+            let shared = shared.clone();
+            move |_: &mut _, _| {
+                update(&shared, |spawned| spawned.ended = true);
+                Ok(())
+            }
+        };
+
+        // the first line is the PID printed by the script
+        let mut pid_line = Some(Vec::new());
+        // bytes of an incomplete UTF-8 character, to be completed by the
+        // next chunk
+        let mut pending: Vec<u8> = Vec::new();
+        let on_data = {
+            let shared = shared.clone();
+            move |fdplane: &mut crate::NcFdPlane, mut data: &[u8]| {
+                if let Some(line) = &mut pid_line {
+                    match data.iter().position(|&b| b == b'\n') {
+                        Some(end) => {
+                            line.extend_from_slice(&data[..end]);
+                            let pid = core::str::from_utf8(line).ok().and_then(|l| l.parse().ok());
+                            update(&shared, |spawned| spawned.pid = pid);
+                            data = &data[end + 1..];
+                            pid_line = None;
+                        }
+                        None => {
+                            line.extend_from_slice(data);
+                            return Ok(());
+                        }
+                    }
+                }
+                pending.extend_from_slice(data);
+                let valid = match core::str::from_utf8(&pending) {
+                    Ok(_) => pending.len(),
+                    Err(e) if e.error_len().is_none() => e.valid_up_to(),
+                    Err(_) => pending.len(),
+                };
+                let text = String::from_utf8_lossy(&pending[..valid]).into_owned();
+                pending.drain(..valid);
+                // a chunk that doesn't fit shouldn't stop the streaming
+                let _ = fdplane.plane()?.putstr(&text);
+                Ok(())
+            }
+        };
+
+        plane.set_scrolling(true);
+        let subproc = Self::create(
+            plane,
+            options,
+            SubprocBin::PathEnv("/bin/sh", &vars),
+            &sh_args,
+            Box::new(on_data),
+            Box::new(on_done),
+        )?;
+        let mut spawned = SPAWNED.lock().unwrap_or_else(|e| e.into_inner());
+        spawned.push((subproc as *const _ as usize, shared));
+        Ok(subproc)
+    }
+
+    /// Waits for a subprocess [spawn]ed from a `Command` to exit, then
+    /// destroys it and returns its exit status.
+    ///
+    /// Errors if the subprocess wasn't spawned from a `Command`, or if its
+    /// exit status couldn't be collected. It must not be used afterwards.
+    ///
+    /// *C style function: [ncsubproc_destroy()][c_api::ncsubproc_destroy].*
+    ///
+    /// [spawn]: NcSubproc#method.spawn
+    pub fn wait(&mut self) -> NcResult<ExitStatus> {
+        let shared = forget(self).ok_or_else(|| NcError::new_msg("NcSubproc.wait()"))?;
+        let pid = {
+            let (spawned, cvar) = &*shared;
+            let mut spawned = spawned.lock().unwrap_or_else(|e| e.into_inner());
+            while spawned.pid.is_none() && !spawned.ended {
+                spawned = cvar.wait(spawned).unwrap_or_else(|e| e.into_inner());
+            }
+            spawned.pid
+        };
+
+        // destroying it would kill it if it was still running, so it's waited
+        // for first, without reaping it, which notcurses does. It may have
+        // already been reaped, which makes this fail.
+        if let Some(pid) = pid {
+            let mut info: libc::siginfo_t = unsafe { mem::zeroed() };
+            while unsafe {
+                libc::waitid(
+                    libc::P_PID,
+                    pid as libc::id_t,
+                    &mut info,
+                    libc::WEXITED | libc::WNOWAIT,
+                )
+            } < 0
+                && io::Error::last_os_error().kind() == io::ErrorKind::Interrupted
+            {}
+        }
+
+        // it's the raw status returned by `waitpid`
+        let status = unsafe { c_api::ncsubproc_destroy(self) };
+        super::closure::free(self as *const _ as *const c_void);
+        if status < 0 {
+            Err(NcError::with_msg(status, "NcSubproc.wait()"))
+        } else {
+            Ok(ExitStatus::from_raw(status))
+        }
+    }
+
+    /// Kills the subprocess if it's still running, and destroys it.
+    ///
+    /// It must not be used afterwards.
+    ///
+    /// *C style function: [ncsubproc_destroy()][c_api::ncsubproc_destroy].*
+    pub fn kill(&mut self) {
+        unsafe { c_api::ncsubproc_destroy(self) };
+        forget(self);
+        super::closure::free(self as *const _ as *const c_void);
+    }
+}
+
+/// Returns the `os_str` as a `str`, erroring with `msg` if it's not valid UTF-8.
+fn to_str<'s>(os_str: &'s OsStr, msg: &str) -> NcResult<&'s str> {
+    os_str.to_str().ok_or_else(|| NcError::new_msg(msg))
+}
@@ -1,12 +1,114 @@
-//! `NcVisual*` methods and associated functions.
+//! `NcFdPlane*` & `NcSubproc*` methods and associated functions.
 
 use core::ffi::c_void;
+#[cfg(feature = "std")]
+use core::ptr::null;
 
-use crate::{NcSubproc, NcSubprocOptions};
+use crate::{
+    c_api, error, error_ref_mut, NcFdPlane, NcFdPlaneOptions, NcPlane, NcResult, NcSubproc,
+    NcSubprocOptions,
+};
 
-/// # NcVisualOptions Constructors
+#[cfg(feature = "std")]
+use {
+    super::closure,
+    crate::{NcError, NcFd},
+    std::{boxed::Box, ffi::CString, vec::Vec},
+};
+
+/// # NcFdPlaneOptions Constructors
+impl NcFdPlaneOptions {
+    /// New `NcFdPlaneOptions`.
+    ///
+    /// If `follow` is `true`, keeps reading beyond EOF.
+    pub fn new(follow: bool) -> Self {
+        Self { curry: core::ptr::null_mut(), follow, flags: 0 }
+    }
+}
+
+/// # NcFdPlane Constructors & Destructors
+impl NcFdPlane {
+    /// Creates an `NcFdPlane` that reads from `fd` until EOF, writing to
+    /// the `plane`.
+    ///
+    /// The closures are called in a context managed by notcurses:
+    /// `on_data` with every chunk of data read, which isn't guaranteed to be
+    /// valid UTF-8 nor to be nul-terminated, and `on_done` with `0` on EOF, or
+    /// with the `errno` of the error. They can write to the plane, which is
+    /// returned by [`NcFdPlane.plane`]. If `on_data` errors or panics, no more
+    /// data is read. They must not block nor do I/O, as explained in [`destroy`].
+    ///
+    /// The curry of the `options` is replaced by the closures, which are
+    /// freed by [`destroy`], and must not call it themselves.
+    ///
+    /// *C style function: [ncfdplane_create()][c_api::ncfdplane_create].*
+    ///
+    /// [`NcFdPlane.plane`]: NcFdPlane#method.plane
+    /// [`destroy`]: NcFdPlane#method.destroy
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn new<'a>(
+        plane: &mut NcPlane,
+        options: &NcFdPlaneOptions,
+        fd: NcFd,
+        on_data: impl FnMut(&mut NcFdPlane, &[u8]) -> NcResult<()> + Send + 'static,
+        on_done: impl FnMut(&mut NcFdPlane, i32) -> NcResult<()> + Send + 'static,
+    ) -> NcResult<&'a mut Self> {
+        let curry = closure::new_curry(Box::new(on_data), Box::new(on_done));
+        let options = NcFdPlaneOptions { curry, ..*options };
+        let res = unsafe {
+            c_api::ncfdplane_create(
+                plane,
+                &options,
+                fd,
+                Some(closure::ncfdplane_data_closure),
+                Some(closure::ncfdplane_done_closure),
+            )
+        };
+        if res.is_null() {
+            unsafe { closure::free_curry(curry) };
+        } else {
+            closure::insert(res as *const c_void, curry);
+        }
+        error_ref_mut![res, &format!["NcFdPlane::new(plane, options, {})", fd]]
+    }
+
+    /// Destroys the `NcFdPlane`, cancelling the thread that reads the file
+    /// descriptor, and frees its closures.
+    ///
+    /// The closures aren't called again, but the one that may be running is
+    /// cancelled at its next cancellation point, so they must not block nor
+    /// do I/O.
+    ///
+    /// It must be called once and only once.
+    ///
+    /// *C style function: [ncfdplane_destroy()][c_api::ncfdplane_destroy].*
+    pub fn destroy(&mut self) -> NcResult<()> {
+        #[cfg(feature = "std")]
+        closure::stop(self as *const _ as *const c_void);
+        let res = unsafe { c_api::ncfdplane_destroy(self) };
+        #[cfg(feature = "std")]
+        closure::free(self as *const _ as *const c_void);
+        error![res, "NcFdPlane.destroy()"]
+    }
+}
+
+/// # NcFdPlane Methods
+impl NcFdPlane {
+    /// Returns the plane the `NcFdPlane` writes to.
+    ///
+    /// *C style function: [ncfdplane_plane()][c_api::ncfdplane_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncfdplane_plane(self) }, "NcFdPlane.plane()"]
+    }
+}
+
+/// # NcSubprocOptions Constructors
 impl NcSubprocOptions {
+    /// New `NcSubprocOptions`.
     ///
+    /// If `restart_period` isn't 0, the subprocess is restarted that many
+    /// seconds after it exits.
     pub fn new(curry: *mut c_void, restart_period: u64, flags: u64) -> Self {
         Self {
             curry,
@@ -19,7 +121,245 @@ impl NcSubprocOptions {
 }
 
 /// # NcSubproc Constructors & Destructors
-impl NcSubproc {}
+impl NcSubproc {
+    /// Spawns the `bin`ary with the `args`, looking for it in the `PATH`,
+    /// and reads its output into the `plane`.
+    ///
+    /// The `args` don't include the name of the program, which is `bin`.
+    ///
+    /// The closures behave as in [`NcFdPlane::new`], and are freed by
+    /// [`destroy`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use libnotcurses_sys::*;
+    /// # fn main() -> NcResult<()> {
+    /// let nc = unsafe { Nc::new()? };
+    /// let plane = NcPlane::new_child_sized(unsafe { nc.stdplane() }, 0, 0, 20, 80)?;
+    /// plane.set_scrolling(true);
+    /// let options = NcSubprocOptions::new(core::ptr::null_mut(), 0, 0);
+    /// let subproc = NcSubproc::new(
+    ///     plane,
+    ///     &options,
+    ///     "ls",
+    ///     &["-l"],
+    ///     |fdplane, data| {
+    ///         fdplane.plane()?.putstr(&String::from_utf8_lossy(data))?;
+    ///         Ok(())
+    ///     },
+    ///     |_, _errno| Ok(()),
+    /// )?;
+    /// // …
+    /// subproc.destroy()?;
+    /// unsafe { nc.stop()? };
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// *C style function: [ncsubproc_createvp()][c_api::ncsubproc_createvp].*
+    ///
+    /// [`destroy`]: NcSubproc#method.destroy
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn new<'a>(
+        plane: &mut NcPlane,
+        options: &NcSubprocOptions,
+        bin: &str,
+        args: &[&str],
+        on_data: impl FnMut(&mut NcFdPlane, &[u8]) -> NcResult<()> + Send + 'static,
+        on_done: impl FnMut(&mut NcFdPlane, i32) -> NcResult<()> + Send + 'static,
+    ) -> NcResult<&'a mut Self> {
+        Self::create(
+            plane,
+            options,
+            SubprocBin::Path(bin),
+            args,
+            Box::new(on_data),
+            Box::new(on_done),
+        )
+    }
+
+    /// Spawns the `bin`ary at the exact path provided, with the `args`,
+    /// and reads its output into the `plane`.
+    ///
+    /// See [`new`][NcSubproc#method.new].
+    ///
+    /// *C style function: [ncsubproc_createv()][c_api::ncsubproc_createv].*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn new_exact<'a>(
+        plane: &mut NcPlane,
+        options: &NcSubprocOptions,
+        bin: &str,
+        args: &[&str],
+        on_data: impl FnMut(&mut NcFdPlane, &[u8]) -> NcResult<()> + Send + 'static,
+        on_done: impl FnMut(&mut NcFdPlane, i32) -> NcResult<()> + Send + 'static,
+    ) -> NcResult<&'a mut Self> {
+        Self::create(
+            plane,
+            options,
+            SubprocBin::Exact(bin),
+            args,
+            Box::new(on_data),
+            Box::new(on_done),
+        )
+    }
+
+    /// Spawns the `bin`ary with the `args` and only the `env`ironment
+    /// variables, as `"KEY=value"` strings, looking for it in the `PATH`,
+    /// and reads its output into the `plane`.
+    ///
+    /// See [`new`][NcSubproc#method.new].
+    ///
+    /// *C style function: [ncsubproc_createvpe()][c_api::ncsubproc_createvpe].*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_env<'a>(
+        plane: &mut NcPlane,
+        options: &NcSubprocOptions,
+        bin: &str,
+        args: &[&str],
+        env: &[&str],
+        on_data: impl FnMut(&mut NcFdPlane, &[u8]) -> NcResult<()> + Send + 'static,
+        on_done: impl FnMut(&mut NcFdPlane, i32) -> NcResult<()> + Send + 'static,
+    ) -> NcResult<&'a mut Self> {
+        Self::create(
+            plane,
+            options,
+            SubprocBin::PathEnv(bin, env),
+            args,
+            Box::new(on_data),
+            Box::new(on_done),
+        )
+    }
+
+    /// Spawns the `bin`ary with the `args` and the boxed closures.
+    #[cfg(feature = "std")]
+    pub(crate) fn create<'a>(
+        plane: &mut NcPlane,
+        options: &NcSubprocOptions,
+        bin: SubprocBin,
+        args: &[&str],
+        on_data: closure::NcFdDataClosure,
+        on_done: closure::NcFdDoneClosure,
+    ) -> NcResult<&'a mut Self> {
+        let msg = || format!["NcSubproc::new(plane, options, {:?}, {:?})", bin, args];
+        let cstring = |s: &str| CString::new(s).map_err(|_| NcError::new_msg(&msg()));
+
+        let name = cstring(bin.name())?;
+        let args = args
+            .iter()
+            .map(|a| cstring(a))
+            .collect::<NcResult<Vec<_>>>()?;
+        let mut argv: Vec<_> = Some(&name)
+            .into_iter()
+            .chain(&args)
+            .map(|a| a.as_ptr())
+            .collect();
+        argv.push(null());
+        let env = match bin {
+            SubprocBin::PathEnv(_, env) => env
+                .iter()
+                .map(|e| cstring(e))
+                .collect::<NcResult<Vec<_>>>()?,
+            _ => Vec::new(),
+        };
+        let mut envp: Vec<_> = env.iter().map(|e| e.as_ptr()).collect();
+        envp.push(null());
+
+        let curry = closure::new_curry(on_data, on_done);
+        let options = NcSubprocOptions { curry, ..*options };
+        let (data_cb, done_cb) = (
+            Some(closure::ncfdplane_data_closure as _),
+            Some(closure::ncfdplane_done_closure as _),
+        );
+        let res = unsafe {
+            match bin {
+                SubprocBin::Exact(_) => c_api::ncsubproc_createv(
+                    plane,
+                    &options,
+                    name.as_ptr(),
+                    argv.as_ptr(),
+                    data_cb,
+                    done_cb,
+                ),
+                SubprocBin::Path(_) => c_api::ncsubproc_createvp(
+                    plane,
+                    &options,
+                    name.as_ptr(),
+                    argv.as_ptr(),
+                    data_cb,
+                    done_cb,
+                ),
+                SubprocBin::PathEnv(..) => c_api::ncsubproc_createvpe(
+                    plane,
+                    &options,
+                    name.as_ptr(),
+                    argv.as_ptr(),
+                    envp.as_ptr(),
+                    data_cb,
+                    done_cb,
+                ),
+            }
+        };
+        if res.is_null() {
+            unsafe { closure::free_curry(curry) };
+        } else {
+            closure::insert(res as *const c_void, curry);
+        }
+        error_ref_mut![res, &msg()]
+    }
+
+    /// Destroys the `NcSubproc`, killing the subprocess if it's still running,
+    /// cancelling the thread that reads its output, and frees its closures.
+    ///
+    /// The closures aren't called again, but the one that may be running is
+    /// cancelled at its next cancellation point, so they must not block nor
+    /// do I/O.
+    ///
+    /// It must be called once and only once.
+    ///
+    /// *C style function: [ncsubproc_destroy()][c_api::ncsubproc_destroy].*
+    pub fn destroy(&mut self) -> NcResult<()> {
+        #[cfg(feature = "std")]
+        closure::stop(self as *const _ as *const c_void);
+        let res = unsafe { c_api::ncsubproc_destroy(self) };
+        #[cfg(feature = "std")]
+        closure::free(self as *const _ as *const c_void);
+        #[cfg(all(feature = "std", unix))]
+        super::command::forget(self);
+        error![res, "NcSubproc.destroy()"]
+    }
+}
 
 /// # NcSubproc Methods
-impl NcSubproc {}
+impl NcSubproc {
+    /// Returns the plane the `NcSubproc` writes to.
+    ///
+    /// *C style function: [ncsubproc_plane()][c_api::ncsubproc_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncsubproc_plane(self) }, "NcSubproc.plane()"]
+    }
+}
+
+/// How to find the binary of a subprocess.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum SubprocBin<'a> {
+    /// At the exact path.
+    Exact(&'a str),
+    /// In the `PATH`.
+    Path(&'a str),
+    /// In the `PATH`, with only these environment variables.
+    PathEnv(&'a str, &'a [&'a str]),
+}
+
+#[cfg(feature = "std")]
+impl SubprocBin<'_> {
+    fn name(&self) -> &str {
+        match self {
+            Self::Exact(bin) | Self::Path(bin) | Self::PathEnv(bin, _) => bin,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Test the `NcSubproc` `Command` integration.
+
+use crate::{
+    c_api::{ncplane_new_test, notcurses_init_test, notcurses_stop},
+    NcSubproc, NcSubprocOptions,
+};
+use core::ptr::null_mut;
+use serial_test::serial;
+use std::process::Command;
+
+#[test]
+#[serial]
+fn spawn_wait() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 5, 20);
+        let options = NcSubprocOptions::new(null_mut(), 0, 0);
+
+        // the output ends before the exit, which must not kill it
+        let mut command = Command::new("sh");
+        command.args(["-c", "exec >&- 2>&-; sleep 0.1; exit 3"]);
+        let subproc = NcSubproc::spawn(plane, &mut command, &options).unwrap();
+        assert_eq![Some(3), subproc.wait().unwrap().code()];
+
+        notcurses_stop(nc);
+    }
+}
@@ -0,0 +1,4 @@
+//! `NcFdPlane` & `NcSubproc` tests.
+
+#[cfg(all(feature = "std", unix))]
+mod command;
@@ -0,0 +1,275 @@
+//! `NcFrameTimer`
+
+use std::{collections::VecDeque, time::Instant};
+
+use crate::{Nc, NcPlane, NcResult, NcStatsAlloc};
+
+/// The width in columns of the overlay of an [`NcFrameTimer`].
+const OVERLAY_COLS: u32 = 40;
+
+/// Records the timings of the last frames of an [`Nc`], from the deltas of
+/// its [`NcStats`][crate::NcStats].
+///
+/// Call [`record`][NcFrameTimer#method.record] after each render, and query
+/// the percentiles and the frame rate of the recorded frames.
+///
+/// It can show an overlay with these metrics at the top right corner of the
+/// standard plane, updated on each record, and visible from the next render.
+/// It's moved back to the corner on each update, after the terminal resizes.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let mut timer = NcFrameTimer::new(nc, 120)?;
+/// timer.set_overlay(nc, true)?;
+/// for _ in 0..1000 {
+///     // …
+///     nc.render()?;
+///     timer.record(nc);
+/// }
+/// let p99 = timer.percentile(0.99, NcFrameTiming::total_ns);
+/// # drop(timer);
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct NcFrameTimer {
+    stats: NcStatsAlloc,
+    last: NcStatsAlloc,
+    frames: VecDeque<(NcFrameTiming, Instant)>,
+    capacity: usize,
+    overlay: Option<*mut NcPlane>,
+}
+
+/// The timings of a frame recorded by an [`NcFrameTimer`], in nanoseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NcFrameTiming {
+    /// The time spent rendering.
+    pub render_ns: u64,
+    /// The time spent rasterizing.
+    pub raster_ns: u64,
+    /// The time spent writing out.
+    pub writeout_ns: u64,
+}
+
+impl NcFrameTiming {
+    /// Returns the total time spent on the frame.
+    pub fn total_ns(&self) -> u64 {
+        self.render_ns + self.raster_ns + self.writeout_ns
+    }
+
+    /// Returns the time spent rendering.
+    pub fn render_ns(&self) -> u64 {
+        self.render_ns
+    }
+
+    /// Returns the time spent rasterizing.
+    pub fn raster_ns(&self) -> u64 {
+        self.raster_ns
+    }
+
+    /// Returns the time spent writing out.
+    pub fn writeout_ns(&self) -> u64 {
+        self.writeout_ns
+    }
+}
+
+/// # Constructors
+impl NcFrameTimer {
+    /// New timer for `nc`, keeping the timings of the last `capacity` frames.
+    ///
+    /// A `capacity` of 0 is treated as 1.
+    pub fn new(nc: &mut Nc, capacity: usize) -> NcResult<Self> {
+        let (stats, mut last) = (nc.stats_alloc()?, nc.stats_alloc()?);
+        nc.stats(&mut last);
+        let capacity = capacity.max(1);
+        Ok(
+            Self {
+                stats,
+                last,
+                frames: VecDeque::with_capacity(capacity),
+                capacity,
+                overlay: None,
+            },
+        )
+    }
+}
+
+/// # Methods
+impl NcFrameTimer {
+    /// Records the frames rendered since the last record.
+    ///
+    /// If more than one frame has been rendered, records their average timing.
+    ///
+    /// Returns the recorded timing, or `None` if no frame has been rendered.
+    pub fn record(&mut self, nc: &mut Nc) -> Option<NcFrameTiming> {
+        nc.stats(&mut self.stats);
+        let (now, last) = (&*self.stats, &*self.last);
+        let frames = now.renders.saturating_sub(last.renders);
+        let writeouts = now.writeouts.saturating_sub(last.writeouts).max(1);
+        let timing = now
+            .render_ns
+            .saturating_sub(last.render_ns)
+            .checked_div(frames)
+            .map(|render_ns| NcFrameTiming {
+                render_ns,
+                raster_ns: now.raster_ns.saturating_sub(last.raster_ns) / writeouts,
+                writeout_ns: now.writeout_ns.saturating_sub(last.writeout_ns) / writeouts,
+            });
+        *self.last = *self.stats;
+
+        if let Some(timing) = timing {
+            self.push(timing, Instant::now());
+            let _ = self.update_overlay();
+        }
+        timing
+    }
+
+    /// Returns the recorded timings, from the oldest to the newest.
+    pub fn timings(&self) -> impl Iterator<Item = NcFrameTiming> + '_ {
+        self.frames.iter().map(|(timing, _)| *timing)
+    }
+
+    /// Returns the number of recorded frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if no frames have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Forgets the recorded frames.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Returns the `p` percentile of a `metric` of the recorded frames,
+    /// with `p` between 0 and 1.
+    ///
+    /// Returns `None` if no frames have been recorded.
+    pub fn percentile(&self, p: f64, metric: impl Fn(&NcFrameTiming) -> u64) -> Option<u64> {
+        percentile(&self.frames, p, metric)
+    }
+
+    /// Returns the frames per second over the recorded frames.
+    ///
+    /// Returns `None` if less than 2 frames have been recorded.
+    pub fn fps(&self) -> Option<f64> {
+        fps(&self.frames)
+    }
+
+    /// Shows or hides the overlay.
+    pub fn set_overlay(&mut self, nc: &mut Nc, show: bool) -> NcResult<()> {
+        match (show, self.overlay) {
+            (true, None) => {
+                let stdplane = unsafe { nc.stdplane() };
+                let x = stdplane.dim_x().saturating_sub(OVERLAY_COLS);
+                let plane = NcPlane::new_child_sized(stdplane, 0, x as i32, 1, OVERLAY_COLS)?;
+                self.overlay = Some(plane as *mut NcPlane);
+                self.update_overlay()
+            }
+            (false, Some(plane)) => {
+                self.overlay = None;
+                unsafe { (*plane).destroy() }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn push(&mut self, timing: NcFrameTiming, at: Instant) {
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back((timing, at));
+    }
+
+    fn update_overlay(&mut self) -> NcResult<()> {
+        if let Some(plane) = self.overlay {
+            let us = |p| self.percentile(p, NcFrameTiming::total_ns).unwrap_or(0) / 1000;
+            let text = format![
+                "{:.1} fps  p50 {}µs  p99 {}µs",
+                self.fps().unwrap_or(0.0),
+                us(0.5),
+                us(0.99)
+            ];
+            let plane = unsafe { &mut *plane };
+            let cols = unsafe { plane.parent_const() }?.dim_x();
+            plane.move_yx(0, cols.saturating_sub(OVERLAY_COLS) as i32)?;
+            plane.erase();
+            plane.putstr_yx(Some(0), Some(0), &text)?;
+            plane.move_top();
+        }
+        Ok(())
+    }
+}
+
+/// Returns the `p` percentile of a `metric` of the `frames`.
+fn percentile(
+    frames: &VecDeque<(NcFrameTiming, Instant)>,
+    p: f64,
+    metric: impl Fn(&NcFrameTiming) -> u64,
+) -> Option<u64> {
+    let mut values: Vec<u64> = frames.iter().map(|(timing, _)| metric(timing)).collect();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let index = (p.clamp(0.0, 1.0) * (values.len() - 1) as f64).round() as usize;
+    Some(values[index])
+}
+
+/// Returns the frames per second between the first and the last `frames`.
+fn fps(frames: &VecDeque<(NcFrameTiming, Instant)>) -> Option<f64> {
+    let (first, last) = (frames.front()?.1, frames.back()?.1);
+    let secs = last.duration_since(first).as_secs_f64();
+    if frames.len() < 2 || secs == 0.0 {
+        return None;
+    }
+    Some((frames.len() - 1) as f64 / secs)
+}
+
+mod core_impls {
+    use super::NcFrameTimer;
+
+    impl Drop for NcFrameTimer {
+        /// Destroys the overlay plane.
+        fn drop(&mut self) {
+            if let Some(plane) = self.overlay.take() {
+                let _ = unsafe { (*plane).destroy() };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fps, percentile, NcFrameTiming};
+    use std::{
+        collections::VecDeque,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn frame_timer_metrics() {
+        let mut frames = VecDeque::new();
+        assert_eq![None, percentile(&frames, 0.5, NcFrameTiming::total_ns)];
+
+        let start = Instant::now();
+        for i in 0..5 {
+            let timing = NcFrameTiming { render_ns: (5 - i) * 100, raster_ns: 0, writeout_ns: 1 };
+            frames.push_back((timing, start + Duration::from_millis(i * 10)));
+        }
+        assert_eq![Some(101), percentile(&frames, 0.0, NcFrameTiming::total_ns)];
+        assert_eq![Some(301), percentile(&frames, 0.5, NcFrameTiming::total_ns)];
+        assert_eq![
+            Some(500),
+            percentile(&frames, 1.0, NcFrameTiming::render_ns)
+        ];
+        assert_eq![100.0, fps(&frames).unwrap().round()];
+    }
+}
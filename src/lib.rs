@@ -91,11 +91,14 @@ mod fade;
 mod fd;
 #[cfg(feature = "std")]
 mod file;
+#[cfg(feature = "std")]
+mod frame_timer;
 mod input;
 mod key;
 mod log_level;
 mod macros;
 mod metric;
+mod names;
 mod notcurses;
 mod palette;
 mod pixel;
@@ -106,6 +109,8 @@ mod scale;
 mod stats;
 mod string;
 mod style;
+mod text_layout;
+mod theme;
 mod time;
 mod visual;
 
@@ -122,30 +127,49 @@ pub use blitter::NcBlitter;
 pub use capabilities::NcCapabilities;
 pub use cell::NcCell;
 pub use channel::{NcChannel, NcChannels};
-pub use direct::{NcDirect, NcDirectFlag};
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+pub use direct::NcDirectWriter;
+pub use direct::{
+    NcDirect, NcDirectBuilder, NcDirectCapabilities, NcDirectFlag, NcDirectSpinner, NcDirectTable,
+    NcDirectVisual,
+};
 pub use error::{NcError, NcResult};
 pub use fade::{NcFadeCb, NcFadeCtx};
 pub use fd::{NcFd, NcFdPlane, NcFdPlaneOptions, NcSubproc, NcSubprocOptions};
 #[cfg(feature = "std")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
 pub use file::NcFile;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+pub use frame_timer::{NcFrameTimer, NcFrameTiming};
 pub use input::{NcInput, NcInputType, NcMiceEvents, NcReceived};
 pub use key::{NcKey, NcKeyMod};
 pub use log_level::NcLogLevel;
-pub use notcurses::{Nc, NcFlag, NcOptions, NcOptionsBuilder};
+pub use metric::NcMetric;
+pub use notcurses::{Nc, NcFlag, NcMargins, NcOptions, NcOptionsBuilder};
 pub use palette::{NcPalette, NcPaletteIndex};
-pub use pixel::{NcPixel, NcPixelGeometry, NcPixelImpl};
-pub use plane::{NcPlane, NcPlaneFlag, NcPlaneOptions, NcPlaneOptionsBuilder};
+pub use pixel::{NcPixel, NcPixelBuffer, NcPixelGeometry, NcPixelImpl};
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+pub use plane::NcPlaneWriter;
+pub use plane::{
+    NcPile, NcPilePlanes, NcPlane, NcPlaneCells, NcPlaneFamily, NcPlaneFlag, NcPlaneOptions,
+    NcPlaneOptionsBuilder, NcRgbaBuffer,
+};
 pub use r#box::NcBoxMask;
 pub use resizecb::NcResizeCb;
 pub use rgb::{NcRgb, NcRgba};
 pub use scale::NcScale;
-pub use stats::NcStats;
+pub use stats::{NcStats, NcStatsAlloc};
 pub use string::NcString;
-pub use style::NcStyle;
+pub use style::{NcStyle, NcStyleIter};
+pub use text_layout::{NcTextLayout, NcTextLine};
+pub use theme::{NcTheme, NcThemeRole};
 pub use time::NcTime;
 pub use visual::{
-    NcStreamCb, NcVisual, NcVisualFlag, NcVisualGeometry, NcVisualOptions, NcVisualOptionsBuilder,
+    NcPlayer, NcPlayerControl, NcPlayerFrame, NcStreamCb, NcVisual, NcVisualFlag, NcVisualFrames,
+    NcVisualGeometry, NcVisualOptions, NcVisualOptionsBuilder,
 };
 
 pub mod c_api {
@@ -47,7 +47,14 @@ pub enum NcLogLevel {
 
 mod core_impls {
     use super::{c_api, NcLogLevel};
-    use core::fmt;
+    use crate::{
+        names::{lex, NcNames},
+        NcError,
+    };
+    use core::{fmt, str::FromStr};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
 
     impl Default for NcLogLevel {
         fn default() -> Self {
@@ -76,6 +83,30 @@ mod core_impls {
         }
     }
 
+    /// The names an [`NcLogLevel`] can be parsed from.
+    const NAMES: &NcNames<NcLogLevel> = &[
+        (NcLogLevel::Silent, &["silent"]),
+        (NcLogLevel::Panic, &["panic"]),
+        (NcLogLevel::Fatal, &["fatal"]),
+        (NcLogLevel::Error, &["error"]),
+        (NcLogLevel::Warning, &["warning", "warn"]),
+        (NcLogLevel::Info, &["info"]),
+        (NcLogLevel::Verbose, &["verbose"]),
+        (NcLogLevel::Debug, &["debug"]),
+        (NcLogLevel::Trace, &["trace"]),
+    ];
+
+    impl FromStr for NcLogLevel {
+        type Err = NcError;
+
+        /// Parses the name of a log level, case-insensitively.
+        ///
+        /// Accepts the names used by [`Display`][fmt::Display], and `"warn"`.
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            lex(NAMES, s).ok_or_else(|| NcError::new_msg(&format!["NcLogLevel::from_str({:?})", s]))
+        }
+    }
+
     impl From<c_api::NcLogLevel_i32> for NcLogLevel {
         fn from(log_level: c_api::NcLogLevel_i32) -> Self {
             use {c_api::*, NcLogLevel::*};
@@ -119,12 +119,75 @@ macro_rules! putstr {
     };
 }
 
+/// Wrapper around [`NcPlane.putstr_styled`][NcPlane#method.putstr_styled],
+/// rendering and rasterizing the plane afterwards.
+///
+/// Returns an `NcResult` with the number of columns advanced,
+/// with newlines counting as 1 column.
+///
+/// # Example
+/// ```
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new_cli()? };
+/// let splane = unsafe { nc.stdplane() };
+/// let red = NcChannels::from_rgb(0xFF0000, 0x000000);
+/// putstr_styled!(splane, NcStyle::Bold, red, "errors: {}", 3)?;
+/// # unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! putstr_styled {
+    ($plane:ident, $styles:expr, $channels:expr, $($args:tt)*) => {
+        {
+            let res = $plane.putstr_styled($styles, $channels, &format![$($args)*])?;
+            $plane.render()?;
+            $plane.rasterize()?;
+            Ok(res)
+        }
+    };
+}
+
+/// Wrapper around [`NcPlane.putstr_yx_styled`][NcPlane#method.putstr_yx_styled],
+/// rendering and rasterizing the plane afterwards.
+///
+/// Returns an `NcResult` with the number of columns advanced,
+/// with newlines counting as 1 column.
+///
+/// # Example
+/// ```
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new_cli()? };
+/// let splane = unsafe { nc.stdplane() };
+/// let green = NcChannels::from_rgb(0x00FF00, 0x000000);
+/// putstr_yx_styled!(splane, 1, 2, NcStyle::Italic, green, "{}%", 100)?;
+/// # unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! putstr_yx_styled {
+    ($plane:ident, $y:expr, $x:expr, $styles:expr, $channels:expr, $($args:tt)*) => {
+        {
+            let res = $plane.putstr_yx_styled($y, $x, $styles, $channels, &format![$($args)*])?;
+            $plane.render()?;
+            $plane.rasterize()?;
+            Ok(res)
+        }
+    };
+}
+
 /// Wrapper around [`NcPlane.putstrln`][NcPlane#method.putstrln].
 /// rendering and rasterizing the plane afterwards.
 ///
 /// Returns an `NcResult` with the number of columns advanced,
 /// with newlines counting as 1 column.
 ///
+/// For formatting without rendering, `NcPlane` also implements
+/// [`core::fmt::Write`], so `writeln!` can be used instead.
+///
 /// # Example
 /// ```
 /// # use libnotcurses_sys::*;
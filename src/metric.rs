@@ -1,5 +1,178 @@
 //! `NcMetric`
 
+use crate::{c_api::ffi, NcError, NcResult};
+use core::ffi::{c_char, CStr};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+/// The size of the buffer for the output of `ncnmetric`, with room to spare.
+const METRIC_BUFLEN: usize = 32;
+
+/// Formats quantities as human-readable strings, with metric prefixes.
+///
+/// E.g. `1_536` is formatted as `1.54K` with [`decimal`] prefixes, or as
+/// `1.50Ki` with [`iec`] prefixes.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let size = NcMetric::iec().width(9).format(3 * 1024 * 1024)?;
+/// assert_eq!["   3.00Mi", size];
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`decimal`]: NcMetric#method.decimal
+/// [`iec`]: NcMetric#method.iec
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NcMetric {
+    divisor: u64,
+    omit_decimal: bool,
+    mult: u64,
+    uprefix: Option<char>,
+    width: usize,
+}
+
+/// # Constructors
+impl NcMetric {
+    /// New `NcMetric` with decimal prefixes (`K`, `M`, `G`…), powers of 1000.
+    pub fn decimal() -> Self {
+        Self { divisor: 1, omit_decimal: false, mult: 1000, uprefix: None, width: 0 }
+    }
+
+    /// New `NcMetric` with binary prefixes (`K`, `M`, `G`…), powers of 1024.
+    pub fn binary() -> Self {
+        Self { mult: 1024, ..Self::decimal() }
+    }
+
+    /// New `NcMetric` with IEC prefixes (`Ki`, `Mi`, `Gi`…), powers of 1024.
+    pub fn iec() -> Self {
+        Self { uprefix: Some('i'), ..Self::binary() }
+    }
+}
+
+/// # Methods
+impl NcMetric {
+    /// Sets the `divisor` the formatted values have been scaled by, e.g. 1000
+    /// for values in thousandths.
+    ///
+    /// A `divisor` of 0 is treated as 1.
+    pub fn divisor(mut self, divisor: u64) -> Self {
+        self.divisor = divisor.max(1);
+        self
+    }
+
+    /// If `true`, omits the decimals when they are all zeros.
+    pub fn omit_decimal(mut self, omit: bool) -> Self {
+        self.omit_decimal = omit;
+        self
+    }
+
+    /// Right-aligns the formatted values to a minimum `width` in columns.
+    ///
+    /// The widest values take [`NCMETRIC_PREFIXCOLUMNS`] columns with decimal
+    /// prefixes, [`NCMETRIC_BPREFIXCOLUMNS`] with binary prefixes and
+    /// [`NCMETRIC_IPREFIXCOLUMNS`] with IEC prefixes.
+    ///
+    /// [`NCMETRIC_PREFIXCOLUMNS`]: crate::c_api::NCMETRIC_PREFIXCOLUMNS
+    /// [`NCMETRIC_BPREFIXCOLUMNS`]: crate::c_api::NCMETRIC_BPREFIXCOLUMNS
+    /// [`NCMETRIC_IPREFIXCOLUMNS`]: crate::c_api::NCMETRIC_IPREFIXCOLUMNS
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Formats the `value`.
+    ///
+    /// *C style function: [ncnmetric()][c_api::ncnmetric].*
+    ///
+    /// [c_api::ncnmetric]: crate::c_api::ffi::ncnmetric
+    #[doc(alias = "ncmetric")]
+    #[doc(alias = "nc_format_metric")]
+    pub fn format(&self, value: u64) -> NcResult<String> {
+        let mut buf = [0 as c_char; METRIC_BUFLEN];
+        let res = unsafe {
+            ffi::ncnmetric(
+                value as _,
+                METRIC_BUFLEN,
+                self.divisor as _,
+                buf.as_mut_ptr(),
+                self.omit_decimal as i32,
+                self.mult as _,
+                self.uprefix.map_or(0, |c| c as i32),
+            )
+        };
+        if res.is_null() {
+            return Err(NcError::new_msg(&format!["NcMetric.format({})", value]));
+        }
+        let metric = unsafe { CStr::from_ptr(buf.as_ptr()) }.to_string_lossy();
+        Ok(pad_left(&metric, self.width))
+    }
+}
+
+/// Right-aligns `s` to a minimum `width` in columns.
+///
+/// The formatted metrics only contain characters one column wide.
+fn pad_left(s: &str, width: usize) -> String {
+    let cols = s.chars().count();
+    if cols >= width {
+        s.to_string()
+    } else {
+        [&" ".repeat(width - cols), s].concat()
+    }
+}
+
+mod core_impls {
+    use super::NcMetric;
+
+    impl Default for NcMetric {
+        fn default() -> Self {
+            Self::decimal()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{pad_left, NcMetric};
+
+    #[test]
+    fn metric_constructors() {
+        let iec = NcMetric::iec().divisor(0).width(9);
+        assert_eq![NcMetric::decimal(), NcMetric::default()];
+        assert_eq![
+            NcMetric::binary(),
+            NcMetric { uprefix: None, ..iec.width(0) }
+        ];
+        assert_eq![1, iec.divisor];
+        assert_eq!["   1.50Mi", pad_left("1.50Mi", iec.width)];
+        assert_eq!["1.50Mi", pad_left("1.50Mi", 2)];
+    }
+
+    #[test]
+    fn metric_format() {
+        assert_eq!["1.50K", NcMetric::decimal().format(1_500).unwrap()];
+        assert_eq!["1.00Ki", NcMetric::iec().format(1_024).unwrap()];
+        assert_eq!["2.00K", NcMetric::decimal().format(2_000).unwrap()];
+        assert_eq![
+            "2K",
+            NcMetric::decimal()
+                .omit_decimal(true)
+                .format(2_000)
+                .unwrap()
+        ];
+        assert_eq![
+            " 1.50K",
+            NcMetric::decimal().width(6).format(1_500).unwrap()
+        ];
+    }
+}
+
 pub(crate) mod reimplemented {
     use crate::{c_api::ffi, cstring, rstring, NcError, NcResult};
 
@@ -0,0 +1,46 @@
+//! Parsing of the enums from their names.
+
+/// The names each value of an enum can be parsed from.
+pub(crate) type NcNames<T> = [(T, &'static [&'static str])];
+
+/// Returns the value named `name`, ignoring the ASCII case.
+pub(crate) fn lex<T: Copy>(names: &NcNames<T>, name: &str) -> Option<T> {
+    names
+        .iter()
+        .find(|(_, aliases)| aliases.iter().any(|a| a.eq_ignore_ascii_case(name)))
+        .map(|(value, _)| *value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::lex;
+    use crate::{NcBlitter, NcLogLevel, NcScale};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    #[test]
+    fn names_lex() {
+        let names = [(1, &["one", "1"][..]), (2, &["two"])];
+        assert_eq![Some(1), lex(&names, "ONE")];
+        assert_eq![Some(1), lex(&names, "1")];
+        assert_eq![Some(2), lex(&names, "Two")];
+        assert_eq![None, lex(&names, "three")];
+
+        assert_eq![Ok(NcScale::ScaleHiRes), "ScaleHi".parse().map_err(|_| ())];
+        assert_eq![Ok(NcLogLevel::Warning), "warn".parse().map_err(|_| ())];
+        assert_eq![Ok(NcBlitter::Sextant), "3x2".parse().map_err(|_| ())];
+        assert!["foo".parse::<NcBlitter>().is_err()];
+
+        // round trips through `Display`
+        for value in [NcScale::None, NcScale::NoneHiRes, NcScale::Stretch] {
+            assert_eq![Ok(value), format!["{}", value].parse().map_err(|_| ())];
+        }
+        for value in [NcLogLevel::Silent, NcLogLevel::Info, NcLogLevel::Trace] {
+            assert_eq![Ok(value), format!["{}", value].parse().map_err(|_| ())];
+        }
+        for value in [NcBlitter::Default, NcBlitter::Braille, NcBlitter::_8x1] {
+            assert_eq![Ok(value), format!["{}", value].parse().map_err(|_| ())];
+        }
+    }
+}
@@ -2,11 +2,16 @@
 
 use core::ptr::{null, null_mut};
 
+#[cfg(feature = "libc")]
+use {super::tty, core::sync::atomic::Ordering::Relaxed};
+
 use crate::{
     c_api::{self, notcurses_init},
-    cstring, error, error_ref_mut, rstring, rstring_free, Nc, NcAlign, NcBlitter, NcCapabilities,
-    NcChannels, NcError, NcFd, NcFlag, NcInput, NcLogLevel, NcMiceEvents, NcOptions, NcPixelImpl,
-    NcPlane, NcReceived, NcResult, NcRgb, NcScale, NcStats, NcStyle, NcTime, NcVisual,
+    cstring, error,
+    error::scoped,
+    error_ref_mut, rstring, rstring_free, Nc, NcAlign, NcBlitter, NcCapabilities, NcChannels,
+    NcError, NcFd, NcFlag, NcInput, NcLogLevel, NcMiceEvents, NcOptions, NcPixelImpl, NcPlane,
+    NcReceived, NcResult, NcRgb, NcScale, NcStats, NcStatsAlloc, NcStyle, NcTime, NcVisual,
     NcVisualGeometry, NcVisualOptions,
 };
 
@@ -102,6 +107,14 @@ impl Nc {
     /// You can't have multiple simultaneous `Nc` instances in the same thread.
     pub unsafe fn with_options<'a>(options: NcOptions) -> NcResult<&'a mut Nc> {
         let res = notcurses_init(&options, null_mut());
+        #[cfg(feature = "libc")]
+        if !res.is_null() {
+            tty::ALTERNATE.store(
+                options.flags & NcFlag::NoAlternateScreen == NcFlag::None,
+                Relaxed,
+            );
+            tty::MICE.store(0, Relaxed);
+        }
         error_ref_mut![res, &format!["Nc.with_options({:?})", options]]
     }
 
@@ -112,6 +125,8 @@ impl Nc {
     ///
     /// *C style function: [notcurses_stop()][c_api::notcurses_stop].*
     pub unsafe fn stop(&mut self) -> NcResult<()> {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget_all_but(null());
         error![c_api::notcurses_stop(self)]
     }
 
@@ -122,10 +137,21 @@ impl Nc {
     ///
     /// *C style function: [notcurses_drop_planes()][c_api::notcurses_drop_planes].*
     pub unsafe fn drop_planes(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget_all_but(c_api::notcurses_stdplane(self));
         c_api::notcurses_drop_planes(self);
     }
 }
 
+/// # `Nc` constants
+impl Nc {
+    /// The notcurses version these bindings were built against, which is the
+    /// minimum supported version.
+    ///
+    /// The running version is returned by [`version`][Nc#method.version].
+    pub const BINDINGS_VERSION: &'static str = env!("LIBNOTCURSES_SYS_NC_VERSION");
+}
+
 /// # `Nc` methods
 impl Nc {
     /// Returns the offset into `availcols` at which `cols` ought be output given
@@ -277,7 +303,47 @@ impl Nc {
         unsafe { c_api::notcurses_check_pixel_support(self) }.into()
     }
 
+    /// Returns the blitter with the highest fidelity for displaying `visual`
+    /// in `plane`, among the ones supported by the terminal.
+    ///
+    /// That is [`Pixel`] if bitmaps are supported. Otherwise it's the
+    /// blitter with the fewest subdivisions per cell that fits the whole
+    /// visual in the plane without downscaling, since fewer subdivisions
+    /// lose fewer colors, or else the one with the most subdivisions.
+    ///
+    /// [`Braille`] is never chosen, since it has a single color per cell.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`Pixel`]: NcBlitter::Pixel
+    /// [`Braille`]: NcBlitter::Braille
+    pub fn best_blitter_for(&self, visual: &NcVisual, plane: &NcPlane) -> NcResult<NcBlitter> {
+        if self.canpixel() {
+            return Ok(NcBlitter::Pixel);
+        }
+        let (pix_y, pix_x) = visual.geom(None, None)?.pix_yx.unwrap_or((0, 0));
+        let (rows, cols) = plane.dim_yx();
+
+        let supported = [
+            (NcBlitter::Ascii, true),
+            (NcBlitter::Half, self.canhalfblock()),
+            (NcBlitter::Quadrant, self.canquadrant()),
+            (NcBlitter::Sextant, self.cansextant()),
+        ];
+        let mut best = NcBlitter::Ascii;
+        for (blitter, _) in supported.iter().filter(|(_, can)| *can) {
+            best = *blitter;
+            let (h, w) = blitter.cell_size().unwrap_or((1, 1));
+            if rows.saturating_mul(h as u32) >= pix_y && cols.saturating_mul(w as u32) >= pix_x {
+                break;
+            }
+        }
+        Ok(best)
+    }
+
     /// Returns the default foreground color, if it is known.
+    ///
+    /// *C style function: [notcurses_default_foreground()][c_api::notcurses_default_foreground].*
     pub fn default_foreground(&self) -> Option<NcRgb> {
         let mut fg = 0;
         let res = unsafe { c_api::notcurses_default_foreground(self, &mut fg) };
@@ -289,6 +355,8 @@ impl Nc {
     }
 
     /// Returns the default background color, if it is known.
+    ///
+    /// *C style function: [notcurses_default_background()][c_api::notcurses_default_background].*
     pub fn default_background(&self) -> Option<NcRgb> {
         let mut bg = 0;
         let res = unsafe { c_api::notcurses_default_background(self, &mut bg) };
@@ -299,13 +367,52 @@ impl Nc {
         }
     }
 
+    /// Returns the default `(foreground, background)` colors, if they are known.
+    pub fn default_colors(&self) -> (Option<NcRgb>, Option<NcRgb>) {
+        (self.default_foreground(), self.default_background())
+    }
+
+    /// Returns true if the terminal seems to have a dark background.
+    ///
+    /// Looks in order at the luminance of the default background color,
+    /// the luminance of the default foreground color, and (with the `std`
+    /// feature) the `COLORFGBG` environment variable. If none of them are
+    /// known it assumes a dark background.
+    ///
+    /// See also [`NcTheme::detect`][crate::NcTheme#method.detect].
+    pub fn prefers_dark(&self) -> bool {
+        fn is_dark(rgb: NcRgb) -> bool {
+            let (r, g, b): (u8, u8, u8) = rgb.into();
+            (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000 < 128
+        }
+
+        match self.default_colors() {
+            (_, Some(bg)) => is_dark(bg),
+            (Some(fg), None) => !is_dark(fg),
+            (None, None) => {
+                // e.g. "15;0" or "15;default;0", the last field being the background
+                #[cfg(feature = "std")]
+                if let Some(bg) = std::env::var("COLORFGBG")
+                    .ok()
+                    .and_then(|v| v.rsplit(';').next().and_then(|bg| bg.parse::<u8>().ok()))
+                {
+                    return matches!(bg, 0..=6 | 8);
+                }
+                true
+            }
+        }
+    }
+
     /// Disables the terminal's cursor, if supported.
     ///
     /// Immediate effect (no need for a call to notcurses_render()).
     ///
     /// *C style function: [notcurses_cursor_disable()][c_api::notcurses_cursor_disable].*
     pub fn cursor_disable(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::notcurses_cursor_disable(self) }]
+        error![
+            unsafe { c_api::notcurses_cursor_disable(self) },
+            "Nc.cursor_disable()"
+        ]
     }
 
     /// Enables the terminal's cursor, if supported, placing it at `y`, `x`.
@@ -315,7 +422,24 @@ impl Nc {
     ///
     /// *C style function: [notcurses_cursor_enable()][c_api::notcurses_cursor_enable].*
     pub fn cursor_enable(&mut self, y: u32, x: u32) -> NcResult<()> {
-        error![unsafe { c_api::notcurses_cursor_enable(self, y as i32, x as i32) }]
+        error![
+            unsafe { c_api::notcurses_cursor_enable(self, y as i32, x as i32) },
+            &format!("Nc.cursor_enable({}, {})", y, x)
+        ]
+    }
+
+    /// Returns the location of the terminal's cursor (`y`, `x`).
+    ///
+    /// Returns an error if the cursor is disabled.
+    ///
+    /// *C style function: [notcurses_cursor_yx()][c_api::notcurses_cursor_yx].*
+    pub fn cursor_yx(&self) -> NcResult<(u32, u32)> {
+        let (mut y, mut x) = (0, 0);
+        error![
+            unsafe { c_api::notcurses_cursor_yx(self, &mut y, &mut x) },
+            "Nc.cursor_yx()",
+            (y as u32, x as u32)
+        ]
     }
 
     /// Shifts to the alternate screen, if available.
@@ -328,8 +452,14 @@ impl Nc {
     ///
     /// *C style function:
     /// [notcurses_enter_alternate_screen()][c_api::notcurses_enter_alternate_screen].*
+    #[doc(alias = "enter_alt_screen")]
     pub fn enter_alternate_screen(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::notcurses_enter_alternate_screen(self) }]
+        let res = unsafe { c_api::notcurses_enter_alternate_screen(self) };
+        #[cfg(feature = "libc")]
+        if res == c_api::NCRESULT_OK {
+            tty::ALTERNATE.store(true, Relaxed);
+        }
+        error![res, "Nc.enter_alternate_screen()"]
     }
 
     /// Exits the alternate screen.
@@ -338,8 +468,37 @@ impl Nc {
     ///
     /// *C style function:
     /// [notcurses_leave_alternate_screen()][c_api::notcurses_leave_alternate_screen].*
+    #[doc(alias = "leave_alt_screen")]
     pub fn leave_alternate_screen(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::notcurses_leave_alternate_screen(self) }]
+        let res = unsafe { c_api::notcurses_leave_alternate_screen(self) };
+        #[cfg(feature = "libc")]
+        if res == c_api::NCRESULT_OK {
+            tty::ALTERNATE.store(false, Relaxed);
+        }
+        error![res, "Nc.leave_alternate_screen()"]
+    }
+
+    /// Shifts to the alternate screen, calls `f`, and exits the alternate
+    /// screen, returning the result of `f`.
+    ///
+    /// This lets a program started in [`CliMode`] go fullscreen temporarily.
+    /// The alternate screen is exited even if `f` returns an error, which is
+    /// then returned, with the error of exiting appended to its message,
+    /// if any.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`CliMode`]: crate::NcFlag#associatedconstant.CliMode
+    pub fn with_alternate_screen<T>(
+        &mut self,
+        f: impl FnOnce(&mut Nc) -> NcResult<T>,
+    ) -> NcResult<T> {
+        scoped(
+            self,
+            Nc::enter_alternate_screen,
+            Nc::leave_alternate_screen,
+            f,
+        )
     }
 
     /// Dumps notcurses state to the supplied `debugfp`.
@@ -356,6 +515,37 @@ impl Nc {
         }
     }
 
+    /// Returns the notcurses state, as dumped by [`debug`][Nc#method.debug].
+    ///
+    /// Output is freeform, and subject to change. It includes geometry of all
+    /// planes, from all piles.
+    ///
+    /// *C style function: [notcurses_debug()][c_api::notcurses_debug].*
+    #[cfg(feature = "libc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "libc")))]
+    pub fn debug_string(&self) -> NcResult<String> {
+        use core::{ffi::c_void, slice};
+
+        let (mut buf, mut len) = (null_mut(), 0);
+        let fp = unsafe { libc::open_memstream(&mut buf, &mut len) };
+        if fp.is_null() {
+            return Err(NcError::new_msg("Nc.debug_string(): open_memstream"));
+        }
+        let res = unsafe {
+            c_api::notcurses_debug(self, fp as *mut c_api::ffi::FILE);
+            libc::fclose(fp)
+        };
+        let string = if buf.is_null() {
+            String::new()
+        } else {
+            let bytes = unsafe { slice::from_raw_parts(buf as *const u8, len) };
+            let string = String::from_utf8_lossy(bytes).into_owned();
+            unsafe { libc::free(buf as *mut c_void) };
+            string
+        };
+        error![res, "Nc.debug_string()", string]
+    }
+
     /// Returns the name of the user under which we are running.
     ///
     /// *C style function: [notcurses_accountname()][c_api::notcurses_accountname].*
@@ -485,21 +675,28 @@ impl Nc {
     /// There can be either a single number, which will define all margins equally,
     /// or there can be four numbers separated by commas.
     ///
+    /// An [`NcMargins`][crate::NcMargins] can also be parsed with `str::parse`.
+    ///
     /// *C style function: [notcurses_lex_margins()][c_api::notcurses_lex_margins].*
     pub fn lex_margins(margins_str: &str, options: &mut NcOptions) -> NcResult<()> {
         let cs = cstring![margins_str];
-        error![unsafe { c_api::notcurses_lex_margins(cs.as_ptr(), options) }]
+        error![
+            unsafe { c_api::notcurses_lex_margins(cs.as_ptr(), options) },
+            &format!["Nc::lex_margins({:?})", margins_str]
+        ]
     }
 
     /// Returns an [`NcScale`] from a string representation.
     ///
+    /// An `NcScale` can also be parsed with `str::parse`.
+    ///
     /// *C style function: [notcurses_lex_scalemode()][c_api::notcurses_lex_scalemode].*
     pub fn lex_scalemode(scale_str: &str) -> NcResult<NcScale> {
         let mut scale = 0;
         let cs = cstring![scale_str];
         error![
             unsafe { c_api::notcurses_lex_scalemode(cs.as_ptr(), &mut scale) },
-            "",
+            &format!["Nc::lex_scalemode({:?})", scale_str],
             scale.into()
         ]
     }
@@ -545,19 +742,43 @@ impl Nc {
     }
 
     /// Disables signals originating from the terminal's line discipline, i.e.
-    /// SIGINT (^C), SIGQUIT (^), and SIGTSTP (^Z). They are enabled by default.
+    /// SIGINT (^C), SIGQUIT (^\\), and SIGTSTP (^Z). They are enabled by default.
+    ///
+    /// While disabled, these keys are received as input.
     ///
     /// *C style function: [notcurses_linesigs_disable()][c_api::notcurses_linesigs_disable].*
+    #[doc(alias = "disable_line_signals")]
     pub fn linesigs_disable(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::notcurses_linesigs_disable(self) }]
+        error![
+            unsafe { c_api::notcurses_linesigs_disable(self) },
+            "Nc.linesigs_disable()"
+        ]
     }
 
     /// Restores signals originating from the terminal's line discipline, i.e.
-    /// SIGINT (^C), SIGQUIT (^), and SIGTSTP (^Z), if disabled.
+    /// SIGINT (^C), SIGQUIT (^\\), and SIGTSTP (^Z), if disabled.
     ///
     /// *C style function: [notcurses_linesigs_enable()][c_api::notcurses_linesigs_enable].*
+    #[doc(alias = "enable_line_signals")]
     pub fn linesigs_enable(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::notcurses_linesigs_enable(self) }]
+        error![
+            unsafe { c_api::notcurses_linesigs_enable(self) },
+            "Nc.linesigs_enable()"
+        ]
+    }
+
+    /// Disables the line signals, calls `f`, and restores them, returning the
+    /// result of `f`.
+    ///
+    /// The signals are restored even if `f` returns an error, which is then
+    /// returned, with the error of restoring appended to its message, if any.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with_line_signals_disabled<T>(
+        &mut self,
+        f: impl FnOnce(&mut Nc) -> NcResult<T>,
+    ) -> NcResult<T> {
+        scoped(self, Nc::linesigs_disable, Nc::linesigs_enable, f)
     }
 
     /// Disables mice events.
@@ -575,10 +796,12 @@ impl Nc {
     ///
     /// *C style function: [notcurses_mice_enable()][c_api::notcurses_mice_enable].*
     pub fn mice_enable(&mut self, eventmask: NcMiceEvents) -> NcResult<()> {
-        error![
-            unsafe { c_api::notcurses_mice_enable(self, eventmask.into()) },
-            "Nc.mice_enable()"
-        ]
+        let res = unsafe { c_api::notcurses_mice_enable(self, eventmask.into()) };
+        #[cfg(feature = "libc")]
+        if res == c_api::NCRESULT_OK {
+            tty::MICE.store(eventmask.into(), Relaxed);
+        }
+        error![res, "Nc.mice_enable()"]
     }
 
     /// Returns the number of simultaneous colors claimed to be supported,
@@ -611,11 +834,76 @@ impl Nc {
         let (mut y, mut x) = (0, 0);
         error![
             unsafe { c_api::notcurses_refresh(self, &mut y, &mut x) },
-            "",
+            "Nc.refresh()",
             (y, x)
         ]
     }
 
+    /// Stops the program as the shell's job control does on `Ctrl-Z`, and
+    /// returns once it has been continued (e.g. with `fg`).
+    ///
+    /// Before stopping it gives the terminal back to the shell: it disables
+    /// the mice, leaves the alternate screen, shows the cursor, disables the
+    /// keyboard protocols and sets the cooked terminal modes. The raw modes
+    /// set by notcurses are restored once continued.
+    ///
+    /// Afterwards call [`resume`][Nc#method.resume] to enable the rest again
+    /// and redraw the screen.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "libc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "libc")))]
+    pub fn suspend(&mut self) -> NcResult<()> {
+        let errmsg = "Nc.suspend()";
+        let tty = tty::Tty::open(errmsg)?;
+        let raw = tty.termios(errmsg)?;
+
+        // the saved mice events and alternate screen are left untouched for `resume`
+        let res = unsafe { c_api::notcurses_mice_enable(self, 0) };
+        if res < c_api::NCRESULT_OK {
+            return Err(NcError::with_msg(res, errmsg));
+        }
+        if tty::ALTERNATE.load(Relaxed) {
+            let res = unsafe { c_api::notcurses_leave_alternate_screen(self) };
+            if res < c_api::NCRESULT_OK {
+                return Err(NcError::with_msg(res, errmsg));
+            }
+        }
+        tty.write(tty::SUSPEND, errmsg)?;
+        tty.set_termios(&tty::cooked(&raw), errmsg)?;
+
+        let res = unsafe { libc::raise(libc::SIGTSTP) };
+
+        tty.set_termios(&raw, errmsg)?;
+        error![res, errmsg]
+    }
+
+    /// Enables again what [`suspend`][Nc#method.suspend] disabled, once
+    /// continued: the mice events last enabled, the alternate screen if it was
+    /// in use, and the keyboard protocols. Then redraws the last rendered frame.
+    ///
+    /// The cursor is hidden, so call [`cursor_enable`][Nc#method.cursor_enable]
+    /// again if it was enabled.
+    ///
+    /// Returns the current screen geometry (`y`, `x`), which may have changed
+    /// while stopped.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "libc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "libc")))]
+    pub fn resume(&mut self) -> NcResult<(u32, u32)> {
+        let errmsg = "Nc.resume()";
+        if tty::ALTERNATE.load(Relaxed) {
+            let res = unsafe { c_api::notcurses_enter_alternate_screen(self) };
+            if res < c_api::NCRESULT_OK {
+                return Err(NcError::with_msg(res, errmsg));
+            }
+        }
+        tty::Tty::open(errmsg)?.write(tty::RESUME, errmsg)?;
+        self.mice_enable(NcMiceEvents(tty::MICE.load(Relaxed)))?;
+        self.refresh()
+    }
+
     /// Renders and rasterizes the standard pile in one shot. Blocking call.
     ///
     /// *C style function: [notcurses_render()][c_api::notcurses_render].*
@@ -632,14 +920,14 @@ impl Nc {
         }
     }
 
-    /// Allocates an [`NcStats`] object.
+    /// Allocates an [`NcStats`] object, which is freed when dropped.
     ///
     /// Use this rather than allocating your own, since future versions of
     /// notcurses might enlarge this structure.
     ///
     /// *C style function: [notcurses_stats_alloc()][c_api::notcurses_stats_alloc].*
-    pub fn stats_alloc(&mut self) -> &mut NcStats {
-        unsafe { &mut *c_api::notcurses_stats_alloc(self) }
+    pub fn stats_alloc(&mut self) -> NcResult<NcStatsAlloc> {
+        NcStatsAlloc::new(self)
     }
 
     /// Resets all cumulative stats (immediate ones, such as fbbytes, are not reset).
@@ -694,6 +982,19 @@ impl Nc {
         &mut *c_api::notcurses_stdplane(self)
     }
 
+    /// Returns the first [`NcPlane`] named `name` in the pile of the standard
+    /// plane, searching from the top of the z-buffer down.
+    ///
+    /// Planes in other piles can be found with
+    /// [`NcPlane.find_in_pile`][NcPlane#method.find_in_pile].
+    ///
+    /// # Safety
+    /// You must be careful not to end up with multiple exclusive references
+    /// to the plane.
+    pub unsafe fn find_plane<'a>(&mut self, name: &str) -> Option<&'a mut NcPlane> {
+        self.stdplane().find_in_pile(name)
+    }
+
     /// Returns a reference to the standard [`NcPlane`] for this terminal.
     ///
     /// The standard plane always exists, and its origin is always at the
@@ -797,6 +1098,30 @@ impl Nc {
         (major as u32, minor as u32, patch as u32, tweak as u32)
     }
 
+    /// Returns `true` if the running notcurses version is at least
+    /// `min` (major, minor, patch).
+    ///
+    /// *(No equivalent C style function)*
+    pub fn check_version(min: (u32, u32, u32)) -> bool {
+        let (major, minor, patch, _) = Self::version_components();
+        (major, minor, patch) >= min
+    }
+
+    /// Returns the notcurses version these bindings were built against
+    /// (major, minor, patch), which is the minimum supported version.
+    ///
+    /// See also [`BINDINGS_VERSION`][Nc#associatedconstant.BINDINGS_VERSION].
+    ///
+    /// *(No equivalent C style function)*
+    #[doc(alias = "abi_version")]
+    pub fn bindings_version() -> (u32, u32, u32) {
+        let mut components = Self::BINDINGS_VERSION
+            .split('.')
+            .map(|n| n.parse().unwrap_or(0));
+        let mut next = || components.next().unwrap_or(0);
+        (next(), next(), next())
+    }
+
     /// Returns [`NcVisualGeometry`].
     ///
     /// If an [`NcVisual`] is not provided, only the [`cdim_yx`], [`blitter`],
@@ -9,7 +9,7 @@
 // (w)  0 : safely wrapped ffi function
 // (r) 15 : static function manually reimplemented
 //
-// (m) 42 : method implemented
+// (m) 43 : method implemented
 //
 // (t) 13 : unit test done for the function
 // (T)  0 : unit test done also for the method
@@ -28,7 +28,7 @@
 //~f   notcurses_core_init
 // fm  notcurses_cursor_disable
 // fm  notcurses_cursor_enable
-// f   notcurses_cursor_yx
+// fm  notcurses_cursor_yx
 // fmt notcurses_debug
 // fm  notcurses_default_background
 // fm  notcurses_default_foreground
@@ -75,11 +75,13 @@ mod methods;
 pub(crate) mod helpers;
 pub(crate) mod options;
 pub(crate) mod reimplemented;
+#[cfg(feature = "libc")]
+mod tty;
 
 #[cfg(test)]
 mod test;
 
-pub use options::{NcFlag, NcOptions, NcOptionsBuilder};
+pub use options::{NcFlag, NcMargins, NcOptions, NcOptionsBuilder};
 
 /// Notcurses state for a given terminal, composed of [`NcPlane`]s.
 ///
@@ -1,6 +1,6 @@
 //!
 
-use crate::{NcFlag, NcLogLevel, NcOptions};
+use crate::{NcFlag, NcLogLevel, NcMargins, NcOptions};
 use core::ptr::null;
 
 /// Builder object for [`NcOptions`].
@@ -141,6 +141,12 @@ impl NcOptionsBuilder {
         self
     }
 
+    /// Sets the margins from an [`NcMargins`].
+    pub fn margins_from(self, margins: impl Into<NcMargins>) -> Self {
+        let m = margins.into();
+        self.margins(m.top, m.right, m.bottom, m.left)
+    }
+
     /// Sets the top margin.
     pub fn margin_top(mut self, top: u32) -> Self {
         self.margin_t = top;
@@ -0,0 +1,110 @@
+//! `NcMargins`
+
+/// The margins of an [`Nc`][crate::Nc], in cells.
+///
+/// It can be parsed from a string following the notcurses definition:
+/// either a single number for all the margins, or four numbers separated by
+/// commas, in the order top, right, bottom, left. Its `Display`
+/// implementation writes the four numbers, so they round trip.
+///
+/// # Example
+/// ```
+/// # use libnotcurses_sys::*;
+/// let margins: NcMargins = "1,2,3,4".parse().unwrap();
+/// let options = NcOptions::builder().margins_from(margins);
+/// assert_eq![(1, 2, 3, 4), options.get_margins()];
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NcMargins {
+    /// The top margin.
+    pub top: u32,
+    /// The right margin.
+    pub right: u32,
+    /// The bottom margin.
+    pub bottom: u32,
+    /// The left margin.
+    pub left: u32,
+}
+
+/// # Constructors
+impl NcMargins {
+    /// New `NcMargins`.
+    pub fn new(top: u32, right: u32, bottom: u32, left: u32) -> Self {
+        Self { top, right, bottom, left }
+    }
+
+    /// New `NcMargins`, with the same `margin` on all sides.
+    pub fn all(margin: u32) -> Self {
+        Self::new(margin, margin, margin, margin)
+    }
+}
+
+mod core_impls {
+    use super::NcMargins;
+    use crate::NcError;
+    use core::{fmt, str::FromStr};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, vec::Vec};
+
+    impl fmt::Display for NcMargins {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f,
+                "{},{},{},{}",
+                self.top, self.right, self.bottom, self.left
+            )
+        }
+    }
+
+    impl FromStr for NcMargins {
+        type Err = NcError;
+
+        /// Parses either a single number, or four numbers separated by commas.
+        ///
+        /// *Equivalent C style function:
+        /// [notcurses_lex_margins()][crate::c_api::notcurses_lex_margins].*
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let numbers: Result<Vec<u32>, _> = s.split(',').map(|n| n.trim().parse()).collect();
+            match numbers.as_deref() {
+                Ok(&[margin]) => Ok(NcMargins::all(margin)),
+                Ok(&[top, right, bottom, left]) => Ok(NcMargins::new(top, right, bottom, left)),
+                _ => Err(NcError::new_msg(&format!["NcMargins::from_str({:?})", s])),
+            }
+        }
+    }
+
+    impl From<(u32, u32, u32, u32)> for NcMargins {
+        fn from((top, right, bottom, left): (u32, u32, u32, u32)) -> Self {
+            Self::new(top, right, bottom, left)
+        }
+    }
+
+    impl From<NcMargins> for (u32, u32, u32, u32) {
+        fn from(m: NcMargins) -> Self {
+            (m.top, m.right, m.bottom, m.left)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NcMargins;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    #[test]
+    fn margins_from_str() {
+        assert_eq![Ok(NcMargins::all(2)), "2".parse().map_err(|_| ())];
+        assert_eq![
+            Ok(NcMargins::new(1, 2, 3, 4)),
+            "1, 2,3,4".parse().map_err(|_| ())
+        ];
+        assert!["1,2".parse::<NcMargins>().is_err()];
+        assert!["-1".parse::<NcMargins>().is_err()];
+
+        let margins = NcMargins::new(0, 5, 0, 5);
+        assert_eq![Ok(margins), format!["{}", margins].parse().map_err(|_| ())];
+    }
+}
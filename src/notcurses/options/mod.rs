@@ -5,9 +5,11 @@ use core::ptr::null;
 
 mod builder;
 pub(crate) mod flags;
+mod margins;
 
 pub use builder::NcOptionsBuilder;
 pub use flags::NcFlag;
+pub use margins::NcMargins;
 
 /// Options struct for [`Nc`][crate::Nc].
 ///
@@ -2,3 +2,19 @@
 
 // use crate::Notcurses;
 // use serial_test::serial;
+
+use crate::Nc;
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+#[test]
+fn notcurses_bindings_version() {
+    let (major, minor, patch) = Nc::bindings_version();
+    assert_eq![3, major];
+    assert_eq![
+        Nc::BINDINGS_VERSION,
+        format!["{}.{}.{}", major, minor, patch]
+    ];
+    assert![(major, minor, patch) > (2, 99, 99)];
+}
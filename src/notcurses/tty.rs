@@ -0,0 +1,89 @@
+//! The terminal modes saved and restored by `Nc.suspend` and `Nc.resume`.
+
+use core::{
+    ffi::c_int,
+    mem::MaybeUninit,
+    sync::atomic::{AtomicBool, AtomicU32},
+};
+
+use crate::{NcError, NcResult};
+
+/// Whether the program draws in the alternate screen, as last set through
+/// `Nc.with_options`, `Nc.enter_alternate_screen` and `Nc.leave_alternate_screen`.
+pub(crate) static ALTERNATE: AtomicBool = AtomicBool::new(false);
+
+/// The mice events enabled, as last set through `Nc.mice_enable`.
+pub(crate) static MICE: AtomicU32 = AtomicU32::new(0);
+
+/// Shows the cursor, pops the kitty keyboard protocol and resets xterm's
+/// `modifyFunctionKeys` and `modifyOtherKeys`.
+pub(crate) const SUSPEND: &[u8] = b"\x1b[?25h\x1b[<u\x1b[>2m\x1b[>4m";
+
+/// Hides the cursor, pushes the kitty keyboard protocol and sets xterm's
+/// `modifyFunctionKeys` and `modifyOtherKeys`, as notcurses does.
+pub(crate) const RESUME: &[u8] = b"\x1b[?25l\x1b[>u\x1b[=11u\x1b[>2;1m\x1b[>4;1m";
+
+/// The controlling terminal, closed when dropped.
+pub(crate) struct Tty(c_int);
+
+impl Drop for Tty {
+    fn drop(&mut self) {
+        unsafe { libc::close(self.0) };
+    }
+}
+
+impl Tty {
+    /// Opens the controlling terminal.
+    pub(crate) fn open(errmsg: &str) -> NcResult<Self> {
+        let fd = unsafe {
+            libc::open(
+                b"/dev/tty\0".as_ptr() as *const _,
+                libc::O_RDWR | libc::O_CLOEXEC,
+            )
+        };
+        if fd < 0 {
+            return Err(NcError::with_msg(fd, errmsg));
+        }
+        Ok(Self(fd))
+    }
+
+    /// Writes all the `bytes`.
+    pub(crate) fn write(&self, mut bytes: &[u8], errmsg: &str) -> NcResult<()> {
+        while !bytes.is_empty() {
+            let res = unsafe { libc::write(self.0, bytes.as_ptr() as *const _, bytes.len()) };
+            if res < 0 {
+                return Err(NcError::with_msg(-1, errmsg));
+            }
+            bytes = &bytes[res as usize..];
+        }
+        Ok(())
+    }
+
+    /// Returns the current modes.
+    pub(crate) fn termios(&self, errmsg: &str) -> NcResult<libc::termios> {
+        let mut termios = MaybeUninit::<libc::termios>::uninit();
+        if unsafe { libc::tcgetattr(self.0, termios.as_mut_ptr()) } != 0 {
+            return Err(NcError::with_msg(-1, errmsg));
+        }
+        Ok(unsafe { termios.assume_init() })
+    }
+
+    /// Sets the `termios` modes, once all the output has been written.
+    pub(crate) fn set_termios(&self, termios: &libc::termios, errmsg: &str) -> NcResult<()> {
+        if unsafe { libc::tcsetattr(self.0, libc::TCSADRAIN, termios) } != 0 {
+            return Err(NcError::with_msg(-1, errmsg));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the `raw` modes set by notcurses, turned into the cooked ones
+/// a shell expects: line editing, echo, signals and output processing.
+pub(crate) fn cooked(raw: &libc::termios) -> libc::termios {
+    let mut termios = *raw;
+    termios.c_iflag |= libc::ICRNL | libc::IXON;
+    termios.c_oflag |= libc::OPOST;
+    termios.c_lflag |=
+        libc::ICANON | libc::ECHO | libc::ECHOE | libc::ECHOK | libc::ISIG | libc::IEXTEN;
+    termios
+}
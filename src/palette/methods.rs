@@ -1,6 +1,17 @@
 //! `NcPalette` methods and associated functions.
 
-use crate::{c_api, error, Nc, NcPalette, NcPaletteIndex, NcResult, NcRgb};
+use core::{
+    fmt::Write,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    c_api, error, Nc, NcChannel, NcError, NcPalette, NcPaletteIndex, NcPixel, NcResult, NcRgb,
+    NcVisual,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
 
 impl NcPalette {
     /// Returns a new `NcPalette`.
@@ -41,4 +52,426 @@ impl NcPalette {
     pub fn set(&mut self, index: impl Into<NcPaletteIndex>, rgb: impl Into<NcRgb>) {
         c_api::ncpalette_set(self, index.into(), rgb.into().into())
     }
+
+    /// Fills the entries from `start` to `end` (both inclusive) of this
+    /// `NcPalette` with a linear gradient from `from_rgb` to `to_rgb`.
+    ///
+    /// If `start` is greater than `end` the gradient is written backwards.
+    pub fn fill_gradient(
+        &mut self,
+        start: impl Into<NcPaletteIndex>,
+        end: impl Into<NcPaletteIndex>,
+        from_rgb: impl Into<NcRgb>,
+        to_rgb: impl Into<NcRgb>,
+    ) {
+        let (start, end) = (usize::from(start.into()), usize::from(end.into()));
+        let (from, to) = (NcChannel::from_rgb(from_rgb), NcChannel::from_rgb(to_rgb));
+        let steps = start.abs_diff(end);
+        for step in 0..=steps {
+            let index = if start <= end { start + step } else { start - step };
+            let t = if steps == 0 { 0.0 } else { step as f32 / steps as f32 };
+            self.chans[index] = from.lerp(to, t).into();
+        }
+    }
+
+    /// Returns the RGB components of the [`NcChannel`] entry
+    /// inside this `NcPalette`.
+    ///
+    /// *C style function: [ncpalette_get_rgb8()][c_api::ncpalette_get_rgb8].*
+    pub fn get_rgb8(&self, index: impl Into<NcPaletteIndex>) -> (u8, u8, u8) {
+        let (mut r, mut g, mut b) = (0, 0, 0);
+        c_api::ncpalette_get_rgb8(self, index.into(), &mut r, &mut g, &mut b);
+        (r, g, b)
+    }
+
+    /// Sets the RGB components of the [`NcChannel`] entry
+    /// inside this `NcPalette`.
+    ///
+    /// *C style function: [ncpalette_set_rgb8()][c_api::ncpalette_set_rgb8].*
+    pub fn set_rgb8(&mut self, index: impl Into<NcPaletteIndex>, red: u8, green: u8, blue: u8) {
+        c_api::ncpalette_set_rgb8(self, index.into(), red, green, blue)
+    }
+
+    /// Sets the same [`NcRgb`] value to all the [`NcChannel`] entries
+    /// inside the `range` of indices of this `NcPalette`.
+    pub fn set_range(&mut self, range: impl RangeBounds<u8>, rgb: impl Into<NcRgb>) {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i as usize,
+            Bound::Excluded(&i) => i as usize + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i as usize + 1,
+            Bound::Excluded(&i) => i as usize,
+            Bound::Unbounded => Self::SIZE as usize,
+        };
+        let rgb = rgb.into();
+        if start < end {
+            for channel in &mut self.as_mut_slice()[start..end] {
+                channel.set(rgb);
+            }
+        }
+    }
+
+    /// Returns a slice over the 256 [`NcChannel`]s of this `NcPalette`.
+    pub fn as_slice(&self) -> &[NcChannel] {
+        // SAFETY: NcChannel is repr(transparent) over NcChannel_u32
+        unsafe {
+            core::slice::from_raw_parts(self.chans.as_ptr() as *const NcChannel, self.chans.len())
+        }
+    }
+
+    /// Returns a mutable slice over the 256 [`NcChannel`]s of this `NcPalette`.
+    pub fn as_mut_slice(&mut self) -> &mut [NcChannel] {
+        // SAFETY: NcChannel is repr(transparent) over NcChannel_u32
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.chans.as_mut_ptr() as *mut NcChannel,
+                self.chans.len(),
+            )
+        }
+    }
+
+    /// Returns an iterator over the 256 [`NcChannel`]s of this `NcPalette`.
+    pub fn iter(&self) -> core::slice::Iter<'_, NcChannel> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a mutable iterator over the 256 [`NcChannel`]s of this `NcPalette`.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, NcChannel> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+/// # Color lookup
+impl NcPalette {
+    /// Returns the index of the entry of this `NcPalette` whose color is
+    /// perceptually nearest to `rgb`.
+    ///
+    /// Uses a weighted ("redmean") RGB distance. On ties returns the lowest index.
+    pub fn nearest(&self, rgb: impl Into<NcRgb>) -> NcPaletteIndex {
+        let (r, g, b) = rgb.into().into();
+        let mut nearest = (0, u32::MAX);
+        for (index, channel) in self.iter().enumerate() {
+            let distance = color_distance((r, g, b), channel.rgb8());
+            if distance < nearest.1 {
+                nearest = (index, distance);
+            }
+        }
+        NcPaletteIndex(nearest.0 as u8)
+    }
+
+    /// Returns the index of the nearest entry of this `NcPalette` for each
+    /// of the `rgbs`.
+    ///
+    /// See [`nearest`][NcPalette#method.nearest].
+    pub fn nearest_many(&self, rgbs: &[NcRgb]) -> Vec<NcPaletteIndex> {
+        rgbs.iter().map(|&rgb| self.nearest(rgb)).collect()
+    }
+}
+
+/// # Import & export
+impl NcPalette {
+    /// Returns a new `NcPalette` parsed from the contents of a GIMP palette
+    /// (`.gpl`) file.
+    ///
+    /// The colors are assigned to consecutive indices starting at 0. The
+    /// entries not defined in the file are left as black.
+    ///
+    /// Returns an error if the header is missing, a color line is malformed,
+    /// or there are more than 256 colors.
+    pub fn from_gpl_str(gpl: &str) -> NcResult<NcPalette> {
+        let mut lines = gpl.lines().map(str::trim).filter(|l| !l.is_empty());
+        if lines.next() != Some("GIMP Palette") {
+            return Err(NcError::new_msg("NcPalette.from_gpl_str(): missing header"));
+        }
+
+        let mut palette = NcPalette { chans: [0; Self::SIZE as usize] };
+        let mut index = 0;
+        for line in lines {
+            if line.starts_with('#') || line.starts_with("Name:") || line.starts_with("Columns:") {
+                continue;
+            }
+            if index >= Self::SIZE as usize {
+                return Err(NcError::new_msg(
+                    "NcPalette.from_gpl_str(): too many colors",
+                ));
+            }
+
+            let mut rgb = [0_u8; 3];
+            let mut fields = line.split_whitespace();
+            for component in &mut rgb {
+                *component = fields.next().and_then(|f| f.parse().ok()).ok_or_else(|| {
+                    NcError::new_msg(&format!["NcPalette.from_gpl_str(): invalid line {line:?}"])
+                })?;
+            }
+            palette.chans[index] = NcChannel::from_rgb(rgb).into();
+            index += 1;
+        }
+        Ok(palette)
+    }
+
+    /// Returns the contents of this `NcPalette` as a GIMP palette (`.gpl`)
+    /// with the provided `name`, and all of its 256 entries.
+    pub fn to_gpl_string(&self, name: &str) -> String {
+        let mut gpl = String::new();
+        let _ = write!(gpl, "GIMP Palette\nName: {name}\nColumns: 16\n#\n");
+        for (index, channel) in self.iter().enumerate() {
+            let (r, g, b) = channel.rgb8();
+            let _ = writeln!(gpl, "{r:3} {g:3} {b:3}\tIndex {index}");
+        }
+        gpl
+    }
+
+    /// Returns a new `NcPalette` parsed from lines of hexadecimal RGB colors.
+    ///
+    /// Each color may be written as `RRGGBB`, `#RRGGBB` or `0xRRGGBB`, and is
+    /// assigned to consecutive indices starting at 0. Empty lines and lines
+    /// starting with `;` or `//` are ignored. The entries not defined are left
+    /// as black.
+    ///
+    /// Returns an error if a line is malformed, or there are more than 256 colors.
+    pub fn from_hex_lines(hex: &str) -> NcResult<NcPalette> {
+        let mut palette = NcPalette { chans: [0; Self::SIZE as usize] };
+        let mut index = 0;
+        for line in hex.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with(';') || line.starts_with("//") {
+                continue;
+            }
+            if index >= Self::SIZE as usize {
+                return Err(NcError::new_msg(
+                    "NcPalette.from_hex_lines(): too many colors",
+                ));
+            }
+
+            let digits = line
+                .strip_prefix('#')
+                .or_else(|| line.strip_prefix("0x"))
+                .or_else(|| line.strip_prefix("0X"))
+                .unwrap_or(line);
+            let is_hex = digits.len() == 6 && digits.bytes().all(|b| b.is_ascii_hexdigit());
+            let rgb = match u32::from_str_radix(digits, 16) {
+                Ok(rgb) if is_hex => rgb,
+                _ => {
+                    return Err(NcError::new_msg(&format![
+                        "NcPalette.from_hex_lines(): invalid line {line:?}"
+                    ]))
+                }
+            };
+            palette.chans[index] = NcChannel::from_rgb(rgb).into();
+            index += 1;
+        }
+        Ok(palette)
+    }
+}
+
+/// # Quantization
+impl NcPalette {
+    /// Returns a new `NcPalette` with up to `ncolors` colors (max 256)
+    /// chosen to best represent the pixels of the `visual`.
+    ///
+    /// Fully transparent pixels are ignored. See [`from_pixels`] for details.
+    ///
+    /// Returns an error if the geometry or a pixel of the `visual`
+    /// can't be retrieved.
+    ///
+    /// [`from_pixels`]: NcPalette#method.from_pixels
+    pub fn from_visual(visual: &NcVisual, ncolors: u32) -> NcResult<NcPalette> {
+        let (rows, cols) = visual
+            .geom(None, None)?
+            .pix_yx
+            .ok_or_else(|| NcError::new_msg("NcPalette.from_visual(): no pixel geometry"))?;
+
+        let mut pixels = Vec::with_capacity((rows * cols) as usize);
+        for y in 0..rows {
+            for x in 0..cols {
+                pixels.push(visual.at_yx(y, x)?);
+            }
+        }
+        Ok(Self::from_pixels(&pixels, ncolors))
+    }
+
+    /// Returns a new `NcPalette` with up to `ncolors` colors (max 256)
+    /// chosen to best represent the provided `pixels`, using median cut
+    /// quantization.
+    ///
+    /// Fully transparent pixels are ignored. The colors are assigned to
+    /// consecutive indices starting at 0, and the rest are left as black.
+    pub fn from_pixels(pixels: &[NcPixel], ncolors: u32) -> NcPalette {
+        let mut palette = NcPalette { chans: [0; Self::SIZE as usize] };
+
+        let colors: Vec<[u8; 3]> = pixels
+            .iter()
+            .filter(|p| p.a() > 0)
+            .map(|p| [p.r(), p.g(), p.b()])
+            .collect();
+        if colors.is_empty() {
+            return palette;
+        }
+        let ncolors = ncolors.clamp(1, Self::SIZE) as usize;
+
+        // repeatedly split the box with the widest component range at its median
+        let mut boxes = vec![colors];
+        while boxes.len() < ncolors {
+            let widest = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| b.len() > 1)
+                .map(|(i, b)| (i, widest_component(b)))
+                .max_by_key(|&(_, (_, range))| range);
+            let (index, component) = match widest {
+                Some((i, (component, range))) if range > 0 => (i, component),
+                _ => break,
+            };
+            let mut colors = boxes.swap_remove(index);
+            colors.sort_unstable_by_key(|c| c[component]);
+            let upper = colors.split_off(colors.len() / 2);
+            boxes.push(colors);
+            boxes.push(upper);
+        }
+
+        for (chan, colors) in palette.chans.iter_mut().zip(&boxes) {
+            let mut sum = [0_u64; 3];
+            for color in colors {
+                for (s, &c) in sum.iter_mut().zip(color) {
+                    *s += c as u64;
+                }
+            }
+            let len = colors.len() as u64;
+            let avg = sum.map(|s| ((s + len / 2) / len) as u8);
+            *chan = NcChannel::from_rgb(avg).into();
+        }
+        palette
+    }
+}
+
+/// Returns the index of the RGB component with the widest range in `colors`,
+/// and that range.
+fn widest_component(colors: &[[u8; 3]]) -> (usize, u8) {
+    let mut min = [u8::MAX; 3];
+    let mut max = [u8::MIN; 3];
+    for color in colors {
+        for c in 0..3 {
+            min[c] = min[c].min(color[c]);
+            max[c] = max[c].max(color[c]);
+        }
+    }
+    (0..3)
+        .map(|c| (c, max[c] - min[c]))
+        .max_by_key(|&(_, range)| range)
+        .unwrap_or((0, 0))
+}
+
+/// # Terminal
+#[cfg(all(feature = "std", unix))]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+impl NcPalette {
+    /// Returns a new `NcPalette` filled with the actual colors of the
+    /// terminal palette, queried with OSC 4 escape sequences.
+    ///
+    /// Only the first [`palette_size`][Nc#method.palette_size] entries are
+    /// queried. The entries the terminal doesn't reply to in time are left
+    /// as black.
+    ///
+    /// Note that the replies are read directly from the controlling terminal,
+    /// so any input pending at the time can be lost, and it can also
+    /// compete with notcurses' own input processing.
+    ///
+    /// Returns an error if the terminal can't be accessed, or if it doesn't
+    /// reply to any of the queries.
+    pub fn from_terminal(nc: &mut Nc) -> NcResult<NcPalette> {
+        use std::{
+            fs::OpenOptions,
+            io::{Read, Write as _},
+            os::unix::io::AsRawFd,
+        };
+
+        let err = |msg: &str| NcError::new_msg(&format!["NcPalette.from_terminal(): {msg}"]);
+
+        let size = nc.palette_size()?.min(Self::SIZE);
+        let mut tty = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .map_err(|e| err(&e.to_string()))?;
+
+        let mut query = String::new();
+        for index in 0..size {
+            let _ = write!(query, "\x1b]4;{index};?\x1b\\");
+        }
+        tty.write_all(query.as_bytes())
+            .and_then(|_| tty.flush())
+            .map_err(|e| err(&e.to_string()))?;
+
+        // read until the terminal stops replying for 100ms
+        let mut palette = NcPalette { chans: [0; Self::SIZE as usize] };
+        let mut reply = Vec::new();
+        let mut buf = [0_u8; 1024];
+        let mut pollfd = libc::pollfd { fd: tty.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+        while unsafe { libc::poll(&mut pollfd, 1, 100) } > 0 {
+            match tty.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(len) => reply.extend_from_slice(&buf[..len]),
+            }
+            if palette.set_from_osc4_replies(&reply) >= size as usize {
+                break;
+            }
+        }
+
+        if palette.set_from_osc4_replies(&reply) == 0 {
+            return Err(err("no reply from the terminal"));
+        }
+        Ok(palette)
+    }
+}
+
+#[cfg(any(test, all(feature = "std", unix)))]
+impl NcPalette {
+    /// Parses the OSC 4 replies (`ESC ] 4 ; index ; rgb:RR/GG/BB` terminated
+    /// by `BEL` or `ESC \`) found in `bytes` into this `NcPalette`, and
+    /// returns how many entries were set.
+    ///
+    /// Each component can have from 1 to 4 hexadecimal digits.
+    pub(crate) fn set_from_osc4_replies(&mut self, bytes: &[u8]) -> usize {
+        fn component(hex: &str) -> Option<u8> {
+            if hex.is_empty() || hex.len() > 4 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            let max = (1_u32 << (4 * hex.len())) - 1;
+            u32::from_str_radix(hex, 16)
+                .ok()
+                .map(|v| ((v * 255 + max / 2) / max) as u8)
+        }
+
+        let text = String::from_utf8_lossy(bytes);
+        let mut count = 0;
+        for reply in text.split("\x1b]4;").skip(1) {
+            let end = reply.find(['\x07', '\x1b']).unwrap_or(reply.len());
+            let mut fields = reply[..end].splitn(2, ';');
+            let index = fields.next().and_then(|i| i.parse::<u8>().ok());
+            let rgb = fields
+                .next()
+                .and_then(|c| c.strip_prefix("rgb:"))
+                .and_then(|c| {
+                    let mut c = c.split('/').map(component);
+                    Some((c.next()??, c.next()??, c.next()??))
+                });
+            if let (Some(index), Some((r, g, b))) = (index, rgb) {
+                c_api::ncpalette_set_rgb8(self, index, r, g, b);
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+/// Returns the weighted ("redmean") squared distance between two colors.
+fn color_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let rmean = (a.0 as i32 + b.0 as i32) / 2;
+    let (dr, dg, db) = (
+        a.0 as i32 - b.0 as i32,
+        a.1 as i32 - b.1 as i32,
+        a.2 as i32 - b.2 as i32,
+    );
+    ((((512 + rmean) * dr * dr) >> 8) + 4 * dg * dg + (((767 - rmean) * db * db) >> 8)) as u32
 }
@@ -15,16 +15,14 @@
 //
 // functions manually reimplemented: 4
 // -----------------------------------------
-// (+) done: 3 / 0
-// (#) test: 0
-// (W) wrap: 3 / 0
+// (+) done: 4 / 0
+// (#) test: 4
+// (W) wrap: 4 / 0
 // -----------------------------------------
-//W+ ncpalette_get
-//W  ncpalette_get_rgb
-// + ncpalette_get_rgb8   // unneeded method
-//W+ ncpalette_set
-//W  ncpalette_set_rgb
-// + ncpalette_set_rgb8   // unneeded method
+//W# ncpalette_get
+//W# ncpalette_get_rgb8
+//W# ncpalette_set
+//W# ncpalette_set_rgb8
 
 use crate::c_api::ffi;
 
@@ -44,17 +42,260 @@ pub use methods::*;
 /// `type in C: ncncpalette (struct)`
 pub type NcPalette = ffi::ncpalette;
 
-/// Used for indexing into a [`NcPalette`] (alias of `u8`).
-pub type NcPaletteIndex = u8;
+/// Used for indexing into a [`NcPalette`].
+///
+/// Unlike a bare `u8`, converting from a wider integer with [`TryFrom`]
+/// validates it against [`NcPalette::SIZE`], instead of silently truncating it.
+///
+/// `type in C: unsigned (int)`
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NcPaletteIndex(pub c_api::NcPaletteIndex_u8);
+
+impl NcPaletteIndex {
+    /// New `NcPaletteIndex`.
+    pub const fn new(index: u8) -> Self {
+        Self(index)
+    }
+}
 
 impl NcPalette {
     /// The supported palette-indexed colors number is up to 8 bits.
     pub const SIZE: u32 = c_api::NCPALETTE_SIZE;
 }
 
+mod core_impls {
+    use super::{c_api::NcPaletteIndex_u8, NcPalette, NcPaletteIndex};
+    use crate::{NcChannel, NcError};
+    use core::ops::{Index, IndexMut};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    crate::from_primitive![NcPaletteIndex, NcPaletteIndex_u8];
+    crate::unit_impl_from![NcPaletteIndex, NcPaletteIndex_u8];
+    crate::unit_impl_fmt![bases+display; NcPaletteIndex];
+
+    impl From<NcPaletteIndex> for usize {
+        fn from(index: NcPaletteIndex) -> Self {
+            index.0 as usize
+        }
+    }
+
+    macro_rules! impl_try_from [
+        ($($int:ty),+) => { $(
+            impl TryFrom<$int> for NcPaletteIndex {
+                type Error = NcError;
+                fn try_from(index: $int) -> Result<Self, Self::Error> {
+                    NcPaletteIndex_u8::try_from(index).map(Self).map_err(|_| {
+                        NcError::new_msg(&format![
+                            "NcPaletteIndex: {} is out of range 0..{}",
+                            index,
+                            NcPalette::SIZE
+                        ])
+                    })
+                }
+            }
+        )+ };
+    ];
+    impl_try_from![u16, u32, u64, usize, i8, i16, i32, i64, isize];
+
+    impl Index<NcPaletteIndex> for NcPalette {
+        type Output = NcChannel;
+        fn index(&self, index: NcPaletteIndex) -> &Self::Output {
+            &self.as_slice()[usize::from(index)]
+        }
+    }
+    impl IndexMut<NcPaletteIndex> for NcPalette {
+        fn index_mut(&mut self, index: NcPaletteIndex) -> &mut Self::Output {
+            &mut self.as_mut_slice()[usize::from(index)]
+        }
+    }
+
+    impl<'a> IntoIterator for &'a NcPalette {
+        type Item = &'a NcChannel;
+        type IntoIter = core::slice::Iter<'a, NcChannel>;
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+    impl<'a> IntoIterator for &'a mut NcPalette {
+        type Item = &'a mut NcChannel;
+        type IntoIter = core::slice::IterMut<'a, NcChannel>;
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::{NcChannel, NcPalette, NcPaletteIndex, NcPixel, NcRgb};
+
+        #[test]
+        fn palette_index_iter() {
+            let mut palette = NcPalette { chans: [0; 256] };
+            assert_eq![256, palette.iter().count()];
+
+            palette[NcPaletteIndex(3)] = NcChannel::from_rgb(0x112233);
+            assert_eq![NcRgb(0x112233), palette.get(3)];
+            assert_eq![NcChannel::from_rgb(0x112233), palette[NcPaletteIndex(3)]];
+
+            palette.set_range(10..=20, 0x445566);
+            assert_eq![NcRgb(0), palette.get(9)];
+            assert_eq![NcRgb(0x445566), palette.get(10)];
+            assert_eq![NcRgb(0x445566), palette.get(20)];
+            assert_eq![NcRgb(0), palette.get(21)];
+            assert_eq![
+                11,
+                palette
+                    .iter()
+                    .filter(|c| c.rgb() == NcRgb(0x445566))
+                    .count()
+            ];
+
+            palette.set_range(.., 0x778899);
+            assert![(&palette).into_iter().all(|c| c.rgb() == NcRgb(0x778899))];
+        }
+
+        #[test]
+        fn palette_index() {
+            assert_eq![
+                NcPaletteIndex(255),
+                NcPaletteIndex::try_from(255_u32).unwrap()
+            ];
+            assert_eq![NcPaletteIndex(0), NcPaletteIndex::try_from(0_i32).unwrap()];
+            assert![NcPaletteIndex::try_from(256_u32).is_err()];
+            assert![NcPaletteIndex::try_from(-1_i32).is_err()];
+            assert_eq![200_usize, NcPaletteIndex::new(200).into()];
+        }
+
+        #[test]
+        fn palette_get_set() {
+            let mut palette = NcPalette { chans: [0; 256] };
+
+            palette.set(0, 0x112233);
+            assert_eq![NcRgb(0x112233), palette.get(0)];
+            assert_eq![(0x11, 0x22, 0x33), palette.get_rgb8(0)];
+
+            palette.set_rgb8(255, 0x44, 0x55, 0x66);
+            assert_eq![NcRgb(0x445566), palette.get(255)];
+            assert_eq![(0x44, 0x55, 0x66), palette.get_rgb8(255)];
+            assert_eq![NcChannel::from_rgb(0x445566), palette[NcPaletteIndex(255)]];
+        }
+
+        #[test]
+        fn palette_fill_gradient() {
+            let mut palette = NcPalette { chans: [0; 256] };
+
+            palette.fill_gradient(16, 20, 0x000000, 0x0000FF);
+            assert_eq![NcRgb(0), palette.get(15)];
+            assert_eq![NcRgb(0x000000), palette.get(16)];
+            assert_eq![NcRgb(0x000040), palette.get(17)];
+            assert_eq![NcRgb(0x000080), palette.get(18)];
+            assert_eq![NcRgb(0x0000BF), palette.get(19)];
+            assert_eq![NcRgb(0x0000FF), palette.get(20)];
+            assert_eq![NcRgb(0), palette.get(21)];
+
+            // backwards
+            palette.fill_gradient(255, 254, 0xFF0000, 0x00FF00);
+            assert_eq![NcRgb(0x00FF00), palette.get(254)];
+            assert_eq![NcRgb(0xFF0000), palette.get(255)];
+
+            // single entry
+            palette.fill_gradient(0, 0, 0x112233, 0x445566);
+            assert_eq![NcRgb(0x112233), palette.get(0)];
+        }
+
+        #[test]
+        fn palette_nearest() {
+            let mut palette = NcPalette { chans: [0; 256] };
+            palette.set_range(.., 0xFFFFFF);
+            palette.set(10, 0xFF0000);
+            palette.set(20, 0x00FF00);
+            palette.set(30, 0x0000FF);
+
+            assert_eq![NcPaletteIndex(10), palette.nearest(0xE01010)];
+            assert_eq![NcPaletteIndex(20), palette.nearest(0x20C020)];
+            assert_eq![NcPaletteIndex(30), palette.nearest(0x000080)];
+            assert_eq![NcPaletteIndex(0), palette.nearest(0xF0F0F0)];
+            assert_eq![
+                [NcPaletteIndex(30), NcPaletteIndex(10)],
+                palette.nearest_many(&[NcRgb(0x101090), NcRgb(0x900000)])[..]
+            ];
+        }
+
+        #[test]
+        fn palette_osc4_replies() {
+            let mut palette = NcPalette { chans: [0; 256] };
+            let replies =
+                b"\x1b]4;1;rgb:ffff/0000/8080\x1b\\\x1b]4;2;rgb:12/34/56\x07\x1b]4;3;?\x07";
+            assert_eq![2, palette.set_from_osc4_replies(replies)];
+            assert_eq![NcRgb(0), palette.get(0)];
+            assert_eq![NcRgb(0xFF0080), palette.get(1)];
+            assert_eq![NcRgb(0x123456), palette.get(2)];
+            assert_eq![NcRgb(0), palette.get(3)];
+        }
+
+        #[test]
+        fn palette_gpl_hex() {
+            let gpl = "GIMP Palette\nName: test\n# comment\n255   0  0\tRed\n  0 128 255 Blue\n";
+            let palette = NcPalette::from_gpl_str(gpl).unwrap();
+            assert_eq![NcRgb(0xFF0000), palette.get(0)];
+            assert_eq![NcRgb(0x0080FF), palette.get(1)];
+            assert_eq![NcRgb(0), palette.get(2)];
+            assert![NcPalette::from_gpl_str("255 0 0").is_err()];
+            assert![NcPalette::from_gpl_str("GIMP Palette\n255 0").is_err()];
+
+            // roundtrip
+            let exported = palette.to_gpl_string("test");
+            assert![exported.starts_with("GIMP Palette\nName: test\n")];
+            let imported = NcPalette::from_gpl_str(&exported).unwrap();
+            assert![palette
+                .iter()
+                .map(|c| c.rgb())
+                .eq(imported.iter().map(|c| c.rgb()))];
+
+            let hex = "#FF0000\n; comment\n\n0x0080ff\n112233\n";
+            let palette = NcPalette::from_hex_lines(hex).unwrap();
+            assert_eq![NcRgb(0xFF0000), palette.get(0)];
+            assert_eq![NcRgb(0x0080FF), palette.get(1)];
+            assert_eq![NcRgb(0x112233), palette.get(2)];
+            assert![NcPalette::from_hex_lines("#12345").is_err()];
+            assert![NcPalette::from_hex_lines("zzzzzz").is_err()];
+            assert![NcPalette::from_hex_lines("+12345").is_err()];
+        }
+
+        #[test]
+        fn palette_from_pixels() {
+            let mut pixels = [NcPixel::from_rgb(0xFF0000); 10];
+            pixels[5..]
+                .iter_mut()
+                .for_each(|p| *p = NcPixel::from_rgb(0x0000FE));
+            pixels[9] = NcPixel::new(0x00_00FF00); // transparent
+
+            let palette = NcPalette::from_pixels(&pixels, 16);
+            let colors = [palette.get(0), palette.get(1)];
+            assert![colors.contains(&NcRgb(0xFF0000))];
+            assert![colors.contains(&NcRgb(0x0000FE))];
+            assert_eq![NcRgb(0), palette.get(2)];
+
+            // a single color averages everything
+            let palette = NcPalette::from_pixels(&pixels[3..7], 1);
+            assert_eq![NcRgb(0x80007F), palette.get(0)];
+
+            assert_eq![NcRgb(0), NcPalette::from_pixels(&[], 256).get(0)];
+        }
+    }
+}
+
 pub(crate) mod c_api {
     use super::ffi;
 
     /// The supported palette-indexed colors number is up to 8 bits.
     pub const NCPALETTE_SIZE: u32 = ffi::NCPALETTESIZE;
+
+    /// Used for indexing into a [`NcPalette`][crate::NcPalette].
+    ///
+    /// It's recommended to use [`NcPaletteIndex`][crate::NcPaletteIndex] instead.
+    pub type NcPaletteIndex_u8 = u8;
 }
@@ -1,8 +1,8 @@
 //! `ncpalette_*` reimplemented functions.
 
 use crate::{
-    c_api::{self, NcChannel_u32, NcRgb_u32},
-    NcPalette, NcPaletteIndex,
+    c_api::{self, NcChannel_u32, NcPaletteIndex_u8, NcRgb_u32},
+    NcPalette,
 };
 
 /// Returns the [`NcRgb_u32`] value from an [`NcChannel_u32`] entry inside
@@ -10,7 +10,7 @@ use crate::{
 ///
 /// *Method: NcPalette.[get()][NcPalette#method.get].*
 #[inline]
-pub fn ncpalette_get(palette: &NcPalette, index: impl Into<NcPaletteIndex>) -> NcRgb_u32 {
+pub fn ncpalette_get(palette: &NcPalette, index: impl Into<NcPaletteIndex_u8>) -> NcRgb_u32 {
     c_api::ncchannel_rgb(palette.chans[index.into() as usize])
 }
 
@@ -21,7 +21,7 @@ pub fn ncpalette_get(palette: &NcPalette, index: impl Into<NcPaletteIndex>) -> N
 #[inline]
 pub fn ncpalette_get_rgb8(
     palette: &NcPalette,
-    index: impl Into<NcPaletteIndex>,
+    index: impl Into<NcPaletteIndex_u8>,
     red: &mut u8,
     green: &mut u8,
     blue: &mut u8,
@@ -34,7 +34,7 @@ pub fn ncpalette_get_rgb8(
 ///
 /// *Method: NcPalette.[set()][NcPalette#method.set].*
 #[inline]
-pub fn ncpalette_set(palette: &mut NcPalette, index: impl Into<NcPaletteIndex>, rgb: NcRgb_u32) {
+pub fn ncpalette_set(palette: &mut NcPalette, index: impl Into<NcPaletteIndex_u8>, rgb: NcRgb_u32) {
     c_api::ncchannel_set(&mut palette.chans[index.into() as usize], rgb);
 }
 
@@ -45,7 +45,7 @@ pub fn ncpalette_set(palette: &mut NcPalette, index: impl Into<NcPaletteIndex>,
 #[inline]
 pub fn ncpalette_set_rgb8(
     palette: &mut NcPalette,
-    index: impl Into<NcPaletteIndex>,
+    index: impl Into<NcPaletteIndex_u8>,
     red: u8,
     green: u8,
     blue: u8,
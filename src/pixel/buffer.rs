@@ -0,0 +1,234 @@
+//! `NcPixelBuffer`
+
+use core::{
+    ops::Range,
+    slice::{Chunks, ChunksMut},
+};
+
+use crate::{NcError, NcPixel, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec, vec::Vec};
+
+/// An owned 2D buffer of [`NcPixel`]s, stored row by row.
+///
+/// It can be turned into an [`NcVisual`] with [`NcVisual::from_buffer`].
+///
+/// # Example
+/// ```
+/// # use libnotcurses_sys::*;
+/// let mut buffer = NcPixelBuffer::new(4, 8);
+/// buffer.fill_rect(1, 2, 2, 4, NcPixel::from_rgb(0xff0000));
+/// assert_eq![NcPixel::from_rgb(0xff0000), buffer[(1, 2)]];
+/// assert_eq![NcPixel::default(), buffer[(0, 0)]];
+/// ```
+///
+/// [`NcVisual`]: crate::NcVisual
+/// [`NcVisual::from_buffer`]: crate::NcVisual#method.from_buffer
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcPixelBuffer {
+    pixels: Vec<NcPixel>,
+    pixel_y: u32,
+    pixel_x: u32,
+}
+
+/// # Constructors
+impl NcPixelBuffer {
+    /// New buffer of `pixel_y` * `pixel_x` transparent black pixels.
+    pub fn new(pixel_y: u32, pixel_x: u32) -> Self {
+        Self::filled(pixel_y, pixel_x, NcPixel::default())
+    }
+
+    /// New buffer of `pixel_y` * `pixel_x` pixels, all set to `pixel`.
+    pub fn filled(pixel_y: u32, pixel_x: u32, pixel: impl Into<NcPixel>) -> Self {
+        let len = pixel_y as usize * pixel_x as usize;
+        Self { pixels: vec![pixel.into(); len], pixel_y, pixel_x }
+    }
+
+    /// New buffer of `pixel_y` * `pixel_x` `pixels`, stored row by row.
+    ///
+    /// Returns an error if there are not exactly that many `pixels`.
+    pub fn from_pixels(pixel_y: u32, pixel_x: u32, pixels: Vec<NcPixel>) -> NcResult<Self> {
+        if pixels.len() != pixel_y as usize * pixel_x as usize {
+            return Err(NcError::new_msg(&format![
+                "NcPixelBuffer::from_pixels({}, {}, pixels): got {} pixels",
+                pixel_y,
+                pixel_x,
+                pixels.len()
+            ]));
+        }
+        Ok(Self { pixels, pixel_y, pixel_x })
+    }
+}
+
+/// # Methods
+impl NcPixelBuffer {
+    /// Returns the dimensions of the buffer in pixels, as `(rows, columns)`.
+    pub fn pixel_yx(&self) -> (u32, u32) {
+        (self.pixel_y, self.pixel_x)
+    }
+
+    /// Returns the number of pixels.
+    pub fn len(&self) -> usize {
+        self.pixels.len()
+    }
+
+    /// Returns `true` if the buffer contains no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.pixels.is_empty()
+    }
+
+    /// Returns the pixels as a slice, row by row.
+    pub fn as_slice(&self) -> &[NcPixel] {
+        &self.pixels
+    }
+
+    /// Returns the pixels as a mutable slice, row by row.
+    pub fn as_mut_slice(&mut self) -> &mut [NcPixel] {
+        &mut self.pixels
+    }
+
+    /// Returns the pixels as bytes, in RGBA order.
+    pub fn to_rgba_bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|p| p.0.to_le_bytes()).collect()
+    }
+
+    /// Returns the pixel at `y`, `x`, if it's inside the buffer.
+    pub fn get(&self, y: u32, x: u32) -> Option<NcPixel> {
+        self.index_of(y, x).map(|i| self.pixels[i])
+    }
+
+    /// Returns a mutable reference to the pixel at `y`, `x`, if it's inside
+    /// the buffer.
+    pub fn get_mut(&mut self, y: u32, x: u32) -> Option<&mut NcPixel> {
+        self.index_of(y, x).map(|i| &mut self.pixels[i])
+    }
+
+    /// Returns an iterator over the rows of pixels.
+    pub fn rows(&self) -> Chunks<'_, NcPixel> {
+        self.pixels.chunks(self.pixel_x.max(1) as usize)
+    }
+
+    /// Returns an iterator over the mutable rows of pixels.
+    pub fn rows_mut(&mut self) -> ChunksMut<'_, NcPixel> {
+        self.pixels.chunks_mut(self.pixel_x.max(1) as usize)
+    }
+
+    /// Sets all the pixels to `pixel`.
+    pub fn fill(&mut self, pixel: impl Into<NcPixel>) {
+        self.pixels.fill(pixel.into());
+    }
+
+    /// Sets the pixels of a rectangle of `len_y` * `len_x` pixels starting at
+    /// `y`, `x` to `pixel`.
+    ///
+    /// The parts of the rectangle outside of the buffer are ignored.
+    pub fn fill_rect(&mut self, y: u32, x: u32, len_y: u32, len_x: u32, pixel: impl Into<NcPixel>) {
+        let pixel = pixel.into();
+        let (rows, cols) = self.clip(y, x, len_y, len_x);
+        for row in self.rows_mut().take(rows.end).skip(rows.start) {
+            row[cols.clone()].fill(pixel);
+        }
+    }
+
+    /// Copies the pixels of `source` into this buffer, with its origin at
+    /// `y`, `x`.
+    ///
+    /// The parts of `source` outside of this buffer are ignored.
+    pub fn blit(&mut self, source: &NcPixelBuffer, y: u32, x: u32) {
+        let (rows, cols) = self.clip(y, x, source.pixel_y, source.pixel_x);
+        let len = cols.len();
+        for (row, source_row) in self
+            .rows_mut()
+            .take(rows.end)
+            .skip(rows.start)
+            .zip(source.rows())
+        {
+            row[cols.clone()].copy_from_slice(&source_row[..len]);
+        }
+    }
+
+    /// Returns the index of the pixel at `y`, `x`, if it's inside the buffer.
+    fn index_of(&self, y: u32, x: u32) -> Option<usize> {
+        if y < self.pixel_y && x < self.pixel_x {
+            Some(y as usize * self.pixel_x as usize + x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ranges of rows and columns of a rectangle, clipped to the
+    /// buffer.
+    fn clip(&self, y: u32, x: u32, len_y: u32, len_x: u32) -> (Range<usize>, Range<usize>) {
+        let range = |start: u32, len: u32, max: u32| {
+            start.min(max) as usize..start.saturating_add(len).min(max) as usize
+        };
+        (range(y, len_y, self.pixel_y), range(x, len_x, self.pixel_x))
+    }
+}
+
+mod core_impls {
+    use super::NcPixelBuffer;
+    use crate::NcPixel;
+    use core::ops::{Index, IndexMut};
+
+    impl Index<(u32, u32)> for NcPixelBuffer {
+        type Output = NcPixel;
+
+        /// Returns the pixel at `(y, x)`.
+        ///
+        /// # Panics
+        /// Panics if the pixel is outside of the buffer.
+        fn index(&self, (y, x): (u32, u32)) -> &Self::Output {
+            match self.index_of(y, x) {
+                Some(i) => &self.pixels[i],
+                None => panic!["pixel {}x{} outside of {:?}", y, x, self.pixel_yx()],
+            }
+        }
+    }
+
+    impl IndexMut<(u32, u32)> for NcPixelBuffer {
+        /// Returns the pixel at `(y, x)`.
+        ///
+        /// # Panics
+        /// Panics if the pixel is outside of the buffer.
+        fn index_mut(&mut self, (y, x): (u32, u32)) -> &mut Self::Output {
+            let yx = self.pixel_yx();
+            match self.index_of(y, x) {
+                Some(i) => &mut self.pixels[i],
+                None => panic!["pixel {}x{} outside of {:?}", y, x, yx],
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NcPixel, NcPixelBuffer};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    #[test]
+    fn pixel_buffer() {
+        let red = NcPixel::from_rgb(0xff0000);
+        let mut buffer = NcPixelBuffer::new(3, 4);
+        assert_eq![(3, 4), buffer.pixel_yx()];
+        assert![NcPixelBuffer::from_pixels(2, 2, vec![red; 3]).is_err()];
+
+        // clipped to the buffer
+        buffer.fill_rect(1, 2, 5, 5, red);
+        assert_eq![red, buffer[(2, 3)]];
+        assert_eq![NcPixel::default(), buffer[(1, 1)]];
+        assert_eq![None, buffer.get(3, 0)];
+
+        let mut dest = NcPixelBuffer::new(3, 4);
+        dest.blit(&buffer, 1, 1);
+        assert_eq![red, dest[(2, 3)]];
+        assert_eq![NcPixel::default(), dest[(1, 2)]];
+
+        assert_eq![3, buffer.rows().count()];
+        dest.fill_rect(5, 5, 1, 1, red);
+        assert_eq![[0xff, 0, 0, 0xff], buffer.to_rgba_bytes()[24..28]];
+    }
+}
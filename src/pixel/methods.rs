@@ -102,5 +102,7 @@ mod tests {
         let abgr: NcPixel = rgba.into();
 
         assert_eq![0x44332211_u32, abgr.into()];
+        assert_eq![rgba, abgr.to_rgba()];
+        assert_eq![[0x11, 0x22, 0x33, 0x44], abgr.0.to_le_bytes()];
     }
 }
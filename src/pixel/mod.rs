@@ -27,12 +27,15 @@
 //W+ ncpixel_set_r
 //X  ncpixel_set_rgb8
 
+mod buffer;
 mod methods;
 pub(crate) mod reimplemented;
 
 mod pixel_impl;
 pub use pixel_impl::NcPixelImpl;
 
+pub use buffer::NcPixelBuffer;
+
 /// An ABGR pixel.
 ///
 /// ## Diagram
@@ -121,6 +124,63 @@ pub struct NcPixelGeometry {
     pub max_bitmap_x: u32,
 }
 
+/// # Methods
+impl NcPixelGeometry {
+    /// Returns the cell containing the pixel at `px_y`, `px_x`, and the
+    /// offset of the pixel inside it, as `((y, x), (off_y, off_x))`.
+    ///
+    /// Returns `None` if the cell-pixel geometry is unknown.
+    pub fn cell_of_pixel(&self, px_y: u32, px_x: u32) -> Option<((u32, u32), (u32, u32))> {
+        if self.cell_y == 0 || self.cell_x == 0 {
+            return None;
+        }
+        Some((
+            (px_y / self.cell_y, px_x / self.cell_x),
+            (px_y % self.cell_y, px_x % self.cell_x),
+        ))
+    }
+
+    /// Returns the pixel at the upper-left corner of the cell at `y`, `x`.
+    pub fn pixel_origin_of_cell(&self, y: u32, x: u32) -> (u32, u32) {
+        (y.saturating_mul(self.cell_y), x.saturating_mul(self.cell_x))
+    }
+
+    /// Returns the number of whole cells covered by the maximum displayable
+    /// bitmap, as `(rows, cols)`.
+    ///
+    /// Returns `(0, 0)` if bitmaps are not supported.
+    pub fn max_bitmap_cells(&self) -> (u32, u32) {
+        (
+            self.max_bitmap_y.checked_div(self.cell_y).unwrap_or(0),
+            self.max_bitmap_x.checked_div(self.cell_x).unwrap_or(0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NcPixelGeometry;
+
+    #[test]
+    fn pixel_geometry() {
+        let geom = NcPixelGeometry {
+            term_y: 480,
+            term_x: 800,
+            cell_y: 16,
+            cell_x: 8,
+            max_bitmap_y: 100,
+            max_bitmap_x: 100,
+        };
+        assert_eq![Some(((2, 1), (3, 4))), geom.cell_of_pixel(35, 12)];
+        assert_eq![(32, 8), geom.pixel_origin_of_cell(2, 1)];
+        assert_eq![(6, 12), geom.max_bitmap_cells()];
+
+        let unknown = NcPixelGeometry { cell_y: 0, cell_x: 0, ..geom };
+        assert_eq![None, unknown.cell_of_pixel(35, 12)];
+        assert_eq![(0, 0), unknown.max_bitmap_cells()];
+    }
+}
+
 pub(crate) mod c_api {
     pub use super::pixel_impl::c_api::*;
 
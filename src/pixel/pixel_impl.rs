@@ -31,6 +31,31 @@ pub enum NcPixelImpl {
     KittySelfRef = c_api::NCPIXEL_KITTY_SELFREF,
 }
 
+/// # Methods
+impl NcPixelImpl {
+    /// Returns `true` if there's some pixel support.
+    pub const fn is_supported(&self) -> bool {
+        !matches![self, NcPixelImpl::None]
+    }
+
+    /// Returns `true` if the implementation is one of the Kitty protocols.
+    pub const fn is_kitty(&self) -> bool {
+        use NcPixelImpl::*;
+        matches![self, KittyStatic | KittyAnimated | KittySelfRef]
+    }
+
+    /// Returns `true` if bitmaps can be animated in place, without being
+    /// wiped and redrawn.
+    pub const fn supports_animation(&self) -> bool {
+        matches![self, NcPixelImpl::KittyAnimated | NcPixelImpl::KittySelfRef]
+    }
+
+    /// Returns `true` if bitmaps support reflexive composition.
+    pub const fn supports_selfref(&self) -> bool {
+        matches![self, NcPixelImpl::KittySelfRef]
+    }
+}
+
 mod core_impls {
     use super::{c_api::*, NcPixelImpl};
     use core::fmt;
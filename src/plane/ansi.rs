@@ -0,0 +1,201 @@
+//! Parsing of the ANSI escape sequences supported by [`NcPlane.put_ansi`].
+//!
+//! [`NcPlane.put_ansi`]: crate::NcPlane#method.put_ansi
+
+use crate::{NcChannels, NcStyle};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A piece of text with ANSI escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NcAnsiToken<'a> {
+    /// Printable text, without control characters other than tabs.
+    Text(&'a str),
+    /// The parameters of a *Select Graphic Rendition* sequence.
+    Sgr(&'a str),
+    /// Moves the cursor forward a number of columns.
+    Forward(u32),
+    /// Moves the cursor to the first column.
+    CarriageReturn,
+    /// Moves the cursor to the next row.
+    LineFeed,
+}
+
+/// An iterator over the [`NcAnsiToken`]s of a string.
+///
+/// Unsupported escape sequences and control characters are skipped.
+/// Tabs are kept in the text, to be expanded when it's put.
+#[derive(Debug)]
+pub(crate) struct NcAnsiTokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> NcAnsiTokens<'a> {
+    pub(crate) fn new(string: &'a str) -> Self {
+        Self { rest: string }
+    }
+
+    /// Skips an escape sequence, returning it if it's a control sequence
+    /// (`ESC [`), as its `(parameters, final byte)`.
+    fn escape(&mut self) -> Option<(&'a str, u8)> {
+        let bytes = self.rest.as_bytes();
+        match bytes.get(1) {
+            Some(b'[') => {
+                let params_end = bytes[2..]
+                    .iter()
+                    .position(|b| !(0x20..0x40).contains(b))
+                    .map_or(bytes.len(), |i| i + 2);
+                match bytes.get(params_end) {
+                    Some(&fin) if (0x40..0x7f).contains(&fin) => {
+                        let params = &self.rest[2..params_end];
+                        self.rest = &self.rest[params_end + 1..];
+                        Some((params, fin))
+                    }
+                    // malformed, or truncated
+                    _ => {
+                        self.rest = &self.rest[params_end.min(bytes.len())..];
+                        None
+                    }
+                }
+            }
+            // operating system command, terminated by BEL or ST
+            Some(b']') => {
+                let rest = &self.rest[2..];
+                self.rest = match rest.find(['\x07', '\x1b']) {
+                    Some(i) if rest[i..].starts_with("\x1b\\") => &rest[i + 2..],
+                    Some(i) if rest.as_bytes()[i] == 0x07 => &rest[i + 1..],
+                    Some(i) => &rest[i..],
+                    None => "",
+                };
+                None
+            }
+            _ => {
+                let next = self.rest[1..].chars().next().map_or(0, char::len_utf8);
+                self.rest = &self.rest[1 + next..];
+                None
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for NcAnsiTokens<'a> {
+    type Item = NcAnsiToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let first = *self.rest.as_bytes().first()?;
+            match first {
+                b'\x1b' => match self.escape() {
+                    Some((params, b'm')) => return Some(NcAnsiToken::Sgr(params)),
+                    Some((params, b'C')) => {
+                        let n = params.parse().unwrap_or(1).max(1);
+                        return Some(NcAnsiToken::Forward(n));
+                    }
+                    _ => continue,
+                },
+                b'\r' => {
+                    self.rest = &self.rest[1..];
+                    return Some(NcAnsiToken::CarriageReturn);
+                }
+                b'\n' => {
+                    self.rest = &self.rest[1..];
+                    return Some(NcAnsiToken::LineFeed);
+                }
+                b if is_control(b) => {
+                    self.rest = &self.rest[1..];
+                    continue;
+                }
+                _ => {
+                    let end = self
+                        .rest
+                        .bytes()
+                        .position(is_control)
+                        .unwrap_or(self.rest.len());
+                    let (text, rest) = self.rest.split_at(end);
+                    self.rest = rest;
+                    return Some(NcAnsiToken::Text(text));
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if `b` is a control character skipped as text.
+fn is_control(b: u8) -> bool {
+    (b < 0x20 && b != b'\t') || b == 0x7f
+}
+
+/// Applies the `params` of an SGR sequence to `styles` & `channels`,
+/// resetting them to `base` when requested.
+///
+/// Dim (`2`), blink (`5`) and reverse (`7`) are ignored, since there are no
+/// such styles.
+pub(crate) fn apply_sgr(
+    params: &str,
+    styles: &mut NcStyle,
+    channels: &mut NcChannels,
+    base: (NcStyle, NcChannels),
+) {
+    // subparameters (`4:3`) are only used to select the underline style
+    let params: Vec<(u16, Option<u16>)> = params
+        .split(';')
+        .map(|p| {
+            let mut sub = p.split(':').map(|n| n.parse().unwrap_or(0));
+            (sub.next().unwrap_or(0), sub.next())
+        })
+        .collect();
+
+    let mut i = 0;
+    while i < params.len() {
+        match params[i] {
+            (0, _) => (*styles, *channels) = base,
+            (1, _) => styles.set(NcStyle::Bold),
+            (3, _) => styles.set(NcStyle::Italic),
+            (4, Some(0)) | (24, _) => styles.unset(NcStyle::Underline | NcStyle::Undercurl),
+            (4, Some(3)) => styles.set(NcStyle::Undercurl),
+            (4, _) => styles.set(NcStyle::Underline),
+            (9, _) => styles.set(NcStyle::Struck),
+            (22, _) => styles.unset(NcStyle::Bold),
+            (23, _) => styles.unset(NcStyle::Italic),
+            (29, _) => styles.unset(NcStyle::Struck),
+            (n @ 30..=37, _) => _ = channels.set_fg_palindex((n - 30) as u8),
+            (n @ 90..=97, _) => _ = channels.set_fg_palindex((n - 90 + 8) as u8),
+            (n @ 40..=47, _) => _ = channels.set_bg_palindex((n - 40) as u8),
+            (n @ 100..=107, _) => _ = channels.set_bg_palindex((n - 100 + 8) as u8),
+            (39, _) => _ = channels.set_fg_default(),
+            (49, _) => _ = channels.set_bg_default(),
+            (n @ (38 | 48), _) => {
+                let fg = n == 38;
+                match params.get(i + 1).map(|p| p.0) {
+                    Some(5) => {
+                        if let Some(&(index, _)) = params.get(i + 2) {
+                            let index = index.min(255) as u8;
+                            _ = if fg {
+                                channels.set_fg_palindex(index)
+                            } else {
+                                channels.set_bg_palindex(index)
+                            };
+                        }
+                        i += 2;
+                    }
+                    Some(2) => {
+                        if let Some(rgb) = params.get(i + 2..i + 5) {
+                            let [r, g, b] = [rgb[0].0, rgb[1].0, rgb[2].0].map(|c| c.min(255));
+                            let rgb = (r as u32) << 16 | (g as u32) << 8 | b as u32;
+                            _ = if fg {
+                                channels.set_fg_rgb(rgb)
+                            } else {
+                                channels.set_bg_rgb(rgb)
+                            };
+                        }
+                        i += 4;
+                    }
+                    _ => (),
+                }
+            }
+            _ => (),
+        }
+        i += 1;
+    }
+}
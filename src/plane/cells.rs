@@ -0,0 +1,77 @@
+//! `NcPlaneCells`
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{NcCell, NcChannels, NcPlane, NcStyle};
+
+/// An iterator over the [`NcCell`]s of a region of an [`NcPlane`],
+/// row by row.
+///
+/// Each item is a `(y, x, egc, styles, channels)` tuple, with a copy of the
+/// contents of the cell, which is released before returning it. A cell that
+/// can't be retrieved is returned empty.
+///
+/// Created by [`NcPlane.cells`].
+///
+/// [`NcPlane.cells`]: NcPlane#method.cells
+#[derive(Debug)]
+pub struct NcPlaneCells<'plane> {
+    plane: &'plane mut NcPlane,
+    beg_x: u32,
+    end_y: u32,
+    end_x: u32,
+    y: u32,
+    x: u32,
+}
+
+impl<'plane> NcPlaneCells<'plane> {
+    /// New iterator over the region from (`beg_y`, `beg_x`) up to
+    /// (`end_y`, `end_x`), excluded.
+    pub(crate) fn new(
+        plane: &'plane mut NcPlane,
+        beg_y: u32,
+        beg_x: u32,
+        end_y: u32,
+        end_x: u32,
+    ) -> Self {
+        Self { plane, beg_x, end_y, end_x, y: beg_y, x: beg_x }
+    }
+}
+
+impl<'plane> Iterator for NcPlaneCells<'plane> {
+    type Item = (u32, u32, String, NcStyle, NcChannels);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.end_y || self.beg_x >= self.end_x {
+            return None;
+        }
+        let (y, x) = (self.y, self.x);
+        self.x += 1;
+        if self.x >= self.end_x {
+            self.x = self.beg_x;
+            self.y += 1;
+        }
+
+        let mut cell = NcCell::new();
+        if self.plane.at_yx_cell(y, x, &mut cell).is_err() {
+            return Some((y, x, String::new(), NcStyle::None, NcChannels::new()));
+        }
+        let egc = cell.strdup(self.plane);
+        let (styles, channels) = (cell.styles(), cell.channels());
+        cell.release(self.plane);
+        Some((y, x, egc, styles, channels))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.y >= self.end_y || self.beg_x >= self.end_x {
+            0
+        } else {
+            let width = (self.end_x - self.beg_x) as usize;
+            (self.end_y - self.y) as usize * width - (self.x - self.beg_x) as usize
+        };
+        (len, Some(len))
+    }
+}
+
+impl<'plane> ExactSizeIterator for NcPlaneCells<'plane> {}
@@ -0,0 +1,54 @@
+//! `NcPlaneFamily`
+
+use core::marker::PhantomData;
+
+use crate::{c_api, NcPlane};
+
+/// An iterator over the children or the descendants of an [`NcPlane`],
+/// following the z-axis of its pile from top to bottom.
+///
+/// Created by [`NcPlane.children`] and [`NcPlane.descendants`].
+///
+/// [`NcPlane.children`]: NcPlane#method.children
+/// [`NcPlane.descendants`]: NcPlane#method.descendants
+#[derive(Debug)]
+pub struct NcPlaneFamily<'plane> {
+    ancestor: *const NcPlane,
+    next: *mut NcPlane,
+    descendants: bool,
+    _plane: PhantomData<&'plane mut NcPlane>,
+}
+
+impl<'plane> NcPlaneFamily<'plane> {
+    /// New iterator over the children of `plane`, or all of its descendants.
+    pub(crate) fn new(plane: &'plane mut NcPlane, descendants: bool) -> Self {
+        let next = unsafe { c_api::ncpile_top(plane) };
+        Self { ancestor: plane, next, descendants, _plane: PhantomData }
+    }
+}
+
+impl<'plane> Iterator for NcPlaneFamily<'plane> {
+    type Item = &'plane mut NcPlane;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.next.is_null() {
+            let plane = self.next;
+            self.next = unsafe { c_api::ncplane_below(plane) };
+            if core::ptr::eq(plane, self.ancestor) {
+                continue;
+            }
+
+            let plane = unsafe { &mut *plane };
+            let ancestor = unsafe { &*self.ancestor };
+            let is_member = if self.descendants {
+                plane.is_descendant_of(ancestor)
+            } else {
+                core::ptr::eq(unsafe { c_api::ncplane_parent_const(plane) }, self.ancestor)
+            };
+            if is_member {
+                return Some(plane);
+            }
+        }
+        None
+    }
+}
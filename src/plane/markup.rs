@@ -0,0 +1,116 @@
+//! Parsing of the styling markup used by [`NcPlane.puttext_markup`].
+//!
+//! [`NcPlane.puttext_markup`]: crate::NcPlane#method.puttext_markup
+
+use crate::{Nc, NcChannels, NcRgb, NcStyle};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
+
+/// A run of text sharing the same styles and channels.
+#[derive(Debug)]
+pub(crate) struct NcMarkupSpan {
+    pub(crate) styles: NcStyle,
+    pub(crate) channels: NcChannels,
+    pub(crate) text: String,
+}
+
+/// Parses `markup` into spans, starting from the base `styles` & `channels`.
+pub(crate) fn parse(
+    markup: &str,
+    styles: NcStyle,
+    channels: NcChannels,
+) -> Result<Vec<NcMarkupSpan>, String> {
+    let mut stack = vec![(styles, channels)];
+    let mut spans = Vec::new();
+    let mut text = String::new();
+    let mut rest = markup;
+
+    while let Some(i) = rest.find(['[', ']']) {
+        text.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        if rest.starts_with("[[") || rest.starts_with("]]") {
+            text.push_str(&rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+        if rest.starts_with(']') {
+            return Err("unmatched ']'".into());
+        }
+        let end = rest.find(']').ok_or("unclosed tag")?;
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        let (styles, channels) = stack[stack.len() - 1];
+        if !text.is_empty() {
+            spans.push(NcMarkupSpan { styles, channels, text: core::mem::take(&mut text) });
+        }
+        if tag == "/" {
+            if stack.len() == 1 {
+                return Err("unmatched '[/]'".into());
+            }
+            stack.pop();
+        } else {
+            stack.push(parse_tag(tag, styles, channels)?);
+        }
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        let (styles, channels) = stack[stack.len() - 1];
+        spans.push(NcMarkupSpan { styles, channels, text });
+    }
+    Ok(spans)
+}
+
+/// Applies the styles and colors of an opening `tag`.
+fn parse_tag(
+    tag: &str,
+    mut styles: NcStyle,
+    mut channels: NcChannels,
+) -> Result<(NcStyle, NcChannels), String> {
+    let mut words = tag.split_whitespace();
+    while let Some(word) = words.next() {
+        if word == "on" {
+            let color = words.next().ok_or("missing color after 'on'")?;
+            match parse_color(color) {
+                Some(Some(rgb)) => channels.set_bg_rgb(rgb),
+                Some(None) => channels.set_bg_default(),
+                None => return Err(format!["unknown color '{}'", color]),
+            };
+        } else if let Some(color) = parse_color(word) {
+            match color {
+                Some(rgb) => channels.set_fg_rgb(rgb),
+                None => channels.set_fg_default(),
+            };
+        } else if let Ok(style) = Nc::lex_styles(word) {
+            styles.set(style);
+        } else {
+            return Err(format!["unknown tag '{}'", word]);
+        }
+    }
+    Ok((styles, channels))
+}
+
+/// Parses a color name or `#rrggbb`, returning `Some(None)` for `default`.
+fn parse_color(color: &str) -> Option<Option<NcRgb>> {
+    let rgb = match color.to_lowercase().as_str() {
+        "default" => return Some(None),
+        "black" => 0x000000,
+        "red" => 0xff0000,
+        "green" => 0x00ff00,
+        "yellow" => 0xffff00,
+        "blue" => 0x0000ff,
+        "magenta" => 0xff00ff,
+        "cyan" => 0x00ffff,
+        "white" => 0xffffff,
+        hex if hex.len() == 7
+            && hex.starts_with('#')
+            && hex[1..].bytes().all(|b| b.is_ascii_hexdigit()) =>
+        {
+            u32::from_str_radix(&hex[1..], 16).ok()?
+        }
+        _ => return None,
+    };
+    Some(Some(NcRgb(rgb)))
+}
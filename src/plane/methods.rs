@@ -1,23 +1,30 @@
 //! `NcPlane*` methods and associated functions.
 
 use core::{
-    ffi::{c_char, c_void},
-    ptr::null_mut,
-    slice::from_raw_parts_mut,
+    ffi::{c_void, CStr},
+    ptr::{null_mut, NonNull},
 };
 
 use crate::{
     c_api, cstring, error, error_ref, error_ref_mut, rstring_free, Nc, NcAlign, NcAlpha, NcBlitter,
-    NcBoxMask, NcCell, NcChannel, NcChannels, NcError, NcFadeCb, NcPaletteIndex, NcPixelGeometry,
-    NcPlane, NcPlaneOptions, NcResizeCb, NcResult, NcRgb, NcRgba, NcStyle, NcTime,
+    NcBoxMask, NcCell, NcChannel, NcChannels, NcError, NcFadeCb, NcPaletteIndex, NcPile, NcPixel,
+    NcPixelGeometry, NcPlane, NcPlaneCells, NcPlaneFamily, NcPlaneOptions, NcResizeCb, NcResult,
+    NcRgb, NcRgbaBuffer, NcScale, NcStyle, NcTime, NcVisual, NcVisualOptions,
 };
 
 #[cfg(feature = "std")]
-use crate::NcFile;
+use {
+    crate::{NcFile, NcPlaneWriter},
+    core::any::Any,
+    std::sync::{Arc, Mutex},
+};
 
 #[cfg(not(feature = "std"))]
 use alloc::{format, string::String, vec::Vec};
 
+/// The tab width of the planes that haven't set a different one.
+const DEFAULT_TAB_WIDTH: u32 = 8;
+
 /// # NcPlane constructors & destructors
 impl NcPlane {
     /// Creates a new `NcPlane` child of `parent` plane.
@@ -96,6 +103,32 @@ impl NcPlane {
         Self::new_pile(nc, &NcPlaneOptions::new(y, x, rows, cols))
     }
 
+    /// Creates a new scrolling `NcPlane` child of `parent` plane, to be used as
+    /// a scrolling region.
+    ///
+    /// Will be placed at the offset `y`×`x` (relative to the origin of `parent`)
+    /// and with the specified size.
+    ///
+    /// Output past the bottom of the new plane scrolls only its own contents,
+    /// leaving the rest of `parent` untouched, and it can be scrolled
+    /// independently with [`scroll_up`] and [`scroll_up_child`].
+    ///
+    /// *C style function: [ncplane_create()][c_api::ncplane_create].*
+    ///
+    /// [`scroll_up`]: NcPlane#method.scroll_up
+    /// [`scroll_up_child`]: NcPlane#method.scroll_up_child
+    pub fn new_child_scrolling<'plane>(
+        parent: &mut NcPlane,
+        y: i32,
+        x: i32,
+        rows: u32,
+        cols: u32,
+    ) -> NcResult<&'plane mut NcPlane> {
+        let mut options = NcPlaneOptions::new(y, x, rows, cols);
+        options.set_vscroll(true);
+        Self::new_child(parent, &options)
+    }
+
     /// Destroys this `NcPlane`.
     ///
     /// None of its contents will be visible after the next render call.
@@ -104,7 +137,14 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_destroy()][c_api::ncplane_destroy].*
     pub fn destroy(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::ncplane_destroy(self) }, "NcPlane.destroy()"]
+        #[cfg(feature = "std")]
+        let ptr: *const NcPlane = self;
+        let res = unsafe { c_api::ncplane_destroy(self) };
+        #[cfg(feature = "std")]
+        if res == c_api::NCRESULT_OK {
+            super::side_table::forget(ptr);
+        }
+        error![res, "NcPlane.destroy()"]
     }
 }
 
@@ -130,8 +170,7 @@ impl NcPlane {
     /// Sets the foreground [`NcAlpha`] from this `NcPlane`.
     ///
     /// *C style function: [ncplane_set_fg_alpha()][c_api::ncplane_set_fg_alpha].*
-    pub fn set_fg_alpha(&mut self, alpha: impl Into<NcAlpha>) -> NcResult<()> {
-        let alpha = alpha.into();
+    pub fn set_fg_alpha(&mut self, alpha: NcAlpha) -> NcResult<()> {
         error![
             unsafe { c_api::ncplane_set_fg_alpha(self, alpha as i32) },
             &format!("NcPlane.set_fg_alpha({})", alpha)
@@ -141,8 +180,7 @@ impl NcPlane {
     /// Sets the background [`NcAlpha`] for this `NcPlane`.
     ///
     /// *C style function: [ncplane_set_bg_alpha()][c_api::ncplane_set_bg_alpha].*
-    pub fn set_bg_alpha(&mut self, alpha: impl Into<NcAlpha>) -> NcResult<()> {
-        let alpha = alpha.into();
+    pub fn set_bg_alpha(&mut self, alpha: NcAlpha) -> NcResult<()> {
         error![
             unsafe { c_api::ncplane_set_bg_alpha(self, alpha as i32) },
             &format!("NcPlane.set_bg_alpha({})", alpha)
@@ -210,7 +248,11 @@ impl NcPlane {
     ///
     /// It is an error for any coordinate to be outside the plane.
     ///
-    /// Returns the number of cells set, or -1 on failure.
+    /// Returns the number of cells set.
+    ///
+    /// # Errors
+    /// If the corner channels don't satisfy the preconditions listed in
+    /// [`gradient`][NcPlane#method.gradient].
     ///
     /// *C style function: [ncplane_stain()][c_api::ncplane_stain].*
     pub fn stain(
@@ -225,6 +267,9 @@ impl NcPlane {
         lr: impl Into<NcChannels>,
     ) -> NcResult<u32> {
         let (ul, ur, ll, lr) = (ul.into(), ur.into(), ll.into(), lr.into());
+        if let Err(msg) = gradient_corners_check([ul, ur, ll, lr]) {
+            return Err(NcError::new_msg(&format!["NcPlane.stain(): {msg}"]));
+        }
         let res = unsafe {
             c_api::ncplane_stain(
                 self,
@@ -461,7 +506,7 @@ impl NcPlane {
     /// *C style function: [ncplane_set_fg_palindex()][c_api::ncplane_set_fg_palindex].*
     pub fn set_fg_palindex(&mut self, palindex: impl Into<NcPaletteIndex>) {
         unsafe {
-            c_api::ncplane_set_fg_palindex(self, palindex.into() as u32);
+            c_api::ncplane_set_fg_palindex(self, palindex.into().0 as u32);
         }
     }
 
@@ -473,7 +518,7 @@ impl NcPlane {
     /// *C style function: [ncplane_set_bg_palindex()][c_api::ncplane_set_bg_palindex].*
     pub fn set_bg_palindex(&mut self, palindex: impl Into<NcPaletteIndex>) {
         unsafe {
-            c_api::ncplane_set_bg_palindex(self, palindex.into() as u32);
+            c_api::ncplane_set_bg_palindex(self, palindex.into().0 as u32);
         }
     }
 }
@@ -645,6 +690,9 @@ impl NcPlane {
     /// Use `None` for either or both of `len_y` and `len_x` in order to
     /// go through the boundary of the plane in that axis (same as `0`).
     ///
+    /// Any invalid UTF-8 sequences are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
     /// # Errors
     /// If either `len_y` or `len_x` fall outside the plane's boundaries.
     ///
@@ -668,10 +716,165 @@ impl NcPlane {
         if ptr.is_null() {
             Err(NcError::with_msg(
                 c_api::NCRESULT_ERR,
-                "NcPlane.contents error",
+                &format!("NcPlane.contents({beg_y:?}, {beg_x:?}, {len_y:?}, {len_x:?})"),
             ))
         } else {
-            Ok(rstring_free![ptr])
+            let bytes = unsafe { CStr::from_ptr(ptr) }.to_bytes();
+            let string = String::from_utf8_lossy(bytes).into_owned();
+            unsafe { c_api::ffi::free(ptr as *mut c_void) };
+            Ok(string)
+        }
+    }
+
+    /// Returns an iterator over copies of the contents of the [`NcCell`]s of
+    /// a region, together with their `y` and `x` coordinates.
+    ///
+    /// Starts at the plane's `beg_y` * `beg_x` coordinates, continuing for
+    /// `len_y` x `len_x` cells.
+    ///
+    /// Use `None` for either or both of `beg_y` and `beg_x` in order to start
+    /// from the origin along that axis, and for either or both of `len_y` and
+    /// `len_x` in order to go through the boundary of the plane in that axis.
+    ///
+    /// See [`NcPlaneCells`] for the items.
+    ///
+    /// # Errors
+    /// If the region falls outside the plane's boundaries.
+    pub fn cells(
+        &mut self,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> NcResult<NcPlaneCells<'_>> {
+        let (beg_y, beg_x, end_y, end_x) = self.cells_region(beg_y, beg_x, len_y, len_x)?;
+        Ok(NcPlaneCells::new(self, beg_y, beg_x, end_y, end_x))
+    }
+
+    /// Calls `f` with the `y` and `x` coordinates of each [`NcCell`] of a
+    /// region, writing it back to the plane if `f` modified it.
+    ///
+    /// The region is specified the same way as in [`cells`].
+    ///
+    /// # Errors
+    /// If the region falls outside the plane's boundaries, or if a cell
+    /// can't be retrieved or written back.
+    ///
+    /// [`cells`]: NcPlane#method.cells
+    pub fn cells_mut(
+        &mut self,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        mut f: impl FnMut(u32, u32, &mut NcCell),
+    ) -> NcResult<()> {
+        let (beg_y, beg_x, end_y, end_x) = self.cells_region(beg_y, beg_x, len_y, len_x)?;
+        for y in beg_y..end_y {
+            for x in beg_x..end_x {
+                let mut cell = NcCell::new();
+                self.at_yx_cell(y, x, &mut cell)?;
+                let original = cell;
+                f(y, x, &mut cell);
+                let res =
+                    if cell != original { self.putc_yx(y, x, &cell).map(|_| ()) } else { Ok(()) };
+                cell.release(self);
+                res?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the [`NcCell`]s of a region of `src` into this plane, starting
+    /// at the `dst_y` * `dst_x` coordinates.
+    ///
+    /// Both the `EGC`s and their styling are copied. The source region is
+    /// specified the same way as in [`cells`].
+    ///
+    /// Unlike [`mergedown`], the cells are copied as they are, replacing the
+    /// destination cells, and the planes don't need to share a pile.
+    ///
+    /// Returns the number of cells copied.
+    ///
+    /// # Errors
+    /// If the source region falls outside `src`'s boundaries, if the
+    /// destination region falls outside this plane's boundaries, or if a cell
+    /// can't be copied.
+    ///
+    /// [`cells`]: NcPlane#method.cells
+    /// [`mergedown`]: NcPlane#method.mergedown
+    pub fn copy_region(
+        &mut self,
+        dst_y: u32,
+        dst_x: u32,
+        src: &NcPlane,
+        src_beg_y: Option<u32>,
+        src_beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> NcResult<u32> {
+        let (beg_y, beg_x, end_y, end_x) = src.cells_region(src_beg_y, src_beg_x, len_y, len_x)?;
+        let (rows, cols) = self.dim_yx();
+        if dst_y.checked_add(end_y - beg_y).map_or(true, |y| y > rows)
+            || dst_x.checked_add(end_x - beg_x).map_or(true, |x| x > cols)
+        {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.copy_region({dst_y}, {dst_x}, NcPlane, {src_beg_y:?}, {src_beg_x:?}, \
+                {len_y:?}, {len_x:?}) outside of {rows}x{cols}"
+            ]));
+        }
+
+        let mut copied = 0;
+        for y in beg_y..end_y {
+            let mut x = beg_x;
+            while x < end_x {
+                let (mut stylemask, mut channels) = (0, 0);
+                let egc_ptr = unsafe {
+                    c_api::ncplane_at_yx(src, y as i32, x as i32, &mut stylemask, &mut channels)
+                };
+                if egc_ptr.is_null() {
+                    return Err(NcError::new_msg(&format![
+                        "NcPlane.copy_region(): can't get the source cell at {y}, {x}"
+                    ]));
+                }
+                let egc = String::from_utf8_lossy(unsafe { CStr::from_ptr(egc_ptr) }.to_bytes())
+                    .into_owned();
+                unsafe { c_api::ffi::free(egc_ptr as *mut c_void) };
+
+                let mut cell = NcCell::new();
+                NcCell::prime(self, &mut cell, &egc, stylemask, channels)?;
+                let res = self.putc_yx(dst_y + y - beg_y, dst_x + x - beg_x, &cell);
+                cell.release(self);
+
+                // wide glyphs are returned for each of their columns
+                x += res?.max(1);
+                copied += 1;
+            }
+        }
+        Ok(copied)
+    }
+
+    // Returns the validated (`beg_y`, `beg_x`, `end_y`, `end_x`) of a region.
+    fn cells_region(
+        &self,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> NcResult<(u32, u32, u32, u32)> {
+        let (rows, cols) = self.dim_yx();
+        let (beg_y, beg_x) = (beg_y.unwrap_or(0), beg_x.unwrap_or(0));
+        let end_y = len_y.map_or(Some(rows), |len| beg_y.checked_add(len));
+        let end_x = len_x.map_or(Some(cols), |len| beg_x.checked_add(len));
+        match (end_y, end_x) {
+            (Some(end_y), Some(end_x))
+                if beg_y <= end_y && end_y <= rows && beg_x <= end_x && end_x <= cols =>
+            {
+                Ok((beg_y, beg_x, end_y, end_x))
+            }
+            _ => Err(NcError::new_msg(&format![
+                "NcPlane.cells({beg_y}, {beg_x}, {len_y:?}, {len_x:?}) outside of {rows}x{cols}"
+            ])),
         }
     }
 
@@ -785,6 +988,82 @@ impl NcPlane {
         error![res, &format!("NcPlane.putc({:?})", cell), res as u32]
     }
 
+    /// Replaces a row of [`NcCell`]s starting at `y`, `x` with the provided
+    /// `cells`, leaving the cursor after the last one.
+    ///
+    /// The new `NcCell`s must already be associated with the `NcPlane`.
+    ///
+    /// The whole row is checked to fit in the plane before writing anything,
+    /// so it's an error if it doesn't, even if scrolling is enabled.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn put_row(&mut self, y: u32, x: u32, cells: &[NcCell]) -> NcResult<u32> {
+        let cols: u32 = cells.iter().map(|c| u32::from(c.width.max(1))).sum();
+        self.put_row_check(y, x, cols, "put_row")?;
+
+        let mut total = 0;
+        for (i, cell) in cells.iter().enumerate() {
+            // after the first cell, the cursor is already in place
+            let (cy, cx) = if i == 0 { (y as i32, x as i32) } else { (-1, -1) };
+            let res = unsafe { c_api::ncplane_putc_yx(self, cy, cx, cell) };
+            if res < c_api::NCRESULT_OK {
+                return Err(NcError::with_msg(
+                    res,
+                    &format!["NcPlane.put_row({}, {}, ..): cell {}", y, x, i],
+                ));
+            }
+            total += res as u32;
+        }
+        Ok(total)
+    }
+
+    /// Writes a row with the `string` starting at `y`, `x`, using the provided
+    /// `styles` and `channels`, and restoring the plane's afterwards.
+    ///
+    /// The whole row is checked to fit in the plane before writing anything,
+    /// so it's an error if it doesn't, even if scrolling is enabled.
+    /// It's also an error if the `string` contains control characters.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn put_row_str(
+        &mut self,
+        y: u32,
+        x: u32,
+        string: &str,
+        styles: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<u32> {
+        let (mut validbytes, mut validwidth) = (0, 0);
+        let cs = cstring![string];
+        unsafe {
+            c_api::ncstrwidth(cs.as_ptr(), &mut validbytes, &mut validwidth);
+        }
+        if validbytes as usize != string.len() {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.put_row_str({}, {}, {:?}): invalid string",
+                y, x, string
+            ]));
+        }
+        self.put_row_check(y, x, validwidth as u32, "put_row_str")?;
+        self.putstr_yx_styled(y, x, styles, channels, string)
+    }
+
+    /// Checks that a row of `cols` columns starting at `y`, `x` fits.
+    fn put_row_check(&self, y: u32, x: u32, cols: u32, method: &str) -> NcResult<()> {
+        let (dim_y, dim_x) = self.dim_yx();
+        if y >= dim_y || x.saturating_add(cols) > dim_x {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.{}({}, {}, ..): {} columns don't fit in a {}x{} plane",
+                method, y, x, cols, dim_y, dim_x
+            ]));
+        }
+        Ok(())
+    }
+
     /// Calls [`putchar_yx`][NcPlane#method.putchar_yx] at the current cursor
     /// location.
     ///
@@ -968,6 +1247,106 @@ impl NcPlane {
         error![res, &format!("NcPlane.putstr({:?})", string), res as u32]
     }
 
+    /// Writes a string with inline styling markup to the current location,
+    /// returning the number of columns advanced.
+    ///
+    /// A tag between brackets opens a span, and `[/]` closes the last one,
+    /// e.g. `"[bold red]error:[/] disk full"`. Literal brackets are escaped
+    /// by doubling them.
+    ///
+    /// A tag can combine any of:
+    /// - the styles supported by [`Nc::lex_styles`].
+    /// - a foreground color, either `#rrggbb`, `default`, or one of `black`,
+    ///   `red`, `green`, `yellow`, `blue`, `magenta`, `cyan` and `white`.
+    /// - `on` followed by a background color.
+    ///
+    /// The styles and channels of the plane are restored afterwards.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn puttext_markup(&mut self, markup: &str) -> NcResult<u32> {
+        let (styles, channels) = (self.styles(), self.channels());
+        let spans = super::markup::parse(markup, styles, channels).map_err(|e| {
+            NcError::new_msg(&format!["NcPlane.puttext_markup({:?}): {}", markup, e])
+        })?;
+
+        let mut res = Ok(0);
+        for span in spans {
+            self.set_styles(span.styles);
+            self.set_channels(span.channels);
+            match self.putstr(&span.text) {
+                Ok(cols) => res = res.map(|total| total + cols),
+                Err(e) => {
+                    res = Err(e);
+                    break;
+                }
+            }
+        }
+        self.set_styles(styles);
+        self.set_channels(channels);
+        res
+    }
+
+    /// Writes a string containing ANSI escape sequences to the current
+    /// location, returning the number of columns advanced.
+    ///
+    /// This allows to show the colored output of external tools.
+    ///
+    /// The supported sequences are:
+    /// - *SGR* styles (bold, italic, underline, undercurl, struck) and colors
+    ///   (the 16 indexed colors, `38;5;n` indexed, and `38;2;r;g;b` RGB).
+    /// - cursor forward (`ESC [ n C`), which stops at the right edge.
+    /// - carriage return and line feed.
+    /// - tabs, which are put as in [`putstr`][NcPlane#method.putstr].
+    ///
+    /// Other escape sequences and control characters are ignored, including
+    /// the *SGR* dim, blink and reverse, which have no equivalent [`NcStyle`].
+    /// The styles and channels of the plane are restored afterwards,
+    /// so they don't carry over between calls.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn put_ansi(&mut self, string: &str) -> NcResult<u32> {
+        use super::ansi::{apply_sgr, NcAnsiToken, NcAnsiTokens};
+
+        let base = (self.styles(), self.channels());
+        let (mut styles, mut channels) = base;
+        let mut res = Ok(0);
+        for token in NcAnsiTokens::new(string) {
+            let cols = match token {
+                NcAnsiToken::Text(text) => {
+                    self.set_styles(styles);
+                    self.set_channels(channels);
+                    self.putstr(text)
+                }
+                NcAnsiToken::Sgr(params) => {
+                    apply_sgr(params, &mut styles, &mut channels, base);
+                    Ok(0)
+                }
+                NcAnsiToken::Forward(n) => {
+                    let x = self
+                        .cursor_x()
+                        .saturating_add(n)
+                        .min(self.dim_x().saturating_sub(1));
+                    self.cursor_move_x(x).map(|_| 0)
+                }
+                NcAnsiToken::CarriageReturn => self.cursor_move_x(0).map(|_| 0),
+                NcAnsiToken::LineFeed => self.putln().map(|_| 0),
+            };
+            match cols {
+                Ok(cols) => res = res.map(|total| total + cols),
+                Err(e) => {
+                    res = Err(NcError::new_msg(&format![
+                        "NcPlane.put_ansi({:?}): {}",
+                        string, e
+                    ]));
+                    break;
+                }
+            }
+        }
+        self.set_styles(base.0);
+        self.set_channels(base.1);
+        res
+    }
+
     /// Same as [`putstr`][NcPlane#method.putstr], but it also puts a newline
     /// character at the end.
     ///
@@ -990,6 +1369,45 @@ impl NcPlane {
         Ok(cols)
     }
 
+    /// Returns the width of the tab stops, in columns.
+    ///
+    /// Tabs are expanded with spaces up to the next tab stop by the put-string
+    /// methods, without going past the end of the line.
+    ///
+    /// Defaults to 8 columns.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn tab_width(&self) -> u32 {
+        #[cfg(feature = "std")]
+        if let Some(width) = super::side_table::with(self, |d| d.tab_width) {
+            return width;
+        }
+        DEFAULT_TAB_WIDTH
+    }
+
+    /// Sets the width of the tab stops, in columns.
+    ///
+    /// A `width` of 0 makes the put-string methods ignore tabs.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn set_tab_width(&mut self, width: u32) {
+        super::side_table::with(self, |d| {
+            d.tab_width = (width != DEFAULT_TAB_WIDTH).then_some(width);
+        });
+    }
+
+    /// Returns a buffered [`std::io::Write`] adapter that writes to this plane
+    /// at the cursor.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn writer(&mut self) -> NcPlaneWriter<'_> {
+        NcPlaneWriter::new(self)
+    }
+
     /// Writes a string to the current location, retaining the previous style.
     ///
     /// Advances the cursor by some positive number of columns (though not
@@ -1118,6 +1536,60 @@ impl NcPlane {
         ]
     }
 
+    /// Writes a string to the current location, using the provided `styles`
+    /// and `channels`, and restoring the plane's afterwards.
+    ///
+    /// Advances the cursor by some positive number of columns (though not
+    /// beyond the end of the plane); this number is returned on success.
+    ///
+    /// If a glyph can not fit in the current line, it is an error, unless
+    /// scrolling is enabled.
+    ///
+    /// See also the [`putstr_styled!`][crate::putstr_styled] macro.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_styled(
+        &mut self,
+        styles: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+        string: &str,
+    ) -> NcResult<u32> {
+        let (styles, channels) = (styles.into(), channels.into());
+        let (prev_styles, prev_channels) = (self.styles(), self.channels());
+        self.set_styles(styles);
+        self.set_channels(channels);
+        let res = c_api::ncplane_putstr(self, string);
+        self.set_styles(prev_styles);
+        self.set_channels(prev_channels);
+        error![
+            res,
+            &format!(
+                "NcPlane.putstr_styled({:?}, {:?}, {:?})",
+                styles, channels, string
+            ),
+            res as u32
+        ]
+    }
+
+    /// Same as [`putstr_styled`] but moving the cursor to `y`, `x` first.
+    ///
+    /// See also the [`putstr_yx_styled!`][crate::putstr_yx_styled] macro.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`putstr_styled`]: NcPlane#method.putstr_styled
+    pub fn putstr_yx_styled(
+        &mut self,
+        y: u32,
+        x: u32,
+        styles: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+        string: &str,
+    ) -> NcResult<u32> {
+        self.cursor_move_yx(y, x)?;
+        self.putstr_styled(styles, channels, string)
+    }
+
     /// Writes a string to the current location, using the current style,
     /// and no more than `num_bytes` bytes will be written.
     ///
@@ -1215,7 +1687,7 @@ impl NcPlane {
     ///
     /// An invalid initial `y`, `x` is an error.
     ///
-    /// *C style function: [ncplane_putnstr_yx()][c_api::ncplane_putnstr_yx].*
+    /// *C style function: [ncplane_polyfill_yx()][c_api::ncplane_polyfill_yx].*
     pub fn polyfill_yx(&mut self, y: u32, x: u32, cell: &NcCell) -> NcResult<usize> {
         let res = unsafe { c_api::ncplane_polyfill_yx(self, y as i32, x as i32, cell) };
         error![
@@ -1224,6 +1696,30 @@ impl NcPlane {
             res as usize
         ]
     }
+
+    /// Flood-fills the region of cardinally-connected cells with the same
+    /// glyph as the one at `y`,`x`, copying `cell` to each of them.
+    ///
+    /// Use `None` for either or both of `y` and `x` in order to
+    /// use the current cursor position along that axis.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// # Errors
+    /// If the initial coordinates fall outside the plane.
+    ///
+    /// *C style function: [ncplane_polyfill_yx()][c_api::ncplane_polyfill_yx].*
+    pub fn polyfill(&mut self, y: Option<u32>, x: Option<u32>, cell: &NcCell) -> NcResult<usize> {
+        let (cur_y, cur_x) = self.cursor_yx();
+        let (y, x) = (y.unwrap_or(cur_y), x.unwrap_or(cur_x));
+        let (rows, cols) = self.dim_yx();
+        if y >= rows || x >= cols {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.polyfill({y}, {x}, {cell:?}) outside of {rows}x{cols}"
+            ]));
+        }
+        self.polyfill_yx(y, x, cell)
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1248,6 +1744,11 @@ impl NcPlane {
         unsafe { &mut *c_api::ncplane_dup(self, null_mut()) }
     }
 
+    /// Returns a handle to the pile of which this `NcPlane` is a part.
+    pub fn pile(&mut self) -> NcPile<'_> {
+        NcPile::from_plane(self)
+    }
+
     /// Returns the topmost `NcPlane` of the current pile.
     ///
     /// # Safety
@@ -1270,6 +1771,47 @@ impl NcPlane {
         &mut *c_api::ncpile_bottom(self)
     }
 
+    /// Returns the first `NcPlane` of the current pile named `name`,
+    /// searching from the top of the z-buffer down.
+    ///
+    /// # Safety
+    /// You must be careful not to end up with multiple exclusive references
+    /// to the plane.
+    pub unsafe fn find_in_pile<'a>(&mut self, name: &str) -> Option<&'a mut NcPlane> {
+        let mut plane = c_api::ncpile_top(self);
+        while !plane.is_null() {
+            if (*plane).name().as_deref() == Some(name) {
+                return Some(&mut *plane);
+            }
+            plane = c_api::ncplane_below(plane);
+        }
+        None
+    }
+
+    /// Returns the name of this `NcPlane`, if it has one.
+    ///
+    /// *C style function: [ncplane_name()][c_api::ncplane_name].*
+    pub fn name(&self) -> Option<String> {
+        let ptr = unsafe { c_api::ncplane_name(self) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(rstring_free![ptr])
+        }
+    }
+
+    /// Sets the name of this `NcPlane`, replacing any current name.
+    ///
+    /// *C style function: [ncplane_set_name()][c_api::ncplane_set_name].*
+    pub fn set_name(&mut self, name: Option<&str>) -> NcResult<()> {
+        let cs = name.map(|name| cstring![name]);
+        let ptr = cs.as_ref().map_or(core::ptr::null(), |cs| cs.as_ptr());
+        error![
+            unsafe { c_api::ncplane_set_name(self, ptr) },
+            &format!("NcPlane.set_name({:?})", name)
+        ]
+    }
+
     /// Relocates this `NcPlane` at the bottom of the z-buffer.
     ///
     /// *C style function: [ncplane_move_bottom()][c_api::ncplane_move_bottom].*
@@ -1351,7 +1893,7 @@ impl NcPlane {
 
     /// Relocates this `NcPlane` below the `below` NcPlane, in the z-buffer.
     ///
-    /// Returns an error the current plane is already in the desired location.
+    /// Returns an error if the current plane is already in the desired location.
     /// Both planes must not be the same.
     ///
     /// *C style function: [ncplane_move_below()][c_api::ncplane_move_below].*
@@ -1394,9 +1936,9 @@ impl NcPlane {
     /// Relative order will be maintained between the reinserted planes.
     ///
     /// For a plane E bound to C, with z-ordering A B C D E, moving the C family
-    /// to the top results in C E A B D.
+    /// above A results in C E A B D.
     ///
-    /// *C style function: [ncplane_move_family_below()][c_api::ncplane_move_family_below].*
+    /// *C style function: [ncplane_move_family_above()][c_api::ncplane_move_family_above].*
     pub fn move_family_above(&mut self, above: &mut NcPlane) -> NcResult<()> {
         error![
             unsafe { c_api::ncplane_move_family_above(self, above) },
@@ -1410,7 +1952,7 @@ impl NcPlane {
     /// Relative order will be maintained between the reinserted planes.
     ///
     /// For a plane E bound to C, with z-ordering A B C D E, moving the C family
-    /// to the bottom results in A B D C E.
+    /// below D results in A B D C E.
     ///
     /// *C style function: [ncplane_move_family_below()][c_api::ncplane_move_family_below].*
     pub fn move_family_below(&mut self, below: &mut NcPlane) -> NcResult<()> {
@@ -1420,6 +1962,81 @@ impl NcPlane {
         ]
     }
 
+    /// Relocates this `NcPlane` one position up in the z-buffer.
+    ///
+    /// Returns an error if the current plane is already at the top.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn raise(&mut self) -> NcResult<()> {
+        let above = unsafe { c_api::ncplane_above(self) };
+        if above.is_null() {
+            return Err(NcError::new_msg("NcPlane.raise(): already at the top"));
+        }
+        error![
+            unsafe { c_api::ncplane_move_above(self, above) },
+            "NcPlane.raise()"
+        ]
+    }
+
+    /// Relocates this `NcPlane` one position down in the z-buffer.
+    ///
+    /// Returns an error if the current plane is already at the bottom.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn lower(&mut self) -> NcResult<()> {
+        let below = unsafe { c_api::ncplane_below(self) };
+        if below.is_null() {
+            return Err(NcError::new_msg("NcPlane.lower(): already at the bottom"));
+        }
+        error![
+            unsafe { c_api::ncplane_move_below(self, below) },
+            "NcPlane.lower()"
+        ]
+    }
+
+    /// Stacks the `planes` in the z-buffer from top to bottom, placing the
+    /// first one at the top of their pile.
+    ///
+    /// Planes not included in the list end up below the listed planes, in
+    /// their current relative order.
+    ///
+    /// Returns an error if the planes don't all belong to the same pile,
+    /// or if any plane is repeated.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn reorder(planes: &[&NcPlane]) -> NcResult<()> {
+        let ptrs: Vec<*mut NcPlane> = planes
+            .iter()
+            .map(|p| *p as *const NcPlane as *mut NcPlane)
+            .collect();
+        if let Some(first) = ptrs.first() {
+            let top = unsafe { c_api::ncpile_top(*first) };
+            for (i, &p) in ptrs.iter().enumerate() {
+                if unsafe { c_api::ncpile_top(p) } != top {
+                    return Err(NcError::new_msg(&format![
+                        "NcPlane::reorder(): plane #{i} belongs to another pile"
+                    ]));
+                }
+                if ptrs[..i].contains(&p) {
+                    return Err(NcError::new_msg(&format![
+                        "NcPlane::reorder(): plane #{i} is repeated"
+                    ]));
+                }
+            }
+            c_api::ncplane_move_top(unsafe { &mut **first });
+        }
+        for pair in ptrs.windows(2) {
+            let (upper, lower) = (pair[0], pair[1]);
+            if unsafe { c_api::ncplane_below(upper) } != lower {
+                let res = unsafe { c_api::ncplane_move_below(lower, upper) };
+                if res < c_api::NCRESULT_OK {
+                    return Err(NcError::with_msg(res, "NcPlane::reorder()"));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Merges the `NcPlane` `source` down onto the current `NcPlane` (`self`).
     ///
     /// This is most rigorously defined as "write to `self` the frame that would
@@ -1487,9 +2104,6 @@ impl NcPlane {
     /// for more information.
     ///
     /// *C style function: [ncplane_mergedown_simple()][c_api::ncplane_mergedown_simple].*
-    //
-    // TODO: maybe create a reversed method, and/or an associated function,
-    // for `mergedown` too.
     pub fn mergedown_simple(&mut self, source: &mut NcPlane) -> NcResult<()> {
         error![
             unsafe { c_api::ncplane_mergedown_simple(source, self) },
@@ -1497,6 +2111,63 @@ impl NcPlane {
         ]
     }
 
+    /// Merges the region starting at this plane's `beg_src_y` * `beg_src_x`
+    /// coordinates, continuing for `len_y` x `len_x` cells, down onto the
+    /// `target` plane at its `dst_y` * `dst_x` coordinates.
+    ///
+    /// This is the reverse of [`mergedown`], leaving `self` unchanged.
+    ///
+    /// *C style function: [ncplane_mergedown()][c_api::ncplane_mergedown].*
+    ///
+    /// [`mergedown`]: NcPlane#method.mergedown
+    pub fn merge_down(
+        &self,
+        target: &mut NcPlane,
+        beg_src_y: Option<u32>,
+        beg_src_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        dst_y: Option<u32>,
+        dst_x: Option<u32>,
+    ) -> NcResult<()> {
+        error![
+            unsafe {
+                // the source plane is not modified
+                c_api::ncplane_mergedown(
+                    self as *const NcPlane as *mut NcPlane,
+                    target,
+                    beg_src_y.unwrap_or(u32::MAX) as i32, // -1_i32
+                    beg_src_x.unwrap_or(u32::MAX) as i32, // "
+                    len_y.unwrap_or(0),
+                    len_x.unwrap_or(0),
+                    dst_y.unwrap_or(u32::MAX) as i32, // -1_i32
+                    dst_x.unwrap_or(u32::MAX) as i32, // "
+                )
+            },
+            &format!(
+                "NcPlane.merge_down(NcPlane, {:?}, {:?}, {:?}, {:?}, {:?}, {:?})",
+                beg_src_y, beg_src_x, len_y, len_x, dst_y, dst_x
+            )
+        ]
+    }
+
+    /// Merges this plane down onto the `target` plane.
+    ///
+    /// This is the reverse of [`mergedown_simple`], leaving `self` unchanged.
+    ///
+    /// *C style function: [ncplane_mergedown_simple()][c_api::ncplane_mergedown_simple].*
+    ///
+    /// [`mergedown_simple`]: NcPlane#method.mergedown_simple
+    pub fn merge_down_simple(&self, target: &mut NcPlane) -> NcResult<()> {
+        error![
+            unsafe {
+                // the source plane is not modified
+                c_api::ncplane_mergedown_simple(self as *const NcPlane as *mut NcPlane, target)
+            },
+            "NcPlane.merge_down_simple(NcPlane)"
+        ]
+    }
+
     /// Gets the parent to which this `NcPlane` is bound, if any.
     ///
     /// # Safety
@@ -1525,6 +2196,39 @@ impl NcPlane {
         error_ref![c_api::ncplane_parent_const(self), "NcPlane.parent_const()"]
     }
 
+    /// Returns `true` if this `NcPlane` is a root plane, bound to itself.
+    pub fn is_root(&self) -> bool {
+        core::ptr::eq(unsafe { c_api::ncplane_parent_const(self) }, self)
+    }
+
+    /// Returns `true` if this `NcPlane` is bound to `ancestor`, either
+    /// directly or through its parents.
+    pub fn is_descendant_of(&self, ancestor: &NcPlane) -> bool {
+        let mut plane: *const NcPlane = self;
+        loop {
+            let parent = unsafe { c_api::ncplane_parent_const(plane) };
+            if parent.is_null() || parent == plane {
+                return false;
+            } else if core::ptr::eq(parent, ancestor) {
+                return true;
+            }
+            plane = parent;
+        }
+    }
+
+    /// Returns an iterator over the planes directly bound to this `NcPlane`,
+    /// from the top of the z-axis to the bottom.
+    pub fn children(&mut self) -> NcPlaneFamily<'_> {
+        NcPlaneFamily::new(self, false)
+    }
+
+    /// Returns an iterator over the planes bound to this `NcPlane`, either
+    /// directly or through their parents, from the top of the z-axis to the
+    /// bottom.
+    pub fn descendants(&mut self) -> NcPlaneFamily<'_> {
+        NcPlaneFamily::new(self, true)
+    }
+
     /// Unbounds this `NcPlane` from its parent, makes it a bound child of
     /// 'newparent', and returns itself.
     ///
@@ -1536,9 +2240,13 @@ impl NcPlane {
     ///
     /// The standard plane cannot be reparented.
     ///
+    /// # Errors
+    /// If this is the standard plane.
+    ///
     /// *C style function: [ncplane_reparent()][c_api::ncplane_reparent].*
     // CHECK: if it's necessary to return the plane.
     pub fn reparent<'a>(&mut self, newparent: &'a mut NcPlane) -> NcResult<&'a mut NcPlane> {
+        self.reparent_check(newparent, "reparent", false)?;
         error_ref_mut![
             unsafe { c_api::ncplane_reparent(self, newparent) },
             "NcPlane.reparent(NcPlane)"
@@ -1550,17 +2258,37 @@ impl NcPlane {
     ///
     /// Their z-order is maintained.
     ///
+    /// # Errors
+    /// If this is the standard plane, or if `newparent` is bound to this
+    /// plane, since that would create a cycle.
+    ///
     /// *C style function: [ncplane_reparent_family()][c_api::ncplane_reparent_family].*
     //
     // CHECK: if it's necessary to return the plane.
-    // CHECK: If 'newparent' is an ancestor, NULL is returned & no changes're made.
     pub fn reparent_family<'a>(&mut self, newparent: &'a mut NcPlane) -> NcResult<&'a mut NcPlane> {
+        self.reparent_check(newparent, "reparent_family", true)?;
         error_ref_mut![
             unsafe { c_api::ncplane_reparent_family(self, newparent) },
             "NcPlane.reparent_family(NcPlane)"
         ]
     }
 
+    // Checks that this plane can be reparented to `newparent`.
+    fn reparent_check(&self, newparent: &NcPlane, method: &str, family: bool) -> NcResult<()> {
+        let nc = unsafe { c_api::ncplane_notcurses_const(self) };
+        if core::ptr::eq(unsafe { c_api::notcurses_stdplane_const(nc) }, self) {
+            Err(NcError::new_msg(&format![
+                "NcPlane.{method}(): the standard plane can't be reparented"
+            ]))
+        } else if family && newparent.is_descendant_of(self) {
+            Err(NcError::new_msg(&format![
+                "NcPlane.{method}(): the new parent is a descendant of this plane"
+            ]))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Makes the physical screen match the last rendered frame from the pile of
     /// which this `NcPlane` is a part.
     ///
@@ -1599,18 +2327,12 @@ impl NcPlane {
     /// but does not write the resulting buffer out to the terminal.
     ///
     /// Using this function, the user can control the writeout process.
-    /// The returned buffer must be freed by the caller.
+    /// The contents of `buffer` are replaced with the rendered frame.
     ///
     /// *C style function: [ncpile_render_to_buffer()][c_api::ncpile_render_to_buffer].*
-    // CHECK this works
     pub fn render_to_buffer(&mut self, buffer: &mut Vec<u8>) -> NcResult<()> {
-        let len = buffer.len() as u32;
-        let mut buf = buffer.as_mut_ptr() as *mut c_char;
-
-        error![
-            unsafe { c_api::ncpile_render_to_buffer(self, &mut buf, &mut len.try_into().unwrap()) },
-            &format!["NcPlane.render_to_buffer(buffer, {})", len]
-        ]
+        *buffer = self.pile().render_to_buffer()?;
+        Ok(())
     }
 
     /// Writes the last rendered frame, in its entirety, to `fp`.
@@ -1623,6 +2345,20 @@ impl NcPlane {
     pub fn render_to_file(&mut self, fp: &mut NcFile) -> NcResult<()> {
         error![unsafe { c_api::ncpile_render_to_file(self, fp.as_nc_ptr()) }]
     }
+
+    /// Returns the state of the notcurses context of this plane, as dumped
+    /// by [`Nc.debug`][Nc#method.debug].
+    ///
+    /// Output is freeform, and subject to change. It includes geometry of all
+    /// planes, from all piles, this one among them.
+    ///
+    /// *C style function: [notcurses_debug()][c_api::notcurses_debug].*
+    #[cfg(feature = "libc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "libc")))]
+    pub fn debug_string(&self) -> NcResult<String> {
+        unsafe { self.notcurses_const()? }.debug_string()
+    }
+
     /// Gets a mutable reference to the [`Nc`] context of this `NcPlane`.
     ///
     /// # Safety
@@ -1750,6 +2486,39 @@ impl NcPlane {
         self.cursor_move_cols(cols)?;
         Ok(())
     }
+
+    /// Saves the current position of the cursor, to be restored later
+    /// with [`pop_cursor`].
+    ///
+    /// Positions are stacked, so nested saves and restores are possible.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`pop_cursor`]: NcPlane#method.pop_cursor
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn push_cursor(&mut self) {
+        let yx = self.cursor_yx();
+        super::side_table::with(self, |d| d.cursor_stack.push(yx));
+    }
+
+    /// Moves the cursor back to the last position saved with [`push_cursor`],
+    /// and returns it.
+    ///
+    /// It will error if there are no saved positions, or if the saved position
+    /// is no longer inside the plane. Either way the position is discarded.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`push_cursor`]: NcPlane#method.push_cursor
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn pop_cursor(&mut self) -> NcResult<(u32, u32)> {
+        let (y, x) = super::side_table::with(self, |d| d.cursor_stack.pop())
+            .ok_or_else(|| NcError::new_msg("NcPlane.pop_cursor(): no saved position"))?;
+        self.cursor_move_yx(y, x)?;
+        Ok((y, x))
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1857,6 +2626,8 @@ impl NcPlane {
     ///
     /// Only glyphs from the specified blitset may be present.
     ///
+    /// The returned buffer frees its memory when dropped.
+    ///
     /// *C style function: [ncplane_as_rgba()][c_api::ncplane_as_rgba].*
     pub fn as_rgba(
         &mut self,
@@ -1865,7 +2636,7 @@ impl NcPlane {
         beg_x: Option<u32>,
         len_y: Option<u32>,
         len_x: Option<u32>,
-    ) -> NcResult<&mut [NcRgba]> {
+    ) -> NcResult<NcRgbaBuffer> {
         let blitter = blitter.into();
 
         // pixel geometry
@@ -1885,14 +2656,42 @@ impl NcPlane {
             )
         };
 
-        error_ref_mut![
-            res_array,
-            &format![
-                "NcPlane.rgba({}, {:?}, {:?}, {:?}, {:?})",
-                blitter, beg_y, beg_x, len_y, len_x
-            ],
-            from_raw_parts_mut(res_array as *mut NcRgba, (pxdim_y * pxdim_x) as usize)
-        ]
+        match NonNull::new(res_array as *mut NcPixel) {
+            Some(ptr) => Ok(unsafe { NcRgbaBuffer::from_raw(ptr, pxdim_y, pxdim_x) }),
+            None => Err(NcError::with_msg(
+                c_api::NCRESULT_ERR,
+                &format![
+                    "NcPlane.as_rgba({}, {:?}, {:?}, {:?}, {:?})",
+                    blitter, beg_y, beg_x, len_y, len_x
+                ],
+            )),
+        }
+    }
+
+    /// Saves the whole plane as a PNG image at `path`, converting it to pixels
+    /// with [`as_rgba`][NcPlane#method.as_rgba].
+    ///
+    /// Only glyphs from the specified blitset may be present.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "image")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "image")))]
+    pub fn screenshot_png(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+        blitter: impl Into<NcBlitter>,
+    ) -> NcResult<()> {
+        let rgba = self.as_rgba(blitter, Some(0), Some(0), None, None)?;
+        let (rows, cols) = rgba.pixel_yx();
+        crate::visual::save_rgba_png(rgba.as_bytes().to_vec(), rows, cols, path.as_ref()).map_err(
+            |e| {
+                NcError::new_msg(&format![
+                    "NcPlane.screenshot_png({:?}): {}",
+                    path.as_ref(),
+                    e
+                ])
+            },
+        )
     }
 
     /// Returns an [`NcPixelGeometry`] structure filled with pixel geometry for
@@ -2056,6 +2855,24 @@ impl NcPlane {
         unsafe { c_api::ncplane_set_resizecb(self, c_api::ncresizecb_to_c(resizecb)) }
     }
 
+    /// Replaces this `NcPlane`'s existing resize callback with a closure.
+    ///
+    /// The closure is called from the thread that resizes the plane, and is
+    /// dropped when the plane is [`destroy`]ed or gets another closure.
+    /// If it panics, the resize fails.
+    ///
+    /// The standard plane's resize callback may not be changed.
+    ///
+    /// [`destroy`]: NcPlane#method.destroy
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn set_on_resize(
+        &mut self,
+        closure: impl FnMut(&mut NcPlane) -> NcResult<()> + Send + 'static,
+    ) {
+        crate::resizecb::closure::set(self, Arc::new(Mutex::new(closure)));
+    }
+
     /// Rotate the plane π/2 radians (90°) clockwise.
     ///
     /// This cannot be performed on arbitrary planes, because glyphs cannot be
@@ -2068,9 +2885,17 @@ impl NcPlane {
     ///
     /// Use the ncvisual rotation for a more flexible approach.
     ///
+    /// # Errors
+    /// If the plane has an odd number of columns, or if it contains glyphs
+    /// that can't be rotated.
+    ///
     /// *C style function: [ncplane_rotate_cw()][c_api::ncplane_rotate_cw].*
     pub fn rotate_cw(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::ncplane_rotate_cw(self) }]
+        self.rotate_check("rotate_cw")?;
+        error![
+            unsafe { c_api::ncplane_rotate_cw(self) },
+            "NcPlane.rotate_cw(): the plane contains glyphs that can't be rotated"
+        ]
     }
 
     /// Rotate the plane π/2 radians (90°) counter-clockwise.
@@ -2080,7 +2905,23 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_rotate_ccw()][c_api::ncplane_rotate_ccw].*
     pub fn rotate_ccw(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::ncplane_rotate_ccw(self) }]
+        self.rotate_check("rotate_ccw")?;
+        error![
+            unsafe { c_api::ncplane_rotate_ccw(self) },
+            "NcPlane.rotate_ccw(): the plane contains glyphs that can't be rotated"
+        ]
+    }
+
+    // Checks the geometry preconditions of a rotation.
+    fn rotate_check(&self, method: &str) -> NcResult<()> {
+        let cols = self.dim_x();
+        if cols % 2 != 0 {
+            Err(NcError::new_msg(&format![
+                "NcPlane.{method}(): the plane must have an even number of columns, not {cols}"
+            ]))
+        } else {
+            Ok(())
+        }
     }
 
     /// Maps the specified coordinates relative to the origin of this `NcPlane`,
@@ -2173,8 +3014,9 @@ impl NcPlane {
     /// fail at the eleventh character. In either case, the cursor will be left
     /// at location 0x10; it must be moved before further printing can take place. I
     ///
-    /// See also
-    /// `NcPlaneOptions::`[`VSCROLL`][NcPlaneOptions#associatedconstant.VSCROLL]
+    /// See also [`NcPlaneFlag::VScroll`].
+    ///
+    /// [`NcPlaneFlag::VScroll`]: crate::NcPlaneFlag#associatedconstant.VScroll
     ///
     /// *C style function: [ncplane_set_scrolling()][c_api::ncplane_set_scrolling].*
     pub fn set_scrolling(&mut self, scroll: bool) -> bool {
@@ -2187,9 +3029,9 @@ impl NcPlane {
     /// and otherwise returns the number of lines scrolled.
     ///
     /// *C style function: [ncplane_scrollup()][c_api::ncplane_scrollup].*
-    pub fn scrollup(&mut self, n: u32) -> NcResult<u32> {
+    pub fn scroll_up(&mut self, n: u32) -> NcResult<u32> {
         let res = unsafe { c_api::ncplane_scrollup(self, n as i32) };
-        error![res, "", res as u32]
+        error![res, &format!["NcPlane.scroll_up({})", n], res as u32]
     }
 
     /// Scrolls the current plane until `child` is no longer hidden beneath it.
@@ -2200,9 +3042,21 @@ impl NcPlane {
     /// Returns the number of scrolling events otherwise (might be 0).
     ///
     /// *C style function: [ncplane_scrollup_child()][c_api::ncplane_scrollup_child].*
-    pub fn scrollup_child(&mut self, child: &NcPlane) -> NcResult<u32> {
+    pub fn scroll_up_child(&mut self, child: &NcPlane) -> NcResult<u32> {
         let res = unsafe { c_api::ncplane_scrollup_child(self, child) };
-        error![res, "", res as u32]
+        error![res, "NcPlane.scroll_up_child(NcPlane)", res as u32]
+    }
+
+    #[doc(hidden)]
+    #[deprecated = "use the `scroll_up` method instead."]
+    pub fn scrollup(&mut self, n: u32) -> NcResult<u32> {
+        self.scroll_up(n)
+    }
+
+    #[doc(hidden)]
+    #[deprecated = "use the `scroll_up_child` method instead."]
+    pub fn scrollup_child(&mut self, child: &NcPlane) -> NcResult<u32> {
+        self.scroll_up_child(child)
     }
 
     /// Returns `true` if this `NcPlane` has autogrow enabled, or `false` otherwise.
@@ -2231,6 +3085,10 @@ impl NcPlane {
     ///
     /// The plane only grows in one dimension.
     ///
+    /// See also [`NcPlaneFlag::AutoGrow`].
+    ///
+    /// [`NcPlaneFlag::AutoGrow`]: crate::NcPlaneFlag#associatedconstant.AutoGrow
+    ///
     /// *C style function: [ncplane_set_autogrow()][c_api::ncplane_set_autogrow].*
     pub fn set_autogrow(&mut self, autogrow: bool) -> bool {
         unsafe { c_api::ncplane_set_autogrow(self, autogrow.into()) }
@@ -2509,61 +3367,78 @@ impl NcPlane {
         error![unsafe { c_api::ncplane_pulse(self, time, fader, null_mut()) }]
     }
 
-    /// Draws a gradient with its upper-left corner at the current cursor
-    /// position, stopping at `stop_y` * `stop_x`.
+    /// Draws a gradient with its upper-left corner at `y` * `x`, continuing
+    /// for `len_y` x `len_x` cells.
+    ///
+    /// Use `None` for either or both of `y` and `x` in order to
+    /// use the current cursor position along that axis.
+    ///
+    /// Use `None` for either or both of `len_y` and `len_x` in order to
+    /// go through the boundary of the plane in that axis.
     ///
-    /// Returns the number of cells filled on success,
-    /// or [`NCRESULT_ERR`][c_api::NCRESULT_ERR] on error.
+    /// Returns the number of cells filled on success.
     ///
-    /// The glyph composed of `egc` and `stylemask` is used for all cells.
+    /// The glyph composed of `egc` and `stylemask` is used for all cells,
+    /// using a space if `egc` is `None`.
     /// The channels specified by `ul`, `ur`, `ll`, and `lr` are composed into
     /// foreground and background gradients.
     ///
     /// To do a vertical gradient, `ul` ought equal `ur` and `ll` ought equal
     /// `lr`. To do a horizontal gradient, `ul` ought equal `ll` and `ur` ought
-    /// equal `ul`.
+    /// equal `lr`.
     ///
     /// To color everything the same, all four channels should be equivalent.
     /// The resulting alpha values are equal to incoming alpha values.
     ///
     /// Palette-indexed color is not supported.
     ///
+    /// # Errors
     /// Preconditions for gradient operations (error otherwise):
     ///
-    /// all: only RGB colors, unless all four channels match as default
-    /// all: all alpha values must be the same
-    /// 1x1: all four colors must be the same
-    /// 1xN: both top and both bottom colors must be the same (vertical gradient)
-    /// Nx1: both left and both right colors must be the same (horizontal gradient)
+    /// - all: only RGB colors, unless all four channels match as default
+    /// - all: all alpha values must be the same
+    /// - 1x1: all four colors must be the same
+    /// - 1xN: both top and both bottom colors must be the same (vertical gradient)
+    /// - Nx1: both left and both right colors must be the same (horizontal gradient)
     ///
     /// *C style function: [ncplane_gradient()][c_api::ncplane_gradient].*
     pub fn gradient(
         &mut self,
         y: Option<u32>,
         x: Option<u32>,
-        stop_y: Option<u32>,
-        stop_x: Option<u32>,
-        egc: &str,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        egc: Option<&str>,
         stylemask: impl Into<NcStyle>,
         ul: impl Into<NcChannels>,
         ur: impl Into<NcChannels>,
         ll: impl Into<NcChannels>,
         lr: impl Into<NcChannels>,
     ) -> NcResult<u32> {
+        let (ul, ur, ll, lr) = (ul.into(), ur.into(), ll.into(), lr.into());
+        self.gradient_check(y, x, len_y, len_x, [ul, ur, ll, lr])?;
+        let egc = egc.unwrap_or(" ");
         let res = c_api::ncplane_gradient(
             self,
             y,
             x,
-            stop_y,
-            stop_x,
+            len_y,
+            len_x,
             egc,
             stylemask.into(),
-            ul.into().0,
-            ur.into().0,
-            ll.into().0,
-            lr.into().0,
+            ul.0,
+            ur.0,
+            ll.0,
+            lr.0,
         );
-        error![res, "", res as u32]
+        error![
+            res,
+            &format!(
+                "NcPlane.gradient({:?}, {:?}, {:?}, {:?}, {:?}, {:0X}, {:0X}, {:0X}, {:0X})",
+                y, x, len_y, len_x, egc, ul, ur, ll, lr
+            ),
+            res as u32
+        ]
     }
 
     /// Does a high-resolution gradient using upper blocks and synced backgrounds.
@@ -2591,6 +3466,10 @@ impl NcPlane {
         ll: impl Into<NcChannel>,
         lr: impl Into<NcChannel>,
     ) -> NcResult<u32> {
+        let (ul, ur, ll, lr) = (ul.into(), ur.into(), ll.into(), lr.into());
+        if let Err(msg) = gradient_channel_check([ul, ur, ll, lr]) {
+            return Err(NcError::new_msg(&format!["NcPlane.gradient2x1(): {msg}"]));
+        }
         let res = unsafe {
             c_api::ncplane_gradient2x1(
                 self,
@@ -2598,13 +3477,54 @@ impl NcPlane {
                 x.unwrap_or(u32::MAX) as i32, // "
                 len_y.unwrap_or(0),
                 len_x.unwrap_or(0),
-                ul.into().0,
-                ur.into().0,
-                ll.into().0,
-                lr.into().0,
+                ul.0,
+                ur.0,
+                ll.0,
+                lr.0,
             )
         };
-        error![res, "", res as u32]
+        error![
+            res,
+            &format!(
+                "NcPlane.gradient2x1({:?}, {:?}, {:?}, {:?}, {:0X}, {:0X}, {:0X}, {:0X})",
+                y, x, len_y, len_x, ul, ur, ll, lr
+            ),
+            res as u32
+        ]
+    }
+
+    // Checks the preconditions of the gradient corners over a region.
+    fn gradient_check(
+        &self,
+        y: Option<u32>,
+        x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        corners: [NcChannels; 4],
+    ) -> NcResult<()> {
+        let err = |msg: &str| Err(NcError::new_msg(&format!["NcPlane.gradient(): {msg}"]));
+
+        if let Err(msg) = gradient_corners_check(corners) {
+            return err(msg);
+        }
+        let [ul, ur, ll, lr] = corners;
+
+        let (cur_y, cur_x) = self.cursor_yx();
+        let (rows, cols) = self.dim_yx();
+        let len_y = len_y.unwrap_or_else(|| rows.saturating_sub(y.unwrap_or(cur_y)));
+        let len_x = len_x.unwrap_or_else(|| cols.saturating_sub(x.unwrap_or(cur_x)));
+        if len_y == 1 && len_x == 1 {
+            if ul != ur || ul != ll || ul != lr {
+                return err("all four corners of a 1x1 region must be the same");
+            }
+        } else if len_x == 1 {
+            if ul != ur || ll != lr {
+                return err("both top and both bottom corners of a Nx1 region must be the same");
+            }
+        } else if len_y == 1 && (ul != ll || ur != lr) {
+            return err("both left and both right corners of a 1xN region must be the same");
+        }
+        Ok(())
     }
 
     /// Converts this `NcPlane`'s content to greyscale.
@@ -2617,29 +3537,186 @@ impl NcPlane {
     }
 }
 
+// -----------------------------------------------------------------------------
+/// ## NcPlane methods: user data
+///
+/// The user data is kept by these bindings, and pointed to by the plane's
+/// `userptr`. Setting another `userptr`, e.g. with
+/// [`ncplane_set_userptr`][c_api::ncplane_set_userptr], detaches it, and then
+/// it's dropped when the plane gets new user data or is destroyed.
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+impl NcPlane {
+    /// Attaches `data` to this `NcPlane`, dropping any previous user data.
+    ///
+    /// *C style function: [ncplane_set_userptr()][c_api::ncplane_set_userptr].*
+    pub fn set_userdata<T: Send + 'static>(&mut self, data: Box<T>) {
+        let mut data: Box<Box<dyn Any + Send>> = Box::new(data);
+        let ptr = &mut *data as *mut Box<dyn Any + Send> as *mut c_void;
+        let previous = super::side_table::with(self, |d| d.userdata.replace(data));
+        unsafe { c_api::ncplane_set_userptr(self, ptr) };
+        drop(previous);
+    }
+
+    /// Returns a reference to the user data, if it's of type `T`.
+    ///
+    /// *C style function: [ncplane_userptr()][c_api::ncplane_userptr].*
+    pub fn userdata<T: Send + 'static>(&self) -> Option<&T> {
+        unsafe { &*self.userdata_ptr()? }.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the user data, if it's of type `T`.
+    ///
+    /// *C style function: [ncplane_userptr()][c_api::ncplane_userptr].*
+    pub fn userdata_mut<T: Send + 'static>(&mut self) -> Option<&mut T> {
+        unsafe { &mut *self.userdata_ptr()? }.downcast_mut()
+    }
+
+    /// Detaches and returns the user data, if it's of type `T`.
+    ///
+    /// User data of any other type is left attached.
+    ///
+    /// *C style function: [ncplane_set_userptr()][c_api::ncplane_set_userptr].*
+    pub fn take_userdata<T: Send + 'static>(&mut self) -> Option<Box<T>> {
+        self.userdata::<T>()?;
+        let data = super::side_table::with(self, |d| d.userdata.take())?;
+        unsafe { c_api::ncplane_set_userptr(self, null_mut()) };
+        data.downcast().ok()
+    }
+
+    // Returns a pointer to the user data, provided it's still the `userptr`,
+    // so that a `userptr` set by other means is never taken for user data.
+    fn userdata_ptr(&self) -> Option<*mut Box<dyn Any + Send>> {
+        let userptr = unsafe { c_api::ncplane_userptr(self as *const NcPlane as *mut NcPlane) };
+        super::side_table::with(self, |d| {
+            let ptr = &mut **d.userdata.as_mut()? as *mut Box<dyn Any + Send>;
+            (ptr as *mut c_void == userptr).then_some(ptr)
+        })
+    }
+}
+
 // -----------------------------------------------------------------------------
 /// ## NcPlane methods: other
 impl NcPlane {
-    /// Draws a QR code at the current position on the plane.
+    /// Draws a QR code with `data` at the current position on the plane.
     ///
-    /// A tuple of 3 elements will be returned: `(version, max_y, max_x)`.
+    /// A tuple of 3 elements will be returned: `(version, len_y, len_x)`.
     ///
     /// - The QR code size is (`version` * 4 + 17) columns wide, and
-    /// ⌈`version` * 4 + 17⌉ rows tall.
-    /// - The properly-scaled values are returned as `max_y` and `max_x`.
+    ///   ⌈`version` * 4 + 17⌉ rows tall.
+    /// - The properly-scaled dimensions of the drawn code are returned as
+    ///   `len_y` and `len_x`.
     ///
-    /// It is an error not to have sufficient room to draw the qrcode.
+    /// The code can use all the space from the cursor to the bottom right
+    /// corner of the plane. It is an error not to have sufficient room to
+    /// draw it, or if the terminal doesn't support UTF-8.
     ///
     /// *C style function: [ncplane_qrcode()][c_api::ncplane_qrcode].*
-    pub fn qrcode(&mut self, data: &mut [u8]) -> NcResult<(u32, u32, u32)> {
-        let (mut max_y, mut max_x) = (0, 0);
-        let len = data.len();
+    pub fn qrcode(&mut self, data: &[u8]) -> NcResult<(u32, u32, u32)> {
+        let nc = unsafe { &*c_api::ncplane_notcurses_const(self) };
+        if !nc.canutf8() {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.qrcode(<{} bytes>): UTF-8 is not supported",
+                data.len()
+            ]));
+        }
+
+        // the maximum size is also an input
+        let ((dim_y, dim_x), (y, x)) = (self.dim_yx(), self.cursor_yx());
+        let (mut max_y, mut max_x) = (dim_y - y, dim_x - x);
         let data_ptr = data.as_ptr() as *const c_void;
-        let res = unsafe { c_api::ncplane_qrcode(self, &mut max_y, &mut max_x, data_ptr, len) };
+        let res =
+            unsafe { c_api::ncplane_qrcode(self, &mut max_y, &mut max_x, data_ptr, data.len()) };
         error![
             res,
-            &format!("NcPlane.qrcode(data:{:?})", data),
+            &format!(
+                "NcPlane.qrcode(<{} bytes>): needs more room than {}x{} cells at {}x{}, or too much data",
+                data.len(),
+                dim_y - y,
+                dim_x - x,
+                y,
+                x
+            ),
             (res as u32, max_y, max_x)
         ]
     }
+
+    /// Loads the image at `path` and draws it into a new child plane of this
+    /// plane, at its origin, returning the child.
+    ///
+    /// The image is scaled with `scale` and blitted with `blitter`, or with
+    /// notcurses' default blitter for media if `None`.
+    ///
+    /// See also [`draw_visual`][NcPlane#method.draw_visual].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn draw_image<'child>(
+        &mut self,
+        nc: &mut Nc,
+        path: &str,
+        scale: impl Into<NcScale>,
+        blitter: Option<NcBlitter>,
+    ) -> NcResult<&'child mut NcPlane> {
+        let visual = NcVisual::from_file(path)?;
+        let res = self.draw_visual(nc, visual, scale, blitter);
+        visual.destroy();
+        res
+    }
+
+    /// Draws the current frame of `visual` into a new child plane of this
+    /// plane, at its origin, returning the child.
+    ///
+    /// The visual is scaled with `scale` and blitted with `blitter`, or with
+    /// notcurses' default blitter for media if `None`.
+    ///
+    /// Returns an error if the visual can't be blitted with those options.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn draw_visual<'child>(
+        &mut self,
+        nc: &mut Nc,
+        visual: &mut NcVisual,
+        scale: impl Into<NcScale>,
+        blitter: Option<NcBlitter>,
+    ) -> NcResult<&'child mut NcPlane> {
+        let scale = scale.into();
+        let blitter = blitter.unwrap_or(NcBlitter::Default);
+        let options = NcVisualOptions::builder()
+            .parent(self)
+            .scale(scale)
+            .blitter(blitter)
+            .build();
+        visual.geom(Some(nc), Some(&options)).map_err(|e| {
+            NcError::with_msg(
+                e.int,
+                &format![
+                    "NcPlane.draw_visual(nc, visual, {}, {}): {}",
+                    scale, blitter, e.msg
+                ],
+            )
+        })?;
+        // the child plane is new, so there can't be other references to it
+        let child = unsafe { visual.blit(nc, Some(&options))? };
+        Ok(unsafe { &mut *(child as *mut NcPlane) })
+    }
+}
+
+// Checks the preconditions of the gradient corner channels.
+fn gradient_corners_check(corners: [NcChannels; 4]) -> Result<(), &'static str> {
+    gradient_channel_check(corners.map(|c| c.fchannel()))?;
+    gradient_channel_check(corners.map(|c| c.bchannel()))
+}
+
+// Checks the preconditions of the gradient corner channel components.
+fn gradient_channel_check(corners: [NcChannel; 4]) -> Result<(), &'static str> {
+    let defaults = corners.iter().filter(|c| c.default_p()).count();
+    if defaults != 0 && defaults != 4 {
+        Err("the corners must be either all default or all RGB")
+    } else if corners.iter().any(|c| c.alpha() != corners[0].alpha()) {
+        Err("the corners must have the same alpha")
+    } else if corners.iter().any(|c| c.palindex_p()) {
+        Err("the corners can't be palette-indexed")
+    } else {
+        Ok(())
+    }
 }
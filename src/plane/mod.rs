@@ -1,9 +1,9 @@
 //! `NcPlane`
 
-// functions already exported by bindgen : 100
+// functions already exported by bindgen : 102
 // -------------------------------------------
-// (#) test: 9
-// (W) wrap: 91
+// (#) test: 22
+// (W) wrap: 96
 // -------------------------------------------
 //W  ncpile_bottom
 //W# ncpile_create
@@ -26,7 +26,7 @@
 //W  ncplane_below
 //W  ncplane_box
 //W  ncplane_center_abs
-//W  ncplane_contents
+//W# ncplane_contents
 //W  ncplane_create
 //W  ncplane_cursor_move_rel
 //W# ncplane_cursor_move_yx
@@ -45,13 +45,14 @@
 //W  ncplane_greyscale
 //   ncplane_hline_interp
 //W# ncplane_home
-//W  ncplane_mergedown
-//W  ncplane_mergedown_simple
+//W# ncplane_mergedown
+//W# ncplane_mergedown_simple
 //W  ncplane_move_above
 //W  ncplane_move_below
 //W  ncplane_move_family_above
 //W  ncplane_move_family_below
 //W  ncplane_move_yx
+//W# ncplane_name
 //W# ncplane_notcurses
 //W# ncplane_notcurses_const
 //W  ncplane_off_styles
@@ -59,23 +60,23 @@
 //W  ncplane_parent
 //W  ncplane_parent_const
 //W  ncplane_pixelgeom
-//   ncplane_polyfill_yx
+//W# ncplane_polyfill_yx
 //W  ncplane_pulse
 //W  ncplane_putc_yx
 //W  ncplane_putnstr_aligned
 //W  ncplane_putnstr_yx
 //W  ncplane_puttext
 //W  ncplane_qrcode
-//W  ncplane_reparent
-//W  ncplane_reparent_family
+//W# ncplane_reparent
+//W# ncplane_reparent_family
 //W# ncplane_resize
 //W  ncplane_resize_marginalized
 //W  ncplane_resize_maximize
 //W  ncplane_resize_placewithin
 //W  ncplane_resize_realign
 //W  ncplane_resizecb
-//W  ncplane_rotate_ccw
-//W  ncplane_rotate_cw
+//W# ncplane_rotate_ccw
+//W# ncplane_rotate_cw
 //W  ncplane_scrolling_p
 //W  ncplane_scrollup,
 //W  ncplane_scrollup_child,
@@ -92,15 +93,16 @@
 //W  ncplane_set_fg_palindex
 //W  ncplane_set_fg_rgb
 //   ncplane_set_fg_rgb8           // unneeded method
+//W# ncplane_set_name
 //W  ncplane_set_resizecb
 //W  ncplane_set_scrolling
 //W  ncplane_set_styles
-//   ncplane_set_userptr
-//W  ncplane_stain
+//W# ncplane_set_userptr
+//W# ncplane_stain
 //W  ncplane_styles
 //W  ncplane_translate
 //W  ncplane_translate_abs
-//   ncplane_userptr
+//W# ncplane_userptr
 //   ncplane_vline_interp
 //W  ncplane_x
 //W  ncplane_y
@@ -110,7 +112,7 @@
 // ------------------------------------------
 // (+) done: 49
 // (W) wrap: 45
-// (#) test:  9
+// (#) test: 10
 // ------------------------------------------
 //W+ ncplane_ascii_box WIP
 //W+ ncplane_bchannel
@@ -129,7 +131,7 @@
 //W# ncplane_fg_default_p
 //W+ ncplane_fg_rgb
 //W+ ncplane_fg_rgb8
-//W+ ncplane_gradient
+//W# ncplane_gradient
 //W+ ncplane_gradient_sized
 //W+ ncplane_halign
 // + ncplane_hline
@@ -185,14 +187,30 @@
 //   ncplane_putwstr_yx
 //   ncplane_vprintf
 
+mod ansi;
+mod cells;
+mod family;
 pub(crate) mod helpers;
+mod markup;
 mod methods;
 pub(crate) mod options;
+mod pile;
 pub(crate) mod reimplemented;
+mod rgba_buffer;
+#[cfg(feature = "std")]
+pub(crate) mod side_table;
 #[cfg(test)]
 pub(crate) mod test;
+#[cfg(feature = "std")]
+mod writer;
 
+pub use cells::NcPlaneCells;
+pub use family::NcPlaneFamily;
 pub use options::{NcPlaneFlag, NcPlaneOptions, NcPlaneOptionsBuilder};
+pub use pile::{NcPile, NcPilePlanes};
+pub use rgba_buffer::NcRgbaBuffer;
+#[cfg(feature = "std")]
+pub use writer::NcPlaneWriter;
 
 // NcPlane
 //
@@ -276,6 +294,7 @@ pub use options::{NcPlaneFlag, NcPlaneOptions, NcPlaneOptionsBuilder};
 /// - [boxes & perimeters](#ncplane-methods-boxes--perimeters)
 /// - [Size, position & alignment](#ncplane-methods-size-position--alignment)
 /// - [fading, gradients & greyscale](#ncplane-methods-fading-gradients--greyscale)
+/// - [user data](#ncplane-methods-user-data)
 /// - [*other*](#ncplane-methods-other)
 ///
 /// [`NcCell`]: crate::NcCell
@@ -284,3 +303,20 @@ pub use options::{NcPlaneFlag, NcPlaneOptions, NcPlaneOptionsBuilder};
 /// [`NcPlane.rasterize`]: crate::NcPlane#method.render
 /// [`Nc.refresh`]: crate::Nc#method.refresh
 pub type NcPlane = crate::c_api::ffi::ncplane;
+
+mod core_impls {
+    use super::NcPlane;
+    use core::fmt;
+
+    /// Writes formatted strings at the cursor, like [`NcPlane.putstr`].
+    ///
+    /// If scrolling is enabled, output past the end of the plane scrolls it,
+    /// otherwise it fails with [`fmt::Error`].
+    ///
+    /// [`NcPlane.putstr`]: NcPlane#method.putstr
+    impl fmt::Write for NcPlane {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.putstr(s).map(|_| ()).map_err(|_| fmt::Error)
+        }
+    }
+}
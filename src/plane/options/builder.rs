@@ -2,7 +2,21 @@
 
 use core::ptr::{null, null_mut};
 
-use crate::{c_api, NcAlign, NcPlaneFlag, NcPlaneOptions, NcResizeCb};
+use crate::{
+    c_api, cstring, Nc, NcAlign, NcPlane, NcPlaneFlag, NcPlaneOptions, NcResizeCb, NcResult,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::ffi::CString;
+
+#[cfg(feature = "std")]
+use {
+    crate::resizecb::closure::{self, NcResizeClosure},
+    std::{
+        ffi::CString,
+        sync::{Arc, Mutex},
+    },
+};
 
 /// Builder object for [`NcPlaneOptions`].
 ///
@@ -24,9 +38,10 @@ pub struct NcPlaneOptionsBuilder {
     // The void* ‘userptr’ can be retrieved (and reset) later.
     // pub(crate) userptr: mut* c_void,
     //
-    // A ‘name’ can be set, used in debugging.
-    // pub(crate) name: String,
+    pub(crate) name: Option<CString>,
     pub(crate) resizecb: Option<NcResizeCb>,
+    #[cfg(feature = "std")]
+    pub(crate) on_resize: Option<NcResizeClosure>,
     pub(crate) flags: u64,
     pub(crate) margin_b: u32,
     pub(crate) margin_r: u32,
@@ -83,7 +98,10 @@ mod core_impls {
                 x: 0,
                 rows: 0,
                 cols: 0,
+                name: None,
                 resizecb: None,
+                #[cfg(feature = "std")]
+                on_resize: None,
                 flags: NcPlaneFlag::Marginalized.into(),
                 margin_b: 0,
                 margin_r: 0,
@@ -95,12 +113,20 @@ mod core_impls {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             let resizecb_str =
                 if self.resizecb.is_some() { String::from("Some") } else { String::from("None") };
-            f.debug_struct("NcPlaneOptionsBuilder")
+            let mut debug = f.debug_struct("NcPlaneOptionsBuilder");
+            debug
                 .field("y", &self.y)
                 .field("x", &self.x)
                 .field("rows", &self.rows)
                 .field("cols", &self.cols)
-                .field("resizecb", &resizecb_str)
+                .field("name", &self.name)
+                .field("resizecb", &resizecb_str);
+            #[cfg(feature = "std")]
+            debug.field(
+                "on_resize",
+                &if self.on_resize.is_some() { String::from("Some") } else { String::from("None") },
+            );
+            debug
                 .field("flags", &self.flags)
                 .field("margin_b", &self.margin_b)
                 .field("margin_r", &self.margin_r)
@@ -154,12 +180,24 @@ impl NcPlaneOptionsBuilder {
             builder = builder.x(options.x);
         }
 
-        // TODO: name, userptr
+        if !options.name.is_null() {
+            builder.name = Some(unsafe { core::ffi::CStr::from_ptr(options.name) }.into());
+        }
+
+        // TODO: userptr
 
         builder
     }
 
     /// Finishes the building and returns [`NcPlaneOptions`].
+    ///
+    /// Note that the returned options don't include the [`name`], since they
+    /// can't outlive the builder. Use [`new_pile`] or [`new_child`] instead to
+    /// create a named plane.
+    ///
+    /// [`name`]: NcPlaneOptionsBuilder#method.name
+    /// [`new_pile`]: NcPlaneOptionsBuilder#method.new_pile
+    /// [`new_child`]: NcPlaneOptionsBuilder#method.new_child
     pub fn build(self) -> NcPlaneOptions {
         NcPlaneOptions {
             y: self.y,
@@ -174,6 +212,44 @@ impl NcPlaneOptionsBuilder {
             margin_r: self.margin_r,
         }
     }
+
+    /// Finishes the building and returns a new pile with these options.
+    ///
+    /// See [`NcPlane::new_pile`].
+    pub fn new_pile<'plane>(&self, nc: &mut Nc) -> NcResult<&'plane mut NcPlane> {
+        let plane = NcPlane::new_pile(nc, &self.options_with_name())?;
+        self.set_on_resize_of(plane);
+        Ok(plane)
+    }
+
+    /// Finishes the building and returns a new child of `parent` with these
+    /// options.
+    ///
+    /// See [`NcPlane::new_child`].
+    pub fn new_child<'plane>(&self, parent: &mut NcPlane) -> NcResult<&'plane mut NcPlane> {
+        let plane = NcPlane::new_child(parent, &self.options_with_name())?;
+        self.set_on_resize_of(plane);
+        Ok(plane)
+    }
+
+    // Sets the resize closure, if any, to the newly created `plane`.
+    #[allow(unused_variables)]
+    fn set_on_resize_of(&self, plane: &mut NcPlane) {
+        #[cfg(feature = "std")]
+        if let Some(on_resize) = &self.on_resize {
+            closure::set(plane, on_resize.clone());
+        }
+    }
+
+    // Returns the options including a pointer to the name, only valid
+    // for as long as this builder.
+    fn options_with_name(&self) -> NcPlaneOptions {
+        let mut options = self.clone().build();
+        if let Some(name) = &self.name {
+            options.name = name.as_ptr();
+        }
+        options
+    }
 }
 
 /// # Methods (chainable)
@@ -388,8 +464,75 @@ impl NcPlaneOptionsBuilder {
     /// (Un)Sets the resize callback.
     ///
     /// Default: *None*.
+    ///
+    /// Effect: Unsets the [`on_resize`] closure.
+    ///
+    /// [`on_resize`]: NcPlaneOptionsBuilder#method.on_resize
     pub fn resizecb(mut self, callback: Option<NcResizeCb>) -> Self {
-        self.resizecb = callback;
+        self.set_resizecb(callback);
+        self
+    }
+
+    /// Sets a closure as the resize callback.
+    ///
+    /// Default: *None*.
+    ///
+    /// Effect: Unsets the [`resizecb`].
+    ///
+    /// Note that the closure is only applied by [`new_pile`] & [`new_child`],
+    /// and not by [`build`].
+    ///
+    /// The closure is shared by all the planes created by this builder, and
+    /// it's not called again while it's running, so resizing one of them from
+    /// the closure of another one makes that resize fail. Use a builder per
+    /// plane for nested planes.
+    ///
+    /// [`resizecb`]: NcPlaneOptionsBuilder#method.resizecb
+    /// [`new_pile`]: NcPlaneOptionsBuilder#method.new_pile
+    /// [`new_child`]: NcPlaneOptionsBuilder#method.new_child
+    /// [`build`]: NcPlaneOptionsBuilder#method.build
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn on_resize(
+        mut self,
+        closure: impl FnMut(&mut NcPlane) -> NcResult<()> + Send + 'static,
+    ) -> Self {
+        self.set_on_resize(closure);
+        self
+    }
+
+    /// Sets [`NcPlane.resize_maximize`] as the resize callback.
+    ///
+    /// [`NcPlane.resize_maximize`]: NcPlane#method.resize_maximize
+    pub fn resize_maximize(self) -> Self {
+        self.resizecb(c_api::ncresizecb_to_rust(Some(
+            c_api::ffi::ncplane_resize_maximize,
+        )))
+    }
+
+    /// Sets [`NcPlane.resize_realign`] as the resize callback.
+    ///
+    /// [`NcPlane.resize_realign`]: NcPlane#method.resize_realign
+    pub fn resize_realign(self) -> Self {
+        self.resizecb(c_api::ncresizecb_to_rust(Some(
+            c_api::ffi::ncplane_resize_realign,
+        )))
+    }
+
+    /// Sets [`NcPlane.resize_marginalized`] as the resize callback.
+    ///
+    /// [`NcPlane.resize_marginalized`]: NcPlane#method.resize_marginalized
+    pub fn resize_marginalized(self) -> Self {
+        self.resizecb(c_api::ncresizecb_to_rust(Some(
+            c_api::ffi::ncplane_resize_marginalized,
+        )))
+    }
+
+    /// Sets the name of the plane, used in debugging.
+    ///
+    /// Default: *None*.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(cstring![name]);
         self
     }
 }
@@ -593,7 +736,42 @@ impl NcPlaneOptionsBuilder {
     /// (Un)Sets the resize callback.
     ///
     /// Default: *None*.
+    ///
+    /// Effect: Unsets the [`on_resize`] closure.
+    ///
+    /// [`on_resize`]: NcPlaneOptionsBuilder#method.set_on_resize
     pub fn set_resizecb(&mut self, callback: Option<NcResizeCb>) {
         self.resizecb = callback;
+        #[cfg(feature = "std")]
+        {
+            self.on_resize = None;
+        }
+    }
+
+    /// Sets a closure as the resize callback.
+    ///
+    /// Default: *None*.
+    ///
+    /// Effect: Unsets the [`resizecb`].
+    ///
+    /// See [`on_resize`] about reusing the builder.
+    ///
+    /// [`resizecb`]: NcPlaneOptionsBuilder#method.set_resizecb
+    /// [`on_resize`]: NcPlaneOptionsBuilder#method.on_resize
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn set_on_resize(
+        &mut self,
+        closure: impl FnMut(&mut NcPlane) -> NcResult<()> + Send + 'static,
+    ) {
+        self.resizecb = None;
+        self.on_resize = Some(Arc::new(Mutex::new(closure)));
+    }
+
+    /// Sets the name of the plane, used in debugging.
+    ///
+    /// Default: *None*.
+    pub fn set_name(&mut self, name: &str) {
+        self.name = Some(cstring![name]);
     }
 }
@@ -158,4 +158,32 @@ impl NcPlaneOptions {
     pub fn is_vscroll(&self) -> bool {
         self.flags & NcPlaneFlag::VScroll != NcPlaneFlag::None
     }
+
+    /// (Un)Sets the [`AutoGrow`] flag.
+    ///
+    /// See [`NcPlane.set_autogrow`] for its effects.
+    ///
+    /// [`AutoGrow`]: NcPlaneFlag#associatedconstant.AutoGrow
+    /// [`NcPlane.set_autogrow`]: crate::NcPlane#method.set_autogrow
+    pub fn set_autogrow(&mut self, autogrow: bool) {
+        self.set_flag(NcPlaneFlag::AutoGrow, autogrow);
+    }
+
+    /// (Un)Sets the [`VScroll`] flag.
+    ///
+    /// See [`NcPlane.set_scrolling`] for its effects.
+    ///
+    /// [`VScroll`]: NcPlaneFlag#associatedconstant.VScroll
+    /// [`NcPlane.set_scrolling`]: crate::NcPlane#method.set_scrolling
+    pub fn set_vscroll(&mut self, vscroll: bool) {
+        self.set_flag(NcPlaneFlag::VScroll, vscroll);
+    }
+
+    fn set_flag(&mut self, flag: NcPlaneFlag, set: bool) {
+        if set {
+            self.flags |= u64::from(flag);
+        } else {
+            self.flags &= !u64::from(flag);
+        }
+    }
 }
@@ -0,0 +1,163 @@
+//! `NcPile`
+
+use core::{ffi::c_void, marker::PhantomData, ptr::null_mut, slice};
+
+use crate::{c_api, error, Nc, NcError, NcPlane, NcPlaneOptions, NcResult};
+
+#[cfg(feature = "std")]
+use crate::NcFile;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A handle to a pile: a collection of [`NcPlane`]s sharing a z-axis, that
+/// are rendered together.
+///
+/// Any plane of the pile can be used to refer to the pile.
+///
+/// Created by [`NcPile::new`] or [`NcPlane.pile`].
+///
+/// [`NcPlane.pile`]: NcPlane#method.pile
+#[derive(Debug)]
+pub struct NcPile<'plane> {
+    plane: &'plane mut NcPlane,
+}
+
+/// # Constructors
+impl<'plane> NcPile<'plane> {
+    /// Creates a new pile, returning a handle to it.
+    ///
+    /// The new pile is made of a single root plane created with `options`.
+    ///
+    /// See [`NcPlane::new_pile`].
+    pub fn new(nc: &mut Nc, options: &NcPlaneOptions) -> NcResult<NcPile<'plane>> {
+        Ok(Self { plane: NcPlane::new_pile(nc, options)? })
+    }
+
+    /// Returns a handle to the pile of which `plane` is a part.
+    pub fn from_plane(plane: &'plane mut NcPlane) -> NcPile<'plane> {
+        Self { plane }
+    }
+}
+
+/// # Methods
+impl<'plane> NcPile<'plane> {
+    /// Returns the plane used to refer to this pile.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Returns the topmost `NcPlane` of this pile.
+    ///
+    /// *C style function: [ncpile_top()][c_api::ncpile_top].*
+    pub fn top(&mut self) -> &mut NcPlane {
+        unsafe { &mut *c_api::ncpile_top(self.plane) }
+    }
+
+    /// Returns the bottommost `NcPlane` of this pile.
+    ///
+    /// *C style function: [ncpile_bottom()][c_api::ncpile_bottom].*
+    pub fn bottom(&mut self) -> &mut NcPlane {
+        unsafe { &mut *c_api::ncpile_bottom(self.plane) }
+    }
+
+    /// Returns an iterator over the planes of this pile, from the top of the
+    /// z-axis to the bottom.
+    pub fn planes(&mut self) -> NcPilePlanes<'_> {
+        let next = unsafe { c_api::ncpile_top(self.plane) };
+        NcPilePlanes { next, _pile: PhantomData }
+    }
+
+    /// Renders this pile.
+    ///
+    /// Rendering this pile again will blow away the render.
+    /// To actually write out the render, call [`rasterize`].
+    ///
+    /// *C style function: [ncpile_render()][c_api::ncpile_render].*
+    ///
+    /// [`rasterize`]: NcPile#method.rasterize
+    pub fn render(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncpile_render(self.plane) },
+            "NcPile.render()"
+        ]
+    }
+
+    /// Makes the physical screen match the last rendered frame of this pile.
+    ///
+    /// This is a blocking call. Don't call this before the pile has been
+    /// rendered (doing so will likely result in a blank screen).
+    ///
+    /// *C style function: [ncpile_rasterize()][c_api::ncpile_rasterize].*
+    pub fn rasterize(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncpile_rasterize(self.plane) },
+            "NcPile.rasterize()"
+        ]
+    }
+
+    /// Renders and rasterizes this pile.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_raster(&mut self) -> NcResult<()> {
+        self.render()?;
+        self.rasterize()
+    }
+
+    /// Performs the rendering and rasterization of this pile, returning the
+    /// resulting buffer instead of writing it out to the terminal.
+    ///
+    /// *C style function: [ncpile_render_to_buffer()][c_api::ncpile_render_to_buffer].*
+    pub fn render_to_buffer(&mut self) -> NcResult<Vec<u8>> {
+        let mut buf = null_mut();
+        let mut len = 0;
+        let res = unsafe { c_api::ncpile_render_to_buffer(self.plane, &mut buf, &mut len) };
+        if res < c_api::NCRESULT_OK || buf.is_null() {
+            return Err(NcError::with_msg(
+                c_api::NCRESULT_ERR,
+                "NcPile.render_to_buffer()",
+            ));
+        }
+        let buffer = unsafe { slice::from_raw_parts(buf as *const u8, len) }.to_vec();
+        unsafe { c_api::ffi::free(buf as *mut c_void) };
+        Ok(buffer)
+    }
+
+    /// Writes the last rendered frame of this pile, in its entirety, to `fp`.
+    ///
+    /// If a frame has not yet been rendered, nothing will be written.
+    ///
+    /// *C style function: [ncpile_render_to_file()][c_api::ncpile_render_to_file].*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn render_to_file(&mut self, fp: &mut NcFile) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncpile_render_to_file(self.plane, fp.as_nc_ptr()) },
+            "NcPile.render_to_file()"
+        ]
+    }
+}
+
+/// An iterator over the planes of an [`NcPile`], from the top of the z-axis
+/// to the bottom.
+///
+/// Created by [`NcPile.planes`][NcPile#method.planes].
+#[derive(Debug)]
+pub struct NcPilePlanes<'pile> {
+    next: *mut NcPlane,
+    _pile: PhantomData<&'pile mut NcPlane>,
+}
+
+impl<'pile> Iterator for NcPilePlanes<'pile> {
+    type Item = &'pile mut NcPlane;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next.is_null() {
+            None
+        } else {
+            let plane = self.next;
+            self.next = unsafe { c_api::ncplane_below(plane) };
+            Some(unsafe { &mut *plane })
+        }
+    }
+}
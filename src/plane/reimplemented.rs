@@ -404,6 +404,8 @@ pub fn ncplane_putstr(plane: &mut NcPlane, string: &str) -> NcResult_i32 {
 /// If a glyph can not fit in the current line, it is an error, unless
 /// scrolling is enabled.
 ///
+/// Tabs are expanded up to the next [tab stop][NcPlane#method.tab_width].
+///
 /// *Method: NcPlane.[putstr_yx()][NcPlane#method.putstr_yx].*
 #[inline]
 pub fn ncplane_putstr_yx(
@@ -420,14 +422,19 @@ pub fn ncplane_putstr_yx(
 
     while unsafe { cs_ptr.read() != 0 } {
         let mut wcs = 0;
-        let cols = unsafe {
-            c_api::ffi::ncplane_putegc_yx(
-                plane,
-                y.unwrap_or(u32::MAX) as i32,
-                x.unwrap_or(u32::MAX) as i32,
-                cs_ptr,
-                &mut wcs,
-            )
+        let cols = if unsafe { cs_ptr.read() } == b'\t' as c_char {
+            wcs = 1;
+            ncplane_puttab_yx(plane, y, x, false)
+        } else {
+            unsafe {
+                c_api::ffi::ncplane_putegc_yx(
+                    plane,
+                    y.unwrap_or(u32::MAX) as i32,
+                    x.unwrap_or(u32::MAX) as i32,
+                    cs_ptr,
+                    &mut wcs,
+                )
+            }
         };
         if cols < 0 {
             return -ret;
@@ -493,6 +500,8 @@ pub fn ncplane_putstr_aligned(
 /// If a glyph can not fit in the current line, it is an error, unless
 /// scrolling is enabled.
 ///
+/// Tabs are expanded up to the next [tab stop][NcPlane#method.tab_width].
+///
 /// *Method: NcPlane.[putstr_stained()][NcPlane#method.putstr_stained].*
 #[inline]
 pub fn ncplane_putstr_stained(plane: &mut NcPlane, string: &str) -> NcResult_i32 {
@@ -502,7 +511,12 @@ pub fn ncplane_putstr_stained(plane: &mut NcPlane, string: &str) -> NcResult_i32
     let mut ret = 0;
     while unsafe { cstring_ptr.read() != 0 } {
         let mut wcs = 0;
-        let cols = unsafe { c_api::ffi::ncplane_putegc_stained(plane, cstring_ptr, &mut wcs) };
+        let cols = if unsafe { cstring_ptr.read() } == b'\t' as c_char {
+            wcs = 1;
+            ncplane_puttab_yx(plane, None, None, true)
+        } else {
+            unsafe { c_api::ffi::ncplane_putegc_stained(plane, cstring_ptr, &mut wcs) }
+        };
 
         if cols < 0 {
             return -ret;
@@ -528,6 +542,8 @@ pub fn ncplane_putstr_stained(plane: &mut NcPlane, string: &str) -> NcResult_i32
 /// If a glyph can not fit in the current line, it is an error, unless
 /// scrolling is enabled.
 ///
+/// Tabs are expanded up to the next [tab stop][NcPlane#method.tab_width].
+///
 /// *Method: NcPlane.[putnstr()][NcPlane#method.putnstr].*
 #[inline]
 pub fn ncplane_putnstr_yx(
@@ -541,19 +557,24 @@ pub fn ncplane_putnstr_yx(
     let cstring_bytes_len = cstring.as_bytes().len();
     let cstring_ptr = cstring.as_ptr();
 
-    let (ret, mut offset) = (0, 0);
+    let (mut ret, mut offset) = (0, 0);
     let (mut y, mut x) = (y, x);
 
     while offset < num_bytes && offset < cstring_bytes_len {
         let mut wcs = 0;
-        let cols = unsafe {
-            c_api::ffi::ncplane_putegc_yx(
-                plane,
-                y.unwrap_or(u32::MAX) as i32,
-                x.unwrap_or(u32::MAX) as i32,
-                cstring_ptr.add(offset),
-                &mut wcs,
-            )
+        let cols = if cstring.as_bytes()[offset] == b'\t' {
+            wcs = 1;
+            ncplane_puttab_yx(plane, y, x, false)
+        } else {
+            unsafe {
+                c_api::ffi::ncplane_putegc_yx(
+                    plane,
+                    y.unwrap_or(u32::MAX) as i32,
+                    x.unwrap_or(u32::MAX) as i32,
+                    cstring_ptr.add(offset),
+                    &mut wcs,
+                )
+            }
         };
         if cols < 0 {
             return c_api::NCRESULT_ERR;
@@ -567,10 +588,53 @@ pub fn ncplane_putnstr_yx(
         y = None;
         x = None;
         offset += wcs;
+        ret += cols;
     }
     ret
 }
 
+/// Expands a tab with spaces, from the provided location up to the next tab
+/// stop of the plane, without going past the end of the line.
+///
+/// Returns the number of columns advanced, or [`NCRESULT_ERR`] on error.
+///
+/// See [`NcPlane.tab_width`][NcPlane#method.tab_width].
+fn ncplane_puttab_yx(plane: &mut NcPlane, y: Option<u32>, x: Option<u32>, stained: bool) -> i32 {
+    if (y.is_some() || x.is_some())
+        && unsafe {
+            c_api::ffi::ncplane_cursor_move_yx(
+                plane,
+                y.unwrap_or(u32::MAX) as i32,
+                x.unwrap_or(u32::MAX) as i32,
+            )
+        } < NCRESULT_OK
+    {
+        return NCRESULT_ERR;
+    }
+
+    let width = plane.tab_width();
+    if width == 0 {
+        return 0;
+    }
+    let cursor_x = ncplane_cursor_x(plane);
+    let cols = (width - cursor_x % width).min(ncplane_dim_x(plane).saturating_sub(cursor_x));
+
+    let space = b" \0".as_ptr() as *const c_char;
+    for _ in 0..cols {
+        let res = unsafe {
+            if stained {
+                c_api::ffi::ncplane_putegc_stained(plane, space, null_mut())
+            } else {
+                c_api::ffi::ncplane_putegc_yx(plane, -1, -1, space, null_mut())
+            }
+        };
+        if res < NCRESULT_OK {
+            return NCRESULT_ERR;
+        }
+    }
+    cols as i32
+}
+
 /// Writes a string to the current location, using the current style,
 /// and no more than `num_bytes` bytes will be written.
 ///
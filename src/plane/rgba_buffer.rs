@@ -0,0 +1,99 @@
+//! `NcRgbaBuffer`
+
+use core::{ptr::NonNull, slice};
+
+use crate::NcPixel;
+
+/// A flat buffer of pixels in RGBA byte order, as returned by
+/// [`NcPlane.as_rgba`].
+///
+/// The pixels are accessed as [`NcPixel`]s, which have that memory layout.
+///
+/// Owns the memory allocated by notcurses, which is freed on drop.
+///
+/// [`NcPlane.as_rgba`]: crate::NcPlane#method.as_rgba
+#[derive(Debug)]
+pub struct NcRgbaBuffer {
+    ptr: NonNull<NcPixel>,
+    pixel_y: u32,
+    pixel_x: u32,
+}
+
+impl NcRgbaBuffer {
+    /// Takes ownership of a buffer of `pixel_y` * `pixel_x` pixels allocated
+    /// by notcurses.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated with `malloc`, and point to at least
+    /// `pixel_y` * `pixel_x` initialized pixels.
+    pub(crate) unsafe fn from_raw(ptr: NonNull<NcPixel>, pixel_y: u32, pixel_x: u32) -> Self {
+        Self { ptr, pixel_y, pixel_x }
+    }
+
+    /// Returns the dimensions of the buffer in pixels, as `(rows, columns)`.
+    pub fn pixel_yx(&self) -> (u32, u32) {
+        (self.pixel_y, self.pixel_x)
+    }
+
+    /// Returns the number of pixels.
+    pub fn len(&self) -> usize {
+        self.pixel_y as usize * self.pixel_x as usize
+    }
+
+    /// Returns `true` if the buffer contains no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the pixels as a slice, row by row.
+    pub fn as_slice(&self) -> &[NcPixel] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len()) }
+    }
+
+    /// Returns the pixels as a mutable slice, row by row.
+    pub fn as_mut_slice(&mut self) -> &mut [NcPixel] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len()) }
+    }
+
+    /// Returns the pixels as a slice of bytes, in RGBA order.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr() as *const u8, self.len() * 4) }
+    }
+
+    /// Returns the pixel at `y`, `x`, if it's inside the buffer.
+    pub fn get(&self, y: u32, x: u32) -> Option<NcPixel> {
+        if y < self.pixel_y && x < self.pixel_x {
+            Some(self.as_slice()[y as usize * self.pixel_x as usize + x as usize])
+        } else {
+            None
+        }
+    }
+}
+
+mod core_impls {
+    use super::NcRgbaBuffer;
+    use crate::{c_api::ffi, NcPixel};
+    use core::{
+        ffi::c_void,
+        ops::{Deref, DerefMut},
+    };
+
+    impl Deref for NcRgbaBuffer {
+        type Target = [NcPixel];
+        fn deref(&self) -> &Self::Target {
+            self.as_slice()
+        }
+    }
+    impl DerefMut for NcRgbaBuffer {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.as_mut_slice()
+        }
+    }
+
+    impl Drop for NcRgbaBuffer {
+        /// Frees the pixels allocated by notcurses.
+        fn drop(&mut self) {
+            unsafe { ffi::free(self.ptr.as_ptr() as *mut c_void) };
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Side table of the data kept by these bindings for each plane.
+//!
+//! notcurses has no room for it in the plane itself, since its `userptr`
+//! belongs to the user, so it's kept here, keyed by the address of the plane.
+//!
+//! An entry must be forgotten when its plane is destroyed, or a new plane
+//! allocated at the same address would inherit it. That is done by:
+//! - [`NcPlane.destroy`], for the plane alone, since its children survive it.
+//! - the `destroy` methods of the widgets, for the plane of the widget.
+//! - [`Nc.stop`] & [`Nc.drop_planes`], for all the planes they destroy.
+//!
+//! The entries of the planes that notcurses destroys on its own, like those
+//! of the tablets of an `NcReel`, or those destroyed through the C API,
+//! are left behind.
+//!
+//! The table is shared by all the threads, since a plane can be resized,
+//! and so its resize closure called, from any of them.
+//!
+//! [`NcPlane.destroy`]: crate::NcPlane#method.destroy
+//! [`Nc.stop`]: crate::Nc#method.stop
+//! [`Nc.drop_planes`]: crate::Nc#method.drop_planes
+
+use crate::{resizecb::closure::NcResizeClosure, NcPlane};
+use core::any::Any;
+use std::{
+    boxed::Box,
+    sync::{Mutex, MutexGuard},
+    vec::Vec,
+};
+
+/// The data kept for a plane.
+#[derive(Default)]
+pub(crate) struct PlaneData {
+    /// The resize closure, called by the C resize callback of the plane.
+    pub(crate) on_resize: Option<NcResizeClosure>,
+    /// The user data, pointed to by the `userptr` of the plane unless
+    /// that has been set to something else since.
+    pub(crate) userdata: Option<Box<Box<dyn Any + Send>>>,
+    /// The cursor positions saved by `push_cursor`.
+    pub(crate) cursor_stack: Vec<(u32, u32)>,
+    /// The tab width, unless it's the default one.
+    pub(crate) tab_width: Option<u32>,
+    /// The number of items of the multiselector using the plane, if any.
+    pub(crate) selector_items: Option<u32>,
+}
+
+impl PlaneData {
+    fn is_empty(&self) -> bool {
+        self.on_resize.is_none()
+            && self.userdata.is_none()
+            && self.cursor_stack.is_empty()
+            && self.tab_width.is_none()
+            && self.selector_items.is_none()
+    }
+}
+
+static PLANES: Mutex<Vec<(usize, PlaneData)>> = Mutex::new(Vec::new());
+
+// The table stays usable after a panic, since it's never left inconsistent.
+fn planes() -> MutexGuard<'static, Vec<(usize, PlaneData)>> {
+    PLANES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Calls `f` with the data of the `plane`, which is created if needed,
+/// and forgotten afterwards if left empty.
+///
+/// The table is locked meanwhile, so `f` must not access it again, nor drop
+/// anything provided by the user; it can return it instead.
+pub(crate) fn with<R>(plane: *const NcPlane, f: impl FnOnce(&mut PlaneData) -> R) -> R {
+    let mut planes = planes();
+    let index = match planes.iter().position(|(p, _)| *p == plane as usize) {
+        Some(index) => index,
+        None => {
+            planes.push((plane as usize, PlaneData::default()));
+            planes.len() - 1
+        }
+    };
+    let res = f(&mut planes[index].1);
+    if planes[index].1.is_empty() {
+        planes.swap_remove(index);
+    }
+    res
+}
+
+/// Forgets the data of the `plane`.
+pub(crate) fn forget(plane: *const NcPlane) {
+    let data = {
+        let mut planes = planes();
+        planes
+            .iter()
+            .position(|(p, _)| *p == plane as usize)
+            .map(|index| planes.swap_remove(index))
+    };
+    drop(data);
+}
+
+/// Forgets the data of all the planes, except for the `kept` one.
+pub(crate) fn forget_all_but(kept: *const NcPlane) {
+    let data: Vec<_> = {
+        let mut planes = planes();
+        let (kept, forgotten): (Vec<_>, Vec<_>) =
+            planes.drain(..).partition(|(p, _)| *p == kept as usize);
+        *planes = kept;
+        forgotten
+    };
+    drop(data);
+}
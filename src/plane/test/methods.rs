@@ -1,4 +1,620 @@
 //! Test `NcPlane` methods and associated functions.
 
-// use crate::NcPlane;
-// use serial_test::serial;
+use crate::{
+    c_api::{ncplane_new_test, notcurses_init_test, notcurses_stop},
+    NcAlign, NcCell, NcChannels, NcPile, NcPlane, NcPlaneOptions, NcPlaneOptionsBuilder, NcStyle,
+};
+use serial_test::serial;
+
+#[test]
+fn options_builder() {
+    let builder = NcPlaneOptions::builder()
+        .yx(1, 2)
+        .rows_cols(3, 4)
+        .vscroll(true)
+        .name("plane");
+    assert_eq![
+        Some("plane"),
+        builder.name.as_ref().and_then(|n| n.to_str().ok())
+    ];
+
+    let options = builder.clone().build();
+    assert_eq![
+        (1, 2, 3, 4),
+        (options.y, options.x, options.rows, options.cols)
+    ];
+    assert![options.is_vscroll()];
+    assert![!options.is_marginalized()];
+    // the name is only applied by `new_pile` & `new_child`
+    assert![options.name.is_null()];
+
+    let options = NcPlaneOptionsBuilder::from_options(&options)
+        .align(NcAlign::Center, NcAlign::Right)
+        .margins(1, 1)
+        .build();
+    assert![options.is_veraligned() && options.is_horaligned()];
+    assert![options.is_marginalized()];
+    assert![options.is_vscroll()];
+}
+
+#[test]
+fn options_flags() {
+    let mut options = NcPlaneOptions::new(0, 0, 2, 2);
+    assert![!options.is_autogrow() && !options.is_vscroll()];
+
+    options.set_autogrow(true);
+    options.set_vscroll(true);
+    assert![options.is_autogrow() && options.is_vscroll()];
+
+    options.set_autogrow(false);
+    assert![!options.is_autogrow() && options.is_vscroll()];
+}
+
+#[test]
+#[serial]
+fn contents() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 10);
+
+        plane.putstr_yx(Some(0), Some(0), "héllo").unwrap();
+        assert_eq![
+            "héllo",
+            plane.contents(Some(0), Some(0), Some(1), Some(5)).unwrap()
+        ];
+        assert_eq![
+            "éll",
+            plane.contents(Some(0), Some(1), Some(1), Some(3)).unwrap()
+        ];
+        assert![plane.contents(Some(0), Some(0), Some(3), None).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn copy_region() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let src = ncplane_new_test(nc, 0, 0, 2, 10);
+        let dst = ncplane_new_test(nc, 0, 0, 4, 10);
+
+        src.putstr_yx(Some(1), Some(0), "abcdef").unwrap();
+        assert_eq![
+            3,
+            dst.copy_region(2, 4, src, Some(1), Some(1), Some(1), Some(3))
+                .unwrap()
+        ];
+        assert_eq![
+            "bcd",
+            dst.contents(Some(2), Some(4), Some(1), Some(3)).unwrap()
+        ];
+        assert![dst.copy_region(3, 8, src, None, None, None, None).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn merge_down() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let src = ncplane_new_test(nc, 0, 0, 2, 4);
+        let dst = ncplane_new_test(nc, 0, 0, 2, 4);
+
+        src.putstr_yx(Some(0), Some(0), "ab").unwrap();
+        src.merge_down_simple(dst).unwrap();
+        assert_eq![
+            "ab",
+            dst.contents(Some(0), Some(0), Some(1), Some(2)).unwrap()
+        ];
+
+        src.merge_down(dst, Some(0), Some(0), Some(1), Some(2), Some(1), Some(2))
+            .unwrap();
+        assert_eq![
+            "ab",
+            dst.contents(Some(1), Some(2), Some(1), Some(2)).unwrap()
+        ];
+        assert![src
+            .merge_down(dst, None, None, Some(3), None, Some(0), Some(0))
+            .is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn rotate() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 4);
+        assert![plane.rotate_cw().is_ok()];
+        assert_eq![(2, 4), plane.dim_yx()];
+
+        let odd = ncplane_new_test(nc, 0, 0, 2, 3);
+        assert![odd.rotate_cw().is_err()];
+        assert![odd.rotate_ccw().is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn gradient() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 4, 4);
+
+        let (a, b) = (
+            NcChannels::from_rgb(0xFF0000, 0),
+            NcChannels::from_rgb(0x0000FF, 0),
+        );
+        assert_eq![
+            16,
+            plane
+                .gradient(Some(0), Some(0), None, None, None, 0, a, b, a, b)
+                .unwrap()
+        ];
+        assert_eq![
+            4,
+            plane
+                .stain(Some(0), Some(0), Some(2), Some(2), a, a, b, b)
+                .unwrap()
+        ];
+
+        // 1x1 regions need equal corners
+        assert![plane
+            .gradient(Some(0), Some(0), Some(1), Some(1), None, 0, a, b, a, b)
+            .is_err()];
+        // palette-indexed corners are not supported
+        let mut p = a;
+        p.set_fg_palindex(3);
+        assert![plane.stain(None, None, None, None, p, p, p, p).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn polyfill() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 3, 3);
+
+        plane.putstr_yx(Some(1), Some(0), "xxx").unwrap();
+        let cell = NcCell::from_char7b('o').unwrap();
+        assert_eq![3, plane.polyfill(Some(0), Some(0), &cell).unwrap()];
+        assert_eq![
+            "ooo",
+            plane.contents(Some(0), Some(0), Some(1), None).unwrap()
+        ];
+        assert_eq![
+            "xxx",
+            plane.contents(Some(1), Some(0), Some(1), None).unwrap()
+        ];
+        assert![plane.polyfill(Some(3), Some(0), &cell).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn on_resize() {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+    fn is_send<T: Send>(_: &T) {}
+    unsafe {
+        let nc = notcurses_init_test();
+        let parent = ncplane_new_test(nc, 0, 0, 10, 10);
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls2 = calls.clone();
+        let builder = NcPlaneOptions::builder().on_resize(move |plane| {
+            calls2.fetch_add(1, Ordering::SeqCst);
+            plane.resize_marginalized()
+        });
+        is_send(&builder);
+        let child = builder.new_child(parent).unwrap();
+
+        parent.resize_simple(6, 8).unwrap();
+        assert_eq![1, calls.load(Ordering::SeqCst)];
+        assert_eq![(6, 8), child.dim_yx()];
+
+        child.destroy().unwrap();
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn userdata() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 2);
+        assert_eq![None, plane.userdata::<u32>()];
+
+        plane.set_userdata(Box::new(42_u32));
+        assert_eq![None, plane.userdata::<i32>()];
+        assert_eq![Some(&42), plane.userdata::<u32>()];
+        *plane.userdata_mut::<u32>().unwrap() += 1;
+
+        assert_eq![None, plane.take_userdata::<i32>()];
+        assert_eq![Some(Box::new(43)), plane.take_userdata::<u32>()];
+        assert_eq![None, plane.userdata::<u32>()];
+
+        // a userptr set by other means is neither read nor freed
+        let mut foreign = 7_u32;
+        let foreign_ptr = &mut foreign as *mut u32 as *mut core::ffi::c_void;
+        plane.set_userdata(Box::new(1_u32));
+        crate::c_api::ncplane_set_userptr(plane, foreign_ptr);
+        assert_eq![None, plane.userdata::<u32>()];
+        assert_eq![None, plane.take_userdata::<u32>()];
+        plane.destroy().unwrap();
+        assert_eq![7, foreign];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn name() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlaneOptions::builder()
+            .rows_cols(2, 2)
+            .name("one")
+            .new_child(nc.stdplane())
+            .unwrap();
+        assert_eq![Some("one".into()), plane.name()];
+
+        plane.set_name(Some("two")).unwrap();
+        assert_eq![Some("two".into()), plane.name()];
+        assert![nc.find_plane("one").is_none()];
+        assert_eq![plane as *mut _, nc.find_plane("two").unwrap() as *mut _];
+
+        plane.set_name(None).unwrap();
+        assert_eq![None, plane.name()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn family() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let root = ncplane_new_test(nc, 0, 0, 10, 10);
+        let child = ncplane_new_test(nc, 0, 0, 5, 5);
+        let grandchild = ncplane_new_test(nc, 0, 0, 2, 2);
+        child.reparent(root).unwrap();
+        grandchild.reparent(child).unwrap();
+
+        assert![grandchild.is_descendant_of(root)];
+        assert![!root.is_descendant_of(grandchild)];
+        assert![!child.is_root()];
+
+        assert_eq![1, root.children().count()];
+        assert_eq![2, root.descendants().count()];
+        assert_eq![
+            grandchild as *mut _,
+            child.children().next().unwrap() as *mut _
+        ];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn pile() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let options = NcPlaneOptions::new(0, 0, 4, 4);
+        let mut pile = NcPile::new(nc, &options).unwrap();
+        let child = NcPlaneOptions::builder()
+            .rows_cols(2, 2)
+            .new_child(pile.plane())
+            .unwrap();
+
+        assert_eq![2, pile.planes().count()];
+        assert_eq![child as *mut _, pile.top() as *mut _];
+        assert![pile.bottom().is_root()];
+
+        pile.render().unwrap();
+        assert![!pile.render_to_buffer().unwrap().is_empty()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn z_order() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let a = ncplane_new_test(nc, 0, 0, 2, 2);
+        let b = ncplane_new_test(nc, 0, 0, 2, 2);
+        let c = ncplane_new_test(nc, 0, 0, 2, 2);
+
+        NcPlane::reorder(&[a, b, c]).unwrap();
+        assert_eq![a as *mut _, a.top() as *mut _];
+        assert_eq![b as *mut _, a.below().unwrap() as *mut _];
+        assert_eq![c as *mut _, b.below().unwrap() as *mut _];
+        assert![a.raise().is_err()];
+
+        c.raise().unwrap();
+        assert_eq![c as *mut _, a.below().unwrap() as *mut _];
+        a.lower().unwrap();
+        assert_eq![c as *mut _, c.top() as *mut _];
+
+        assert![NcPlane::reorder(&[a, b, a]).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn reparent() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let parent = ncplane_new_test(nc, 0, 0, 4, 4);
+        let child = ncplane_new_test(nc, 0, 0, 2, 2);
+        child.reparent_family(parent).unwrap();
+
+        assert![parent.reparent_family(child).is_err()];
+        assert![nc.stdplane().reparent(parent).is_err()];
+        assert![child.is_descendant_of(parent)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn scroll_region() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 4, 4);
+        assert![plane.scroll_up(1).is_err()];
+
+        let region = NcPlane::new_child_scrolling(plane, 1, 0, 2, 4).unwrap();
+        assert![region.scrolling_p() && !plane.scrolling_p()];
+        assert_eq![1, region.scroll_up(1).unwrap()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn cursor_stack() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 4, 4);
+        assert![plane.pop_cursor().is_err()];
+
+        plane.cursor_move_yx(1, 2).unwrap();
+        plane.push_cursor();
+        plane.cursor_move_yx(3, 3).unwrap();
+        plane.push_cursor();
+        plane.cursor_home();
+
+        assert_eq![(3, 3), plane.pop_cursor().unwrap()];
+        assert_eq![(1, 2), plane.pop_cursor().unwrap()];
+        assert_eq![(1, 2), plane.cursor_yx()];
+        assert![plane.pop_cursor().is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn fmt_write() {
+    use core::fmt::Write;
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 1, 10);
+
+        write![plane, "score: {}", 42].unwrap();
+        assert![plane
+            .contents(Some(0), Some(0), None, None)
+            .unwrap()
+            .starts_with("score: 42")];
+        assert![write![plane, "overflow"].is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn writer() {
+    use std::io::Write;
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 10);
+
+        let mut writer = plane.writer();
+        writer.write_all("abc\nd\u{e9}".as_bytes()).unwrap();
+        assert_eq![(1, 0), writer.plane().cursor_yx()];
+        writer.flush().unwrap();
+        assert_eq![(1, 2), writer.plane().cursor_yx()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn puttext_markup() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 1, 20);
+        let styles = plane.styles();
+
+        assert_eq![7, plane.puttext_markup("[bold red]a[/] b [[c]]").unwrap()];
+        assert_eq![3, plane.puttext_markup("[italic on #00ff00] de").unwrap()];
+        assert_eq![styles, plane.styles()];
+
+        let mut cell = NcCell::new();
+        plane.at_yx_cell(0, 0, &mut cell).unwrap();
+        assert![cell.styles().has(NcStyle::Bold)];
+        assert_eq![0xff0000, cell.fg_rgb().0];
+
+        assert![plane.puttext_markup("[/]").is_err()];
+        assert![plane.puttext_markup("[bold").is_err()];
+        assert![plane.puttext_markup("[nope]x").is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn put_ansi() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 20);
+        let channels = plane.channels();
+
+        let cols = plane
+            .put_ansi("\x1b[1;31mab\x1b[0m\x1b[2Cc\r\nd\x1b]0;title\x07e")
+            .unwrap();
+        assert_eq![5, cols];
+        assert_eq![(1, 2), plane.cursor_yx()];
+        assert_eq![channels, plane.channels()];
+
+        let mut cell = NcCell::new();
+        plane.at_yx_cell(0, 0, &mut cell).unwrap();
+        assert![cell.styles().has(NcStyle::Bold)];
+        assert_eq![1, cell.channels().fg_palindex().0];
+        plane.at_yx_cell(0, 4, &mut cell).unwrap();
+        assert_eq!["c", cell.egc(plane)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn put_ansi_huge_forward() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 20);
+
+        plane.put_ansi("a\x1b[4294967295C").unwrap();
+        assert_eq![(0, 19), plane.cursor_yx()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn putstr_styled() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 10);
+        let (styles, channels) = (plane.styles(), plane.channels());
+        let red = NcChannels::from_rgb(0xFF0000, 0x000000);
+
+        assert_eq![2, plane.putstr_styled(NcStyle::Bold, red, "ab").unwrap()];
+        assert_eq![
+            1,
+            plane
+                .putstr_yx_styled(1, 3, NcStyle::Italic, red, "c")
+                .unwrap()
+        ];
+        assert_eq![(styles, channels), (plane.styles(), plane.channels())];
+
+        let mut cell = NcCell::new();
+        plane.at_yx_cell(1, 3, &mut cell).unwrap();
+        assert![cell.styles().has(NcStyle::Italic)];
+        assert_eq![red.fg_rgb(), cell.fg_rgb()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn tab_width() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 10);
+        assert_eq![8, plane.tab_width()];
+
+        plane.set_tab_width(4);
+        assert_eq![5, plane.putstr("a\tb").unwrap()];
+        assert_eq![(0, 5), plane.cursor_yx()];
+        // doesn't go past the end of the line
+        assert_eq![5, plane.putstr("\t\t").unwrap()];
+
+        plane.set_tab_width(0);
+        assert_eq![2, plane.putstr_yx(Some(1), Some(0), "a\tb").unwrap()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn put_row() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 2, 4);
+
+        let cells = [
+            NcCell::from_char7b('a').unwrap(),
+            NcCell::from_char7b('b').unwrap(),
+        ];
+        assert_eq![2, plane.put_row(1, 1, &cells).unwrap()];
+        assert_eq![(1, 3), plane.cursor_yx()];
+        assert![plane.put_row(1, 3, &cells).is_err()];
+        assert![plane.put_row(2, 0, &cells).is_err()];
+
+        let red = NcChannels::from_rgb(0xFF0000, 0x000000);
+        assert_eq![
+            4,
+            plane.put_row_str(0, 0, "abcd", NcStyle::Bold, red).unwrap()
+        ];
+        assert![plane.put_row_str(0, 1, "abcd", NcStyle::Bold, red).is_err()];
+        assert![plane.put_row_str(0, 0, "a\nb", NcStyle::None, red).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn qrcode() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 40, 40);
+        let (version, len_y, len_x) = plane.qrcode(b"https://notcurses.com").unwrap();
+        assert![version > 0];
+        assert![len_y <= 40 && len_x <= 40];
+
+        let small = ncplane_new_test(nc, 0, 0, 4, 4);
+        assert![small.qrcode(b"data").is_err()];
+
+        notcurses_stop(nc);
+    }
+}
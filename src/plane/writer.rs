@@ -0,0 +1,115 @@
+//! `NcPlaneWriter`
+
+use crate::NcPlane;
+use std::{
+    io::{self, Error, ErrorKind},
+    str,
+    string::String,
+    vec::Vec,
+};
+
+/// A buffered [`io::Write`] adapter that writes to an [`NcPlane`],
+/// starting at its cursor.
+///
+/// Output is buffered until a newline is written or the writer is flushed.
+/// Each newline moves the cursor to the start of the next row, scrolling the
+/// plane if it has scrolling enabled.
+///
+/// Incomplete UTF-8 sequences are kept buffered until completed, and invalid
+/// ones are replaced with `U+FFFD`. Any buffered output is flushed on drop,
+/// ignoring errors. Output that fails to be put is kept buffered.
+///
+/// Created by [`NcPlane.writer`][NcPlane#method.writer].
+#[derive(Debug)]
+pub struct NcPlaneWriter<'plane> {
+    plane: &'plane mut NcPlane,
+    buf: Vec<u8>,
+}
+
+impl<'plane> NcPlaneWriter<'plane> {
+    /// New writer for `plane`.
+    pub(crate) fn new(plane: &'plane mut NcPlane) -> Self {
+        Self { plane, buf: Vec::new() }
+    }
+
+    /// Returns the plane being written to.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Puts the buffered complete lines, or all the buffered text if `all`.
+    fn put_buffered(&mut self, all: bool) -> io::Result<()> {
+        let end = if all {
+            match str::from_utf8(&self.buf) {
+                Err(e) if e.error_len().is_none() => e.valid_up_to(),
+                _ => self.buf.len(),
+            }
+        } else {
+            match self.buf.iter().rposition(|&b| b == b'\n') {
+                Some(i) => i + 1,
+                None => return Ok(()),
+            }
+        };
+        // each line is drained once it's put, so a failed one is kept
+        let mut res = Ok(());
+        let mut start = 0;
+        while start < end {
+            let line_end = self.buf[start..end]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(end, |i| start + i);
+            let line = String::from_utf8_lossy(&self.buf[start..line_end]);
+            if !line.is_empty() {
+                if let Err(e) = self.plane.putstr(&line) {
+                    res = Err(Error::new(ErrorKind::Other, e));
+                    break;
+                }
+            }
+            if line_end < end {
+                if let Err(e) = self.plane.putln() {
+                    res = Err(Error::new(ErrorKind::Other, e));
+                    // the line has been put, but not its newline
+                    start = line_end;
+                    break;
+                }
+            }
+            start = line_end + 1;
+        }
+        self.buf.drain(..start.min(end));
+        res
+    }
+}
+
+impl<'plane> io::Write for NcPlaneWriter<'plane> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let old_len = self.buf.len();
+        self.buf.extend_from_slice(buf);
+        if !buf.contains(&b'\n') {
+            return Ok(buf.len());
+        }
+        match self.put_buffered(false) {
+            Ok(()) => Ok(buf.len()),
+            Err(e) => {
+                // only the bytes of `buf` that have been put count as written
+                let drained = old_len + buf.len() - self.buf.len();
+                let put = drained.saturating_sub(old_len);
+                self.buf.truncate(self.buf.len() - (buf.len() - put));
+                if put == 0 {
+                    Err(e)
+                } else {
+                    Ok(put)
+                }
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.put_buffered(true)
+    }
+}
+
+impl<'plane> Drop for NcPlaneWriter<'plane> {
+    fn drop(&mut self) {
+        let _ = self.put_buffered(true);
+    }
+}
@@ -10,6 +10,49 @@ use crate::{c_api::NcResult_i32, NcPlane};
 /// - [`ncresizecb_to_c`][c_api::ncresizecb_to_c]
 pub type NcResizeCb = fn(&mut NcPlane) -> NcResult_i32;
 
+/// The resize closures, kept in the [`side_table`] of the planes.
+///
+/// The closure of a plane is called by [`ncresizecb_closure`] through its
+/// C resize callback.
+///
+/// [`side_table`]: crate::plane::side_table
+/// [`ncresizecb_closure`]: closure::ncresizecb_closure
+#[cfg(feature = "std")]
+pub(crate) mod closure {
+    use crate::{c_api::NcResult_i32, error::catch_panic, plane::side_table, NcPlane, NcResult};
+    use std::sync::{Arc, Mutex};
+
+    /// A resize closure, shareable between builders.
+    pub(crate) type NcResizeClosure = Arc<Mutex<dyn FnMut(&mut NcPlane) -> NcResult<()> + Send>>;
+
+    /// Sets the `closure` as the resize callback of the `plane`.
+    pub(crate) fn set(plane: &mut NcPlane, closure: NcResizeClosure) {
+        let previous = side_table::with(plane, |data| data.on_resize.replace(closure));
+        drop(previous);
+        unsafe { crate::c_api::ncplane_set_resizecb(plane, Some(ncresizecb_closure)) };
+    }
+
+    /// The C resize callback that calls the closure of the plane.
+    ///
+    /// The closure is called outside of the side table lock, so that it can
+    /// resize other planes. A closure that is already running is not called,
+    /// and a closure that panics returns an error.
+    pub(crate) unsafe extern "C" fn ncresizecb_closure(plane: *mut NcPlane) -> NcResult_i32 {
+        let closure = match side_table::with(plane, |data| data.on_resize.clone()) {
+            Some(closure) => closure,
+            None => return crate::c_api::NCRESULT_OK,
+        };
+        let res = match closure.try_lock() {
+            Ok(mut closure) => catch_panic("on_resize", || closure(&mut *plane)),
+            Err(_) => return crate::c_api::NCRESULT_ERR,
+        };
+        match res {
+            Ok(()) => crate::c_api::NCRESULT_OK,
+            Err(_) => crate::c_api::NCRESULT_ERR,
+        }
+    }
+}
+
 pub(crate) mod c_api {
     use super::*;
 
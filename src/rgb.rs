@@ -29,15 +29,23 @@ impl NcRgb {
 
 /// 32 bits broken into 3x RGB components + alpha component.
 ///
+/// The components are in RGBA order from the most significant byte, so the
+/// big-endian bytes of the value are `[R, G, B, A]`.
+///
+/// This is not the layout of an [`NcPixel`], which is ABGR from the most
+/// significant byte, and so `[R, G, B, A]` in little-endian memory.
+/// Convert between them with `From`.
+///
 /// ## Diagram
 ///
 /// ```txt
-/// AAAAAAAA RRRRRRRR GGGGGGGG BBBBBBBB
+/// RRRRRRRR GGGGGGGG BBBBBBBB AAAAAAAA
 /// ```
 /// `type in C: no data type`
 ///
 /// See also: [`NcRgb`] and [`NcChannel`] types.
 ///
+/// [`NcPixel`]: crate::NcPixel
 /// [`NcRgba`]: crate::NcRgba
 /// [`NcChannel`]: crate::NcChannel
 #[repr(transparent)]
@@ -47,12 +55,42 @@ impl NcRgba {
     /// New const RGBA color.
     pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self(
-            (a as NcRgba_u32) << 24
-                | (r as NcRgba_u32) << 16
-                | (g as NcRgba_u32) << 8
-                | b as NcRgba_u32,
+            (r as NcRgba_u32) << 24
+                | (g as NcRgba_u32) << 16
+                | (b as NcRgba_u32) << 8
+                | a as NcRgba_u32,
         )
     }
+
+    /// New const RGBA color from an RGB color and an `alpha` component.
+    pub const fn from_rgb(rgb: NcRgb, alpha: u8) -> Self {
+        Self(rgb.0 << 8 | alpha as NcRgba_u32)
+    }
+
+    /// Returns the RGB color, without the alpha component.
+    pub const fn to_rgb(&self) -> NcRgb {
+        NcRgb(self.0 >> 8)
+    }
+
+    /// Returns the red component.
+    pub const fn r(&self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// Returns the green component.
+    pub const fn g(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Returns the blue component.
+    pub const fn b(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Returns the alpha component.
+    pub const fn a(&self) -> u8 {
+        self.0 as u8
+    }
 }
 mod core_impls {
     use super::{
@@ -172,6 +210,19 @@ mod core_impls {
         }
     }
 
+    /// Opaque.
+    impl From<NcRgb> for NcRgba {
+        fn from(rgb: NcRgb) -> Self {
+            Self::from_rgb(rgb, 0xFF)
+        }
+    }
+    /// Drops the alpha component.
+    impl From<NcRgba> for NcRgb {
+        fn from(rgba: NcRgba) -> Self {
+            rgba.to_rgb()
+        }
+    }
+
     #[cfg(test)]
     mod test {
         use super::{NcRgb, NcRgba};
@@ -195,6 +246,11 @@ mod core_impls {
             assert_eq!(rgba, NcRgba::from(rgba_tup));
             assert_eq!(rgba_arr, <[u8; 4]>::from(rgba));
             assert_eq!(rgba_tup, <(u8, u8, u8, u8)>::from(rgba));
+
+            assert_eq!(rgba, NcRgba::new(0x11, 0x22, 0x33, 0xAA));
+            assert_eq!(rgba, NcRgba::from_rgb(rgb, 0xAA));
+            assert_eq!(rgb, NcRgb::from(rgba));
+            assert_eq!((0x11, 0xAA), (rgba.r(), rgba.a()));
         }
     }
 }
@@ -227,7 +283,7 @@ pub(crate) mod c_api {
     /// ## Diagram
     ///
     /// ```txt
-    /// AAAAAAAA RRRRRRRR GGGGGGGG BBBBBBBB
+    /// RRRRRRRR GGGGGGGG BBBBBBBB AAAAAAAA
     /// ```
     /// `type in C: no data type`
     ///
@@ -49,7 +49,14 @@ pub enum NcScale {
 
 mod core_impls {
     use super::{c_api, NcScale};
-    use core::fmt;
+    use crate::{
+        names::{lex, NcNames},
+        NcError,
+    };
+    use core::{fmt, str::FromStr};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
 
     impl Default for NcScale {
         fn default() -> Self {
@@ -74,6 +81,30 @@ mod core_impls {
         }
     }
 
+    /// The names an [`NcScale`] can be parsed from.
+    const NAMES: &NcNames<NcScale> = &[
+        (NcScale::None, &["none"]),
+        (NcScale::Scale, &["scale"]),
+        (NcScale::NoneHiRes, &["nonehires", "hires"]),
+        (NcScale::ScaleHiRes, &["scalehires", "scalehi"]),
+        (NcScale::Stretch, &["stretch"]),
+    ];
+
+    impl FromStr for NcScale {
+        type Err = NcError;
+
+        /// Parses the name of a scaling mode, case-insensitively.
+        ///
+        /// Accepts the names used by [`Display`][fmt::Display] and the ones
+        /// used by notcurses (e.g. `"hires"`, `"scalehi"`).
+        ///
+        /// *Equivalent C style function:
+        /// [notcurses_lex_scalemode()][crate::c_api::notcurses_lex_scalemode].*
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            lex(NAMES, s).ok_or_else(|| NcError::new_msg(&format!["NcScale::from_str({:?})", s]))
+        }
+    }
+
     impl fmt::Debug for NcScale {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             write!(f, "NcScale {{ {} }}", self)
@@ -1,13 +1,37 @@
 //! `NcStats`
 
-use crate::{c_api, Nc};
+use core::{
+    ffi::c_void,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{c_api, Nc, NcError, NcResult};
 
 /// notcurses runtime statistics
 pub type NcStats = c_api::ffi::ncstats;
 
+/// An [`NcStats`] allocated by notcurses, which is freed when dropped.
+///
+/// It dereferences to `NcStats`.
+///
+/// Created by [`Nc.stats_alloc`][Nc#method.stats_alloc].
+#[derive(Debug)]
+pub struct NcStatsAlloc {
+    stats: *mut NcStats,
+}
+
 /// # `NcStats` Methods.
 impl NcStats {
-    /// Allocates an NcStats object.
+    /// Allocates an NcStats object, which is freed when dropped.
+    ///
+    /// See [`Nc.stats_alloc`][Nc#method.stats_alloc].
+    pub fn alloc(nc: &mut Nc) -> NcResult<NcStatsAlloc> {
+        nc.stats_alloc()
+    }
+
+    #[doc(hidden)]
+    #[deprecated = "use the `alloc` method instead."]
     pub fn new(nc: &mut Nc) -> &mut Self {
         unsafe { &mut *c_api::notcurses_stats_alloc(nc) }
     }
@@ -21,4 +45,166 @@ impl NcStats {
     pub fn reset(&mut self, nc: &mut Nc) {
         unsafe { c_api::notcurses_stats_reset(nc, self) }
     }
+
+    /// Returns the average time spent rendering a frame, in nanoseconds.
+    ///
+    /// Returns `None` if there have been no renders.
+    pub fn avg_render_ns(&self) -> Option<u64> {
+        self.render_ns.checked_div(self.renders)
+    }
+
+    /// Returns the average time spent rasterizing a frame, in nanoseconds.
+    ///
+    /// Returns `None` if there have been no writeouts.
+    pub fn avg_raster_ns(&self) -> Option<u64> {
+        self.raster_ns.checked_div(self.writeouts)
+    }
+
+    /// Returns the average time spent writing out a frame, in nanoseconds.
+    ///
+    /// Returns `None` if there have been no writeouts.
+    pub fn avg_writeout_ns(&self) -> Option<u64> {
+        self.writeout_ns.checked_div(self.writeouts)
+    }
+
+    /// Returns the average number of bytes rasterized per frame.
+    ///
+    /// Returns `None` if there have been no writeouts.
+    pub fn avg_raster_bytes(&self) -> Option<u64> {
+        self.raster_bytes.checked_div(self.writeouts)
+    }
+
+    /// Returns the ratio of cells that didn't need to be emitted, because
+    /// they were unchanged, from 0 to 1.
+    ///
+    /// Returns `None` if no cells have been rasterized.
+    pub fn cell_hit_ratio(&self) -> Option<f64> {
+        ratio(self.cellelisions, self.cellemissions)
+    }
+
+    /// Returns the ratio of foreground colors that didn't need to be emitted,
+    /// from 0 to 1.
+    ///
+    /// Returns `None` if no foreground colors have been rasterized.
+    pub fn fg_hit_ratio(&self) -> Option<f64> {
+        ratio(self.fgelisions, self.fgemissions)
+    }
+
+    /// Returns the ratio of background colors that didn't need to be emitted,
+    /// from 0 to 1.
+    ///
+    /// Returns `None` if no background colors have been rasterized.
+    pub fn bg_hit_ratio(&self) -> Option<f64> {
+        ratio(self.bgelisions, self.bgemissions)
+    }
+}
+
+/// Returns `elisions / (elisions + emissions)`, if there are any.
+fn ratio(elisions: u64, emissions: u64) -> Option<f64> {
+    let total = elisions.saturating_add(emissions);
+    if total == 0 {
+        None
+    } else {
+        Some(elisions as f64 / total as f64)
+    }
+}
+
+/// # `NcStatsAlloc` Constructors.
+impl NcStatsAlloc {
+    /// Allocates an [`NcStats`] object for `nc`.
+    ///
+    /// *C style function: [notcurses_stats_alloc()][c_api::notcurses_stats_alloc].*
+    pub fn new(nc: &mut Nc) -> NcResult<Self> {
+        let stats = unsafe { c_api::notcurses_stats_alloc(nc) };
+        if stats.is_null() {
+            return Err(NcError::new_msg("NcStatsAlloc::new()"));
+        }
+        Ok(Self { stats })
+    }
+}
+
+mod core_impls {
+    use super::*;
+
+    impl Deref for NcStatsAlloc {
+        type Target = NcStats;
+        fn deref(&self) -> &NcStats {
+            unsafe { &*self.stats }
+        }
+    }
+
+    impl DerefMut for NcStatsAlloc {
+        fn deref_mut(&mut self) -> &mut NcStats {
+            unsafe { &mut *self.stats }
+        }
+    }
+
+    impl Drop for NcStatsAlloc {
+        fn drop(&mut self) {
+            unsafe { c_api::ffi::free(self.stats as *mut c_void) }
+        }
+    }
+
+    impl fmt::Display for NcStatsAlloc {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(&**self, f)
+        }
+    }
+
+    impl fmt::Display for NcStats {
+        /// Summarizes the stats in a few lines.
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let us = |ns: Option<u64>| ns.map_or(0.0, |ns| ns as f64 / 1000.0);
+            let pct = |ratio: Option<f64>| ratio.unwrap_or(0.0) * 100.0;
+            writeln!(
+                f,
+                "{} renders ({} failed), {:.2}µs avg render",
+                self.renders,
+                self.failed_renders,
+                us(self.avg_render_ns())
+            )?;
+            writeln!(
+                f,
+                "{} writeouts ({} failed), {:.2}µs avg raster, {:.2}µs avg write, {} avg bytes",
+                self.writeouts,
+                self.failed_writeouts,
+                us(self.avg_raster_ns()),
+                us(self.avg_writeout_ns()),
+                self.avg_raster_bytes().unwrap_or(0)
+            )?;
+            write!(
+                f,
+                "elisions: {:.1}% cells, {:.1}% fg, {:.1}% bg",
+                pct(self.cell_hit_ratio()),
+                pct(self.fg_hit_ratio()),
+                pct(self.bg_hit_ratio())
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NcStats;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    #[test]
+    fn stats_metrics() {
+        let mut stats = NcStats::default();
+        assert_eq![None, stats.avg_render_ns()];
+        assert_eq![None, stats.cell_hit_ratio()];
+
+        stats.renders = 4;
+        stats.render_ns = 10_000;
+        stats.cellelisions = 3;
+        stats.cellemissions = 1;
+        assert_eq![Some(2_500), stats.avg_render_ns()];
+        assert_eq![Some(0.75), stats.cell_hit_ratio()];
+
+        let display = format!["{}", stats];
+        assert![display.starts_with("4 renders (0 failed), 2.50µs avg render\n")];
+        assert![display.ends_with("elisions: 75.0% cells, 0.0% fg, 0.0% bg")];
+    }
 }
@@ -14,7 +14,7 @@ use std::ffi::CString;
 /// A wrapped [`CString`] accepted by widgets.
 ///
 // MAYBE: also for exporting to `*mut c_char`?
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct NcString {
     cstring: CString,
     // ptr: *mut c_char,
@@ -33,6 +33,12 @@ impl NcString {
         self.cstring.as_ptr()
     }
 
+    /// Returns the string slice.
+    pub fn as_str(&self) -> &str {
+        // it was created from a `&str`
+        self.cstring.to_str().unwrap_or_default()
+    }
+
     // MAYBE: fn as_raw(self) ? (transfers ownership)
 
     // /// Choose whether to dellocate the string on drop or not.
@@ -1,7 +1,7 @@
 //!
 
 #[cfg(not(feature = "std"))]
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 
 /// A bitmask of styles.
 ///
@@ -59,7 +59,7 @@ mod core_impls {
     #[cfg(not(feature = "std"))]
     use alloc::string::String;
 
-    use super::{c_api::NcStyle_u16, NcStyle};
+    use super::{c_api::NcStyle_u16, NcStyle, NcStyleIter};
 
     impl Default for NcStyle {
         fn default() -> Self {
@@ -67,22 +67,20 @@ mod core_impls {
         }
     }
 
+    /// Displays the lowercase names of the included styles joined by `|`,
+    /// e.g. `bold|italic`, or `none` if there are no styles.
     impl fmt::Display for NcStyle {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            let mut string = String::new();
-            for s in self.to_vec() {
-                string.push_str(match s {
-                    NcStyle::Italic => "Italic ",
-                    NcStyle::Underline => "Underline ",
-                    NcStyle::Undercurl => "Undercurl ",
-                    NcStyle::Struck => "Struck ",
-                    NcStyle::Bold => "Bold ",
-                    NcStyle::None => "None ",
-                    _ => "",
-                });
+            if self.is_none() {
+                return write!(f, "none");
             }
-            let _ = string.pop();
-            write!(f, "{}", string)
+            for (i, s) in self.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "|")?;
+                }
+                write!(f, "{}", s.name())?;
+            }
+            Ok(())
         }
     }
     impl fmt::Debug for NcStyle {
@@ -117,25 +115,122 @@ mod core_impls {
 
     crate::unit_impl_ops![bitwise; NcStyle, NcStyle_u16];
     crate::unit_impl_fmt![bases; NcStyle];
+
+    impl IntoIterator for NcStyle {
+        type Item = NcStyle;
+        type IntoIter = NcStyleIter;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl FromIterator<NcStyle> for NcStyle {
+        fn from_iter<I: IntoIterator<Item = NcStyle>>(iter: I) -> Self {
+            iter.into_iter().fold(NcStyle::None, |acc, s| acc | s)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::NcStyle;
+
+        #[cfg(not(feature = "std"))]
+        use alloc::string::ToString;
+
+        #[test]
+        fn style_iter_display() {
+            let style = NcStyle::Italic | NcStyle::Bold;
+            assert![style.contains(NcStyle::Bold)];
+            assert![!style.contains(NcStyle::Bold | NcStyle::Struck)];
+
+            let mut iter = style.iter();
+            assert_eq![Some(NcStyle::Bold), iter.next()];
+            assert_eq![Some(NcStyle::Italic), iter.next()];
+            assert_eq![None, iter.next()];
+            assert_eq![style, style.into_iter().collect::<NcStyle>()];
+
+            assert_eq!["bold|italic", style.to_string()];
+            assert_eq!["none", NcStyle::None.to_string()];
+        }
+    }
+}
+
+/// An iterator over the single styles included in an [`NcStyle`].
+///
+/// Created by [`NcStyle.iter()`][NcStyle#method.iter].
+#[derive(Clone, Debug)]
+pub struct NcStyleIter {
+    style: NcStyle,
+    index: usize,
+}
+
+impl Iterator for NcStyleIter {
+    type Item = NcStyle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(s) = NcStyle::ALL.get(self.index) {
+            self.index += 1;
+            if self.style.contains(*s) {
+                return Some(*s);
+            }
+        }
+        None
+    }
 }
 
 /// # Methods
 impl NcStyle {
+    /// All the single styles, in the order they are iterated.
+    pub const ALL: [NcStyle; 5] = [
+        NcStyle::Bold,
+        NcStyle::Italic,
+        NcStyle::Underline,
+        NcStyle::Undercurl,
+        NcStyle::Struck,
+    ];
+
+    /// Returns the style from its bits, discarding any bits outside
+    /// of the [`Mask`][NcStyle::Mask].
+    pub const fn from_bits_truncate(bits: c_api::NcStyle_u16) -> Self {
+        Self(bits & c_api::NCSTYLE_MASK)
+    }
+
+    /// Returns the raw bits of the style.
+    pub const fn bits(&self) -> c_api::NcStyle_u16 {
+        self.0
+    }
+
+    /// Returns true if there are no styles included.
+    pub const fn is_none(&self) -> bool {
+        self.0 == c_api::NCSTYLE_NONE
+    }
+
+    /// Returns true if the current style includes all of the `other` styles.
+    #[inline]
+    pub fn contains(&self, other: impl Into<NcStyle>) -> bool {
+        let other = other.into();
+        (self.0 & other.0) == other.0
+    }
+
+    /// Returns true if the current style has included the `other_style`.
+    ///
+    /// This is the same as [`contains`][NcStyle#method.contains].
+    #[inline]
+    pub fn has(&self, other: impl Into<NcStyle>) -> bool {
+        self.contains(other)
+    }
+
+    /// Returns an iterator over the single styles included in the current style.
+    pub fn iter(&self) -> NcStyleIter {
+        NcStyleIter { style: *self, index: 0 }
+    }
+
     /// Returns a `Vec` with all the styles contained in the current style.
+    ///
+    /// Returns a `Vec` with just [`None`][NcStyle::None] if there are no styles.
     pub fn to_vec(&self) -> Vec<NcStyle> {
-        let mut v = vec![];
-        let styles = [
-            NcStyle::Italic,
-            NcStyle::Underline,
-            NcStyle::Undercurl,
-            NcStyle::Struck,
-            NcStyle::Bold,
-        ];
-        for s in &styles {
-            if self.has(*s) {
-                v.push(*s)
-            }
-        }
+        let mut v: Vec<NcStyle> = self.iter().collect();
         // only push None if there are no other styles present.
         if v.is_empty() {
             v.push(NcStyle::None)
@@ -143,11 +238,18 @@ impl NcStyle {
         v
     }
 
-    /// Returns true if the current style has included the `other_style`.
-    #[inline]
-    pub fn has(&self, other: impl Into<NcStyle>) -> bool {
-        let other = other.into();
-        (self.0 & other.0) == other.0
+    /// Returns the lowercase name of a single style, or an empty string
+    /// for any other combination of styles.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            NcStyle::Bold => "bold",
+            NcStyle::Italic => "italic",
+            NcStyle::Underline => "underline",
+            NcStyle::Undercurl => "undercurl",
+            NcStyle::Struck => "struck",
+            NcStyle::None => "none",
+            _ => "",
+        }
     }
 
     /// Sets the `other` style in the current style.
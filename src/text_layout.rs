@@ -0,0 +1,237 @@
+//! `NcTextLayout`
+
+use core::ffi::c_char;
+
+use crate::{c_api, NcAlign, NcPlane, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Lays out a text in lines wrapped to a width in columns, with an alignment.
+///
+/// Lines are broken at newlines and between words. Words wider than a line
+/// are broken between characters, keeping zero-width characters with the
+/// previous one. The width of each character is measured with
+/// [`ncstrwidth`][c_api::ncstrwidth], so wide and zero-width characters are
+/// accounted for, as long as the locale is UTF-8.
+///
+/// # Example
+/// ```
+/// # use libnotcurses_sys::*;
+/// let layout = NcTextLayout::new("the quick brown fox", 10, NcAlign::Center);
+/// let lines: Vec<&str> = layout.lines().iter().map(|l| l.text).collect();
+/// assert_eq![vec!["the quick", "brown fox"], lines];
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NcTextLayout<'text> {
+    text: &'text str,
+    width: u32,
+    align: NcAlign,
+}
+
+/// A line of an [`NcTextLayout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NcTextLine<'text> {
+    /// The text of the line, without the surrounding whitespace.
+    pub text: &'text str,
+    /// The byte offset of the line in the whole text.
+    pub offset: usize,
+    /// The width of the line, in columns.
+    pub cols: u32,
+}
+
+/// # Constructors
+impl<'text> NcTextLayout<'text> {
+    /// New layout of `text` wrapped to `width` columns, aligned with `align`.
+    ///
+    /// A `width` of 0 is treated as 1.
+    pub fn new(text: &'text str, width: u32, align: impl Into<NcAlign>) -> Self {
+        Self { text, width: width.max(1), align: align.into() }
+    }
+}
+
+/// # Methods
+impl<'text> NcTextLayout<'text> {
+    /// Returns the text being laid out.
+    pub fn text(&self) -> &'text str {
+        self.text
+    }
+
+    /// Returns the width of the layout, in columns.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the alignment of the lines.
+    pub fn align(&self) -> NcAlign {
+        self.align
+    }
+
+    /// Computes the wrapped lines.
+    pub fn lines(&self) -> Vec<NcTextLine<'text>> {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        for paragraph in self.text.split('\n') {
+            self.wrap(paragraph, offset, &mut lines);
+            offset += paragraph.len() + 1;
+        }
+        lines
+    }
+
+    /// Renders the lines into `plane`, starting at row `y`, with the layout
+    /// `width` starting at column `x`.
+    ///
+    /// Returns the number of rows written.
+    pub fn render(&self, plane: &mut NcPlane, y: u32, x: u32) -> NcResult<u32> {
+        let lines = self.lines();
+        for (row, line) in lines.iter().enumerate() {
+            let indent = match self.align {
+                NcAlign::Center => (self.width.saturating_sub(line.cols)) / 2,
+                NcAlign::Right => self.width.saturating_sub(line.cols),
+                _ => 0,
+            };
+            if !line.text.is_empty() {
+                plane.putstr_yx(Some(y + row as u32), Some(x + indent), line.text)?;
+            }
+        }
+        Ok(lines.len() as u32)
+    }
+
+    /// Wraps a `paragraph` with no newlines, at byte `offset` of the text.
+    fn wrap(&self, paragraph: &'text str, offset: usize, lines: &mut Vec<NcTextLine<'text>>) {
+        let first_line = lines.len();
+        // byte range and width of the current line in the paragraph
+        let mut line: Option<(usize, usize, u32)> = None;
+
+        for (start, word) in words(paragraph) {
+            let end = start + word.len();
+            let word_cols = str_width(word);
+            if let Some((line_start, line_end, line_cols)) = line {
+                let cols = line_cols + str_width(&paragraph[line_end..start]) + word_cols;
+                if cols <= self.width {
+                    line = Some((line_start, end, cols));
+                    continue;
+                }
+                self.push(paragraph, line.take(), offset, lines);
+            }
+
+            // breaks the words that don't fit in a line
+            let (mut start, mut cols) = (start, word_cols);
+            while cols > self.width {
+                let (split, split_cols) = split_at_width(&paragraph[start..end], self.width);
+                self.push(
+                    paragraph,
+                    Some((start, start + split, split_cols)),
+                    offset,
+                    lines,
+                );
+                start += split;
+                cols -= split_cols;
+            }
+            if start < end {
+                line = Some((start, end, cols));
+            }
+        }
+        self.push(paragraph, line, offset, lines);
+
+        // empty paragraphs are still a line
+        if lines.len() == first_line {
+            lines.push(NcTextLine { text: "", offset, cols: 0 });
+        }
+    }
+
+    fn push(
+        &self,
+        paragraph: &'text str,
+        line: Option<(usize, usize, u32)>,
+        offset: usize,
+        lines: &mut Vec<NcTextLine<'text>>,
+    ) {
+        if let Some((start, end, cols)) = line {
+            lines.push(NcTextLine { text: &paragraph[start..end], offset: offset + start, cols });
+        }
+    }
+}
+
+/// Returns the words of `string` with their byte offsets.
+fn words(string: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in string.char_indices() {
+        if !c.is_whitespace() {
+            start.get_or_insert(i);
+        } else if let Some(start) = start.take() {
+            words.push((start, &string[start..i]));
+        }
+    }
+    if let Some(start) = start {
+        words.push((start, &string[start..]));
+    }
+    words
+}
+
+/// Returns the byte index and the width of the longest prefix of `string`
+/// that fits in `cols` columns, without separating zero-width characters
+/// from the previous one.
+///
+/// If not even the first character fits, returns it anyway.
+fn split_at_width(string: &str, cols: u32) -> (usize, u32) {
+    let mut width = 0;
+    for (i, c) in string.char_indices() {
+        let char_cols = char_width(c);
+        if i > 0 && char_cols > 0 && width + char_cols > cols {
+            return (i, width);
+        }
+        width += char_cols;
+    }
+    (string.len(), width)
+}
+
+/// Returns the width of `string` in columns.
+///
+/// It's the sum of the widths of its characters, so NULs don't end it.
+pub(crate) fn str_width(string: &str) -> u32 {
+    string.chars().map(char_width).sum()
+}
+
+/// Returns the width of `c` in columns.
+fn char_width(c: char) -> u32 {
+    // the character is followed by at least one NUL
+    let mut buf = [0_u8; 5];
+    c.encode_utf8(&mut buf);
+    let (mut validbytes, mut validwidth) = (0, 0);
+    unsafe {
+        c_api::ncstrwidth(
+            buf.as_ptr() as *const c_char,
+            &mut validbytes,
+            &mut validwidth,
+        );
+    }
+    validwidth.max(0) as u32
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NcAlign, NcTextLayout};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn text_layout_lines() {
+        let layout = NcTextLayout::new("lorem ipsum  dolor\n\nconsectetur", 8, NcAlign::Left);
+        let lines = layout.lines();
+        let texts: Vec<&str> = lines.iter().map(|l| l.text).collect();
+        assert_eq![
+            vec!["lorem", "ipsum", "dolor", "", "consecte", "tur"],
+            texts
+        ];
+        assert_eq![13, lines[2].offset];
+        assert_eq![8, lines[4].cols];
+
+        // NULs are valid text
+        let layout = NcTextLayout::new("ab\0c de", 3, NcAlign::Left);
+        let texts: Vec<&str> = layout.lines().iter().map(|l| l.text).collect();
+        assert_eq![vec!["ab\0c", "de"], texts];
+    }
+}
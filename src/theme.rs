@@ -0,0 +1,316 @@
+//! `NcTheme`
+
+use crate::{
+    widgets::{
+        NcMenuOptions, NcMultiSelectorOptions, NcReaderOptions, NcReelOptions, NcSelectorOptions,
+        NcTabbedOptions,
+    },
+    Nc, NcChannels,
+};
+
+/// A semantic color role of an [`NcTheme`].
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NcThemeRole {
+    /// The channels used to fill empty areas.
+    Background,
+    /// The channels used for regular text.
+    Foreground,
+    /// The channels used for secondary, less important text.
+    Muted,
+    /// The channels used for highlighted elements.
+    Accent,
+    /// The channels used for titles and headers.
+    Title,
+    /// The channels used for borders and separators.
+    Border,
+    /// The channels used for the selected or focused element.
+    Selection,
+    /// The channels used for errors and warnings.
+    Error,
+}
+
+impl NcThemeRole {
+    /// All the roles, in order.
+    pub const ALL: [NcThemeRole; 8] = [
+        Self::Background,
+        Self::Foreground,
+        Self::Muted,
+        Self::Accent,
+        Self::Title,
+        Self::Border,
+        Self::Selection,
+        Self::Error,
+    ];
+
+    /// Returns the lowercase name of the role.
+    pub fn name(&self) -> &'static str {
+        use NcThemeRole::*;
+        match self {
+            Background => "background",
+            Foreground => "foreground",
+            Muted => "muted",
+            Accent => "accent",
+            Title => "title",
+            Border => "border",
+            Selection => "selection",
+            Error => "error",
+        }
+    }
+
+    /// Returns the role with the provided lowercase `name`, if any.
+    pub fn from_name(name: &str) -> Option<NcThemeRole> {
+        Self::ALL.into_iter().find(|role| role.name() == name)
+    }
+}
+
+/// A mapping of semantic [`NcThemeRole`]s to [`NcChannels`].
+///
+/// # Default
+/// *[`NcTheme::dark`]*
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NcTheme {
+    channels: [NcChannels; NcThemeRole::ALL.len()],
+}
+
+/// # Constructors
+impl NcTheme {
+    /// Returns a new theme where every role uses the same `channels`.
+    pub fn new(channels: impl Into<NcChannels>) -> Self {
+        Self { channels: [channels.into(); NcThemeRole::ALL.len()] }
+    }
+
+    /// Returns the built-in dark theme.
+    pub fn dark() -> Self {
+        Self::from_rgb_pairs([
+            (0xD0D0D0, 0x1C1C1C),
+            (0xD0D0D0, 0x1C1C1C),
+            (0x808080, 0x1C1C1C),
+            (0x5FAFFF, 0x1C1C1C),
+            (0xFFFFFF, 0x303030),
+            (0x5F87AF, 0x1C1C1C),
+            (0x1C1C1C, 0x5FAFFF),
+            (0xFF5F5F, 0x1C1C1C),
+        ])
+    }
+
+    /// Returns the built-in light theme.
+    pub fn light() -> Self {
+        Self::from_rgb_pairs([
+            (0x303030, 0xF5F5F5),
+            (0x303030, 0xF5F5F5),
+            (0x8A8A8A, 0xF5F5F5),
+            (0x005FAF, 0xF5F5F5),
+            (0x000000, 0xDADADA),
+            (0x87AFD7, 0xF5F5F5),
+            (0xF5F5F5, 0x005FAF),
+            (0xD70000, 0xF5F5F5),
+        ])
+    }
+
+    /// Returns the built-in theme that matches the terminal background.
+    ///
+    /// See [`Nc.prefers_dark`][Nc#method.prefers_dark].
+    pub fn detect(nc: &Nc) -> Self {
+        if nc.prefers_dark() {
+            Self::dark()
+        } else {
+            Self::light()
+        }
+    }
+
+    fn from_rgb_pairs(pairs: [(u32, u32); NcThemeRole::ALL.len()]) -> Self {
+        let mut theme = Self::new(NcChannels::new());
+        for (role, (fg, bg)) in NcThemeRole::ALL.into_iter().zip(pairs) {
+            theme.set(role, NcChannels::from_rgb(fg, bg));
+        }
+        theme
+    }
+}
+
+/// # Methods
+impl NcTheme {
+    /// Returns the channels of the `role`.
+    pub fn get(&self, role: NcThemeRole) -> NcChannels {
+        self.channels[role as usize]
+    }
+
+    /// Sets the channels of the `role`.
+    pub fn set(&mut self, role: NcThemeRole, channels: impl Into<NcChannels>) -> &mut Self {
+        self.channels[role as usize] = channels.into();
+        self
+    }
+
+    /// Applies this theme to the channels of the `options`.
+    pub fn apply_to_selector(&self, options: &mut NcSelectorOptions) {
+        use NcThemeRole::*;
+        options.opchannels = self.get(Accent).into();
+        options.descchannels = self.get(Foreground).into();
+        options.titlechannels = self.get(Title).into();
+        options.footchannels = self.get(Muted).into();
+        options.boxchannels = self.get(Border).into();
+    }
+
+    /// Applies this theme to the channels of the `options`.
+    pub fn apply_to_multiselector(&self, options: &mut NcMultiSelectorOptions) {
+        use NcThemeRole::*;
+        options.opchannels = self.get(Accent).into();
+        options.descchannels = self.get(Foreground).into();
+        options.titlechannels = self.get(Title).into();
+        options.footchannels = self.get(Muted).into();
+        options.boxchannels = self.get(Border).into();
+    }
+
+    /// Applies this theme to the channels of the `options`.
+    pub fn apply_to_reader(&self, options: &mut NcReaderOptions) {
+        options.tchannels = self.get(NcThemeRole::Foreground).into();
+    }
+
+    /// Applies this theme to the channels of the `options`.
+    pub fn apply_to_menu(&self, options: &mut NcMenuOptions) {
+        options.headerchannels = self.get(NcThemeRole::Title).into();
+        options.sectionchannels = self.get(NcThemeRole::Foreground).into();
+    }
+
+    /// Applies this theme to the channels of the `options`.
+    pub fn apply_to_tabbed(&self, options: &mut NcTabbedOptions) {
+        options.selchan = self.get(NcThemeRole::Selection).into();
+        options.hdrchan = self.get(NcThemeRole::Title).into();
+        options.sepchan = self.get(NcThemeRole::Border).into();
+    }
+
+    /// Applies this theme to the channels of the `options`.
+    pub fn apply_to_reel(&self, options: &mut NcReelOptions) {
+        options.borderchan = self.get(NcThemeRole::Border).into();
+        options.tabletchan = self.get(NcThemeRole::Border).into();
+        options.focusedchan = self.get(NcThemeRole::Selection).into();
+    }
+}
+
+/// # TOML
+#[cfg(feature = "toml")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "toml")))]
+impl NcTheme {
+    /// Returns a new theme parsed from a TOML string.
+    ///
+    /// The optional top-level `base` key selects the theme (`"dark"` or
+    /// `"light"`) that provides the roles not defined. Each role is a table
+    /// named after [`NcThemeRole::name`], with optional `fg` and `bg` colors
+    /// written as `"#RRGGBB"`.
+    ///
+    /// # Example
+    /// ```toml
+    /// base = "light"
+    ///
+    /// [accent]
+    /// fg = "#AF00AF"
+    ///
+    /// [error]
+    /// fg = "#FFFFFF"
+    /// bg = "#D70000"
+    /// ```
+    pub fn from_toml_str(toml: &str) -> crate::NcResult<NcTheme> {
+        use crate::NcError;
+
+        let err = |msg: &str| NcError::new_msg(&format!["NcTheme.from_toml_str(): {msg}"]);
+
+        let table = match toml.parse::<toml::Value>() {
+            Ok(toml::Value::Table(table)) => table,
+            Ok(_) => return Err(err("not a table")),
+            Err(e) => return Err(err(&e.to_string())),
+        };
+
+        let mut theme = match table.get("base").map(|b| b.as_str()) {
+            None | Some(Some("dark")) => Self::dark(),
+            Some(Some("light")) => Self::light(),
+            Some(_) => return Err(err("invalid base")),
+        };
+
+        for (key, value) in table.iter().filter(|(k, _)| *k != "base") {
+            let role =
+                NcThemeRole::from_name(key).ok_or_else(|| err(&format!["unknown role {key:?}"]))?;
+            let colors = value
+                .as_table()
+                .ok_or_else(|| err(&format!["role {key:?} is not a table"]))?;
+
+            let mut channels = theme.get(role);
+            for (name, color) in colors {
+                let rgb = color
+                    .as_str()
+                    .and_then(|c| c.strip_prefix('#'))
+                    .filter(|c| c.len() == 6)
+                    .and_then(|c| u32::from_str_radix(c, 16).ok())
+                    .ok_or_else(|| err(&format!["invalid color {key}.{name}"]))?;
+                match name.as_str() {
+                    "fg" => channels.set_fg_rgb(rgb),
+                    "bg" => channels.set_bg_rgb(rgb),
+                    _ => return Err(err(&format!["unknown key {key}.{name}"])),
+                };
+            }
+            theme.set(role, channels);
+        }
+        Ok(theme)
+    }
+}
+
+mod core_impls {
+    use super::{NcTheme, NcThemeRole};
+    use crate::NcChannels;
+    use core::ops::{Index, IndexMut};
+
+    impl Default for NcTheme {
+        fn default() -> Self {
+            Self::dark()
+        }
+    }
+
+    impl Index<NcThemeRole> for NcTheme {
+        type Output = NcChannels;
+        fn index(&self, role: NcThemeRole) -> &Self::Output {
+            &self.channels[role as usize]
+        }
+    }
+    impl IndexMut<NcThemeRole> for NcTheme {
+        fn index_mut(&mut self, role: NcThemeRole) -> &mut Self::Output {
+            &mut self.channels[role as usize]
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::{NcChannels, NcRgb, NcTheme, NcThemeRole};
+
+        #[test]
+        fn theme_roles() {
+            let mut theme = NcTheme::default();
+            assert_eq![NcTheme::dark(), theme];
+            assert_ne![NcTheme::light(), theme];
+
+            theme.set(NcThemeRole::Error, NcChannels::from_rgb(0xFF0000, 0x000000));
+            assert_eq![NcRgb(0xFF0000), theme[NcThemeRole::Error].fg_rgb()];
+
+            for role in NcThemeRole::ALL {
+                assert_eq![Some(role), NcThemeRole::from_name(role.name())];
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "toml")]
+        fn theme_toml() {
+            let toml = "base = \"light\"\n[accent]\nfg = \"#AF00AF\"\n[error]\nbg = \"#123456\"\n";
+            let theme = NcTheme::from_toml_str(toml).unwrap();
+            let light = NcTheme::light();
+            assert_eq![light[NcThemeRole::Title], theme[NcThemeRole::Title]];
+            assert_eq![NcRgb(0xAF00AF), theme[NcThemeRole::Accent].fg_rgb()];
+            assert_eq![
+                light[NcThemeRole::Accent].bg_rgb(),
+                theme[NcThemeRole::Accent].bg_rgb()
+            ];
+            assert_eq![NcRgb(0x123456), theme[NcThemeRole::Error].bg_rgb()];
+
+            assert![NcTheme::from_toml_str("[unknown]\nfg = \"#000000\"").is_err()];
+            assert![NcTheme::from_toml_str("[accent]\nfg = \"red\"").is_err()];
+            assert![NcTheme::from_toml_str("base = \"blue\"").is_err()];
+        }
+    }
+}
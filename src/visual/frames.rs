@@ -0,0 +1,73 @@
+//! `NcVisualFrames`
+
+use crate::{NcResult, NcVisual};
+
+/// A lending iterator over the frames of an [`NcVisual`].
+///
+/// Each call to [`next_frame`] decodes the next frame and lends the visual,
+/// ready to be blitted. The first call lends the frame that is already loaded.
+///
+/// It's not an [`Iterator`], since each frame borrows the visual mutably,
+/// only until the next one is decoded.
+///
+/// Created by [`NcVisual.frames`] and [`NcVisual.frames_loop`].
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let visual = NcVisual::from_file("video.mp4")?;
+/// let mut frames = visual.frames();
+/// while let Some(frame) = frames.next_frame() {
+///     unsafe { frame?.blit(nc, None)? };
+///     nc.render()?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`next_frame`]: NcVisualFrames#method.next_frame
+/// [`NcVisual.frames`]: NcVisual#method.frames
+/// [`NcVisual.frames_loop`]: NcVisual#method.frames_loop
+#[derive(Debug)]
+pub struct NcVisualFrames<'visual> {
+    visual: &'visual mut NcVisual,
+    looping: bool,
+    started: bool,
+    done: bool,
+}
+
+impl<'visual> NcVisualFrames<'visual> {
+    /// New iterator over the frames of `visual`, rewinding at the end if
+    /// `looping`.
+    pub(crate) fn new(visual: &'visual mut NcVisual, looping: bool) -> Self {
+        Self { visual, looping, started: false, done: false }
+    }
+
+    /// Decodes the next frame, and lends the visual until the following one.
+    ///
+    /// Returns `None` at the end of the visual, unless looping, and after
+    /// the first error.
+    pub fn next_frame(&mut self) -> Option<NcResult<&mut NcVisual>> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(Ok(self.visual));
+        }
+        let res = if self.looping { self.visual.decode_loop() } else { self.visual.decode() };
+        match res {
+            Ok(1) if !self.looping => {
+                self.done = true;
+                None
+            }
+            Ok(_) => Some(Ok(self.visual)),
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
@@ -3,20 +3,24 @@
 use core::ptr::{null, null_mut};
 
 #[cfg(not(feature = "std"))]
-use alloc::format;
+use alloc::{format, vec::Vec};
 
 use core::ffi::c_void;
 
 use crate::{
     c_api::{self, NcResult_i32, NCRESULT_ERR},
-    cstring, error, error_ref_mut, Nc, NcBlitter, NcDirect, NcError, NcPalette, NcPixel, NcPlane,
-    NcResult, NcRgba, NcScale, NcTime, NcVisual, NcVisualGeometry, NcVisualOptions,
+    cstring, error, error_ref_mut, Nc, NcBlitter, NcDirect, NcError, NcPalette, NcPixel,
+    NcPixelBuffer, NcPlane, NcResult, NcRgba, NcScale, NcTime, NcVisual, NcVisualFrames,
+    NcVisualGeometry, NcVisualOptions,
 };
 
 /// # NcVisual Constructors & destructors
 impl NcVisual {
     /// Like [from_rgba][NcVisual#method.from_rgba], but 'bgra' is arranged as BGRA.
     ///
+    /// Returns an error if `bgra` is smaller than `rows` * `rowstride` bytes,
+    /// or if `rowstride` is smaller than `cols` * 4.
+    ///
     /// *C style function: [ncvisual_from_bgra()][c_api::ncvisual_from_bgra].*
     pub fn from_bgra<'a>(
         bgra: &[u8],
@@ -24,6 +28,7 @@ impl NcVisual {
         rowstride: u32,
         cols: u32,
     ) -> NcResult<&'a mut NcVisual> {
+        check_buffer("from_bgra", bgra.len(), rows, rowstride, cols, 4)?;
         error_ref_mut![
             unsafe {
                 c_api::ncvisual_from_bgra(
@@ -97,13 +102,14 @@ impl NcVisual {
         ]
     }
 
-    /// Constructs an `NcVisual` from a nul-terminated Sixel control `sequence`.
+    /// Constructs an `NcVisual` from a Sixel control `sequence`, of
+    /// `len_y` * `len_x` pixels.
     ///
     /// *C style function: [ncvisual_from_sixel()][c_api::ncvisual_from_sixel].*
     pub fn from_sixel<'a>(sequence: &str, len_y: u32, len_x: u32) -> NcResult<&'a mut NcVisual> {
         let cs = cstring![sequence];
         error_ref_mut![
-            unsafe { c_api::ncvisual_from_file(cs.as_ptr()) },
+            unsafe { c_api::ncvisual_from_sixel(cs.as_ptr(), len_y, len_x) },
             &format!("NcVisual::from_sixel({}, {}, {})", sequence, len_y, len_x)
         ]
     }
@@ -113,6 +119,9 @@ impl NcVisual {
     ///
     /// `rowstride` must be a multiple of 4.
     ///
+    /// Returns an error if `rgb` is smaller than `rows` * `rowstride` bytes,
+    /// or if `rowstride` is smaller than `cols` * 4.
+    ///
     /// *C style function: [ncvisual_from_rgb_loose()][c_api::ncvisual_from_rgb_loose].*
     pub fn from_rgb_loose<'a>(
         rgb: &[u8],
@@ -121,6 +130,13 @@ impl NcVisual {
         cols: u32,
         alpha: u8,
     ) -> NcResult<&'a mut NcVisual> {
+        check_buffer("from_rgb_loose", rgb.len(), rows, rowstride, cols, 4)?;
+        if rowstride % 4 != 0 {
+            return Err(NcError::new_msg(&format![
+                "NcVisual::from_rgb_loose(): rowstride {} is not a multiple of 4",
+                rowstride
+            ]));
+        }
         error_ref_mut![
             unsafe {
                 c_api::ncvisual_from_rgb_loose(
@@ -141,6 +157,9 @@ impl NcVisual {
     /// Like [`from_rgba`][NcVisual#method.from_rgba], but the pixels are
     /// 3-byte RGB. Alpha is filled in throughout using 'alpha'.
     ///
+    /// Returns an error if `rgb` is smaller than `rows` * `rowstride` bytes,
+    /// or if `rowstride` is smaller than `cols` * 3.
+    ///
     /// *C style function: [ncvisual_from_rgb_packed()][c_api::ncvisual_from_rgb_packed].*
     pub fn from_rgb_packed<'a>(
         rgb: &[u8],
@@ -149,6 +168,7 @@ impl NcVisual {
         cols: u32,
         alpha: u8,
     ) -> NcResult<&'a mut NcVisual> {
+        check_buffer("from_rgb_packed", rgb.len(), rows, rowstride, cols, 3)?;
         error_ref_mut![
             unsafe {
                 c_api::ncvisual_from_rgb_packed(
@@ -176,6 +196,9 @@ impl NcVisual {
     /// The total size of `rgba` is thus (rows * rowstride) bytes, of which
     /// (rows * cols * 4) bytes are actual non-padding data.
     ///
+    /// Returns an error if `rgba` is smaller than that, or if `rowstride` is
+    /// smaller than `cols` * 4.
+    ///
     /// *C style function: [ncvisual_from_rgba()][c_api::ncvisual_from_rgba].*
     pub fn from_rgba<'a>(
         rgba: &[u8],
@@ -183,6 +206,7 @@ impl NcVisual {
         rowstride: u32,
         cols: u32,
     ) -> NcResult<&'a mut NcVisual> {
+        check_buffer("from_rgba", rgba.len(), rows, rowstride, cols, 4)?;
         error_ref_mut![
             unsafe {
                 c_api::ncvisual_from_rgba(
@@ -205,6 +229,9 @@ impl NcVisual {
     ///
     /// `palette` is an array of at least `palsize` [`NcChannel`][crate::NcChannel]s.
     ///
+    /// Returns an error if `data` is smaller than `rows` * `rowstride` bytes,
+    /// or if `rowstride` is smaller than `cols` * `pstride`.
+    ///
     /// *C style function: [ncvisual_from_palidx()][c_api::ncvisual_from_palidx].*
     pub fn from_palidx<'a>(
         data: &[u8],
@@ -216,6 +243,7 @@ impl NcVisual {
         pstride: u32,
         palette: &NcPalette,
     ) -> NcResult<&'a mut NcVisual> {
+        check_buffer("from_palidx", data.len(), rows, rowstride, cols, pstride)?;
         error_ref_mut![
             unsafe {
                 c_api::ncvisual_from_palidx(
@@ -239,6 +267,51 @@ impl NcVisual {
         ]
     }
 
+    /// Like [`from_rgba`][NcVisual#method.from_rgba], but from an image of the
+    /// [`image`] crate, which is converted to RGBA first if needed.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "image")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "image")))]
+    pub fn from_image<'a>(image: &image::DynamicImage) -> NcResult<&'a mut NcVisual> {
+        match image {
+            image::DynamicImage::ImageRgba8(rgba) => Self::from_rgba_image(rgba),
+            image => Self::from_rgba_image(&image.to_rgba8()),
+        }
+    }
+
+    /// Like [`from_rgba`][NcVisual#method.from_rgba], but from an RGBA image
+    /// of the [`image`] crate.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "image")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "image")))]
+    pub fn from_rgba_image<'a>(image: &image::RgbaImage) -> NcResult<&'a mut NcVisual> {
+        let (cols, rows) = image.dimensions();
+        if rows == 0 || cols == 0 {
+            return Err(NcError::new_msg(&format![
+                "NcVisual::from_rgba_image(): empty {}x{} image",
+                rows, cols
+            ]));
+        }
+        Self::from_rgba(image.as_raw(), rows, cols * 4, cols)
+    }
+
+    /// Like [`from_rgba`][NcVisual#method.from_rgba], but from an
+    /// [`NcPixelBuffer`].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_buffer<'a>(buffer: &NcPixelBuffer) -> NcResult<&'a mut NcVisual> {
+        let (rows, cols) = buffer.pixel_yx();
+        if buffer.is_empty() {
+            return Err(NcError::new_msg(&format![
+                "NcVisual::from_buffer(): empty {}x{} buffer",
+                rows, cols
+            ]));
+        }
+        Self::from_rgba(&buffer.to_rgba_bytes(), rows, cols * 4, cols)
+    }
+
     /// Destroys this NcVisual.
     ///
     /// Rendered elements will not be disrupted, but the visual can be neither
@@ -284,6 +357,25 @@ impl NcVisual {
         }
     }
 
+    /// Returns a lending iterator over the frames of the NcVisual, that decodes
+    /// the next frame on each step, ending at EOF.
+    ///
+    /// The first step yields the frame that is currently loaded.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn frames(&mut self) -> NcVisualFrames<'_> {
+        NcVisualFrames::new(self, false)
+    }
+
+    /// Returns an endless lending iterator over the frames of the NcVisual, ala
+    /// [frames][NcVisual#method.frames], but rewinding to the first frame
+    /// when the end has been reached.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn frames_loop(&mut self) -> NcVisualFrames<'_> {
+        NcVisualFrames::new(self, true)
+    }
+
     /// Returns [`NcVisualGeometry`].
     ///
     /// if [`Nc`] is not provided, only [`pix_yx`] will be filled in, with the
@@ -420,8 +512,9 @@ impl NcVisual {
     ///
     /// *C style function: [ncvisual_polyfill_yx()][c_api::ncvisual_polyfill_yx].*
     pub fn polyfill_yx(&mut self, y: u32, x: u32, rgba: impl Into<NcRgba>) -> NcResult<()> {
+        let pixel = NcPixel::from(rgba.into());
         error![
-            unsafe { c_api::ncvisual_polyfill_yx(self, y, x, rgba.into().into()) },
+            unsafe { c_api::ncvisual_polyfill_yx(self, y, x, pixel.into()) },
             &format!["NcVisual.polyfill_yx({}, {}, rgba)", y, x]
         ]
     }
@@ -460,39 +553,96 @@ impl NcVisual {
         error_ref_mut![c_api::ncvisual_blit(nc, self, options_ptr), "NcVisual.blit"]
     }
 
-    /// Resizes the visual to `cols` X `rows` pixels.
+    /// Blits the visual with the [`Pixel`][NcBlitter::Pixel] blitter into a
+    /// new child of `plane`, at `px_off_y`, `px_off_x` pixels from the cell
+    /// `cell_y`, `cell_x` of `plane`.
+    ///
+    /// The pixel offsets can exceed the size of a cell, in which case they're
+    /// carried over to the cell coordinates, using the cell-pixel geometry of
+    /// `plane`. This allows moving a bitmap smoothly by pixels.
+    ///
+    /// Returns the new plane.
+    ///
+    /// # Safety
+    /// You must be careful not to end up with multiple exclusive references
+    /// to the returned `NcPlane`, or with one exclusive reference
+    /// and one or more shared references.
+    ///
+    /// *(No equivalent C style function)*
+    pub unsafe fn blit_at_pixels(
+        &mut self,
+        nc: &mut Nc,
+        plane: &mut NcPlane,
+        cell_y: u32,
+        cell_x: u32,
+        px_off_y: u32,
+        px_off_x: u32,
+    ) -> NcResult<&mut NcPlane> {
+        let geom = plane.pixel_geom();
+        let (origin_y, origin_x) = geom.pixel_origin_of_cell(cell_y, cell_x);
+        let ((y, x), (off_y, off_x)) = geom
+            .cell_of_pixel(origin_y + px_off_y, origin_x + px_off_x)
+            .ok_or_else(|| {
+                NcError::new_msg("NcVisual.blit_at_pixels(): unknown cell-pixel geometry")
+            })?;
+        let options = NcVisualOptions::builder()
+            .parent(plane)
+            .yx(y as i32, x as i32)
+            .pixel()
+            .cell_offset(off_y, off_x)
+            .build();
+        self.blit(nc, Some(&options))
+    }
+
+    /// Resizes the visual to `rows` X `cols` pixels.
     ///
     /// This is a lossy transformation, unless the size is unchanged.
     ///
+    /// Returns an error if `rows` or `cols` is 0 or greater than `i32::MAX`.
+    /// The new size is reflected by [`geom`][NcVisual#method.geom].
+    ///
     /// *C style function: [ncvisual_resize()][c_api::ncvisual_resize].*
     pub fn resize(&mut self, rows: u32, cols: u32) -> NcResult<()> {
+        check_dims("resize", rows, cols)?;
         error![
             unsafe { c_api::ncvisual_resize(self, rows as i32, cols as i32) },
             &format!["NcVisual.resize({}, {})", rows, cols]
         ]
     }
 
-    /// Resizes the visual to  in the image to `rows` X `cols` pixels, without
-    /// interpolating the color values.
+    /// Resizes the visual to `rows` X `cols` pixels, without interpolating
+    /// the color values.
     ///
     /// The original color is retained.
     ///
+    /// Returns an error if `rows` or `cols` is 0 or greater than `i32::MAX`.
+    /// The new size is reflected by [`geom`][NcVisual#method.geom].
+    ///
     /// *C style function:
     /// [ncvisual_resize_noninterpolative()][c_api::ncvisual_resize_noninterpolative].*
     pub fn resize_noninterpolative(&mut self, rows: u32, cols: u32) -> NcResult<()> {
+        check_dims("resize_noninterpolative", rows, cols)?;
         error![
             unsafe { c_api::ncvisual_resize_noninterpolative(self, rows as i32, cols as i32) },
-            &format!["NcVisual.resize_noninterpolative({}, {})", cols, rows]
+            &format!["NcVisual.resize_noninterpolative({}, {})", rows, cols]
         ]
     }
 
     /// Rotates the visual `rads` radians.
     ///
-    /// Only M_PI/2 and -M_PI/2 are supported at the moment,
-    /// but this will change. (FIXME)
+    /// The visual is resized to fit the rotated image, which is reflected by
+    /// [`geom`][NcVisual#method.geom].
+    ///
+    /// Returns an error if `rads` is not a finite number.
     ///
     /// *C style function: [ncvisual_rotate()][c_api::ncvisual_rotate].*
     pub fn rotate(&mut self, rads: f64) -> NcResult<()> {
+        if !rads.is_finite() {
+            return Err(NcError::new_msg(&format![
+                "NcVisual.rotate({}): not finite",
+                rads
+            ]));
+        }
         error![
             unsafe { c_api::ncvisual_rotate(self, rads) },
             &format!["NcVisual.rotate({})", rads]
@@ -519,6 +669,116 @@ impl NcVisual {
         ]
     }
 
+    /// Gets the pixel at `y`, `x`, checking it's inside the visual.
+    ///
+    /// Unlike [`at_yx`][NcVisual#method.at_yx], returns an error describing
+    /// the visual's geometry if the coordinates are out of bounds.
+    ///
+    /// *C style function: [ncvisual_at_yx()][c_api::ncvisual_at_yx].*
+    pub fn pixel(&self, y: u32, x: u32) -> NcResult<NcPixel> {
+        self.check_pixel_yx(y, x, "pixel")?;
+        self.at_yx(y, x)
+    }
+
+    /// Sets the pixel at `y`, `x`, checking it's inside the visual.
+    ///
+    /// Unlike [`set_yx`][NcVisual#method.set_yx], returns an error describing
+    /// the visual's geometry if the coordinates are out of bounds.
+    ///
+    /// *C style function: [ncvisual_set_yx()][c_api::ncvisual_set_yx].*
+    pub fn set_pixel(&mut self, y: u32, x: u32, pixel: impl Into<NcPixel>) -> NcResult<()> {
+        self.check_pixel_yx(y, x, "set_pixel")?;
+        self.set_yx(y, x, pixel)
+    }
+
+    /// Returns a copy of all the pixels of this NcVisual, row by row.
+    ///
+    /// notcurses neither exposes the backing store of an `NcVisual` nor
+    /// copies it in bulk, so this takes one call per pixel. For repeated bulk
+    /// edits, copy the pixels once with [`to_buffer`], edit the
+    /// [`NcPixelBuffer`], and create a new visual from it with
+    /// [`from_buffer`], which copies it in bulk.
+    ///
+    /// [`to_buffer`]: NcVisual#method.to_buffer
+    /// [`from_buffer`]: NcVisual#method.from_buffer
+    ///
+    /// *(No equivalent C style function)*
+    pub fn pixels(&self) -> NcResult<Vec<NcPixel>> {
+        let (rows, cols) = self.geom(None, None)?.pix_yx.unwrap_or((0, 0));
+        let mut pixels = Vec::with_capacity(rows as usize * cols as usize);
+        for y in 0..rows {
+            for x in 0..cols {
+                pixels.push(self.at_yx(y, x)?);
+            }
+        }
+        Ok(pixels)
+    }
+
+    /// Sets all the pixels of this NcVisual, row by row.
+    ///
+    /// Returns an error if `pixels` doesn't have exactly as many pixels
+    /// as the visual.
+    ///
+    /// Like [`pixels`][NcVisual#method.pixels], this takes one call per pixel.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_pixels(&mut self, pixels: &[NcPixel]) -> NcResult<()> {
+        let (rows, cols) = self.geom(None, None)?.pix_yx.unwrap_or((0, 0));
+        if pixels.len() != rows as usize * cols as usize {
+            return Err(NcError::new_msg(&format![
+                "NcVisual.set_pixels(): got {} pixels for {}x{}",
+                pixels.len(),
+                rows,
+                cols
+            ]));
+        }
+        for (i, pixel) in pixels.iter().enumerate() {
+            let (y, x) = (i as u32 / cols, i as u32 % cols);
+            self.set_yx(y, x, *pixel)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of all the pixels of this NcVisual in an [`NcPixelBuffer`],
+    /// whose pixels can then be edited in place.
+    ///
+    /// Like [`pixels`][NcVisual#method.pixels], this takes one call per pixel.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_buffer(&self) -> NcResult<NcPixelBuffer> {
+        let (rows, cols) = self.geom(None, None)?.pix_yx.unwrap_or((0, 0));
+        NcPixelBuffer::from_pixels(rows, cols, self.pixels()?)
+    }
+
+    /// Saves this NcVisual as a PNG image at `path`.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "image")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "image")))]
+    pub fn save_png(&self, path: impl AsRef<std::path::Path>) -> NcResult<()> {
+        let (rows, cols) = self.geom(None, None)?.pix_yx.unwrap_or((0, 0));
+        let rgba: Vec<u8> = self
+            .pixels()?
+            .iter()
+            .flat_map(|p| p.0.to_le_bytes())
+            .collect();
+        save_rgba_png(rgba, rows, cols, path.as_ref()).map_err(|e| {
+            NcError::new_msg(&format!["NcVisual.save_png({:?}): {}", path.as_ref(), e])
+        })
+    }
+
+    /// Returns an error if `y`, `x` is outside of the visual's pixel geometry.
+    fn check_pixel_yx(&self, y: u32, x: u32, method: &str) -> NcResult<()> {
+        let (rows, cols) = self.geom(None, None)?.pix_yx.unwrap_or((0, 0));
+        if y >= rows || x >= cols {
+            return Err(NcError::new_msg(&format![
+                "NcVisual.{}({}, {}): out of bounds of {}x{} pixels",
+                method, y, x, rows, cols
+            ]));
+        }
+        Ok(())
+    }
+
     /// Displays frames.
     ///
     /// *Provide as an argument to ncvisual_stream().*
@@ -586,14 +846,20 @@ impl NcVisual {
     //     todo![]
     // }
 
-    /// If a subtitle ought be displayed at this time, return a new plane
+    /// If a subtitle ought be displayed at this time, returns a new plane.
     ///
     /// The returned plane is bound to `parent` and contains the subtitle,
     /// which might be text or graphics (depending on the input format).
     ///
+    /// Returns an error if there's no subtitle to display for the current
+    /// frame, or if it couldn't be rendered.
+    ///
     /// *C style function: [ncvisual_subtitle_plane()][c_api::ncvisual_subtitle_plane].*
-    pub fn subtitle_plane(&self, parent: &mut NcPlane) -> NcResult<&mut NcPlane> {
-        error_ref_mut![unsafe { c_api::ncvisual_subtitle_plane(parent, self) }]
+    pub fn subtitle_plane<'a>(&self, parent: &'a mut NcPlane) -> NcResult<&'a mut NcPlane> {
+        error_ref_mut![
+            unsafe { c_api::ncvisual_subtitle_plane(parent, self) },
+            "NcVisual.subtitle_plane(): no subtitle"
+        ]
     }
 }
 
@@ -605,6 +871,8 @@ impl NcVisual {
     ///
     /// You must destroy the result with [ncdirectf_free()][2];
     ///
+    /// See also [`NcDirectVisual`][crate::NcDirectVisual], which frees it when dropped.
+    ///
     /// [0]: NcVisual#method.ncdirectf_geom
     /// [1]: NcVisual#method.ncdirectf_render
     /// [2]: NcVisual#method.ncdirectf_free
@@ -662,3 +930,57 @@ impl NcVisual {
         error![res, "NcVisual.ncdirectf_geom()", geom.into()];
     }
 }
+
+/// Returns an error if `rows` or `cols` can't be the size of a visual.
+fn check_dims(method: &str, rows: u32, cols: u32) -> NcResult<()> {
+    if rows == 0 || cols == 0 || rows > i32::MAX as u32 || cols > i32::MAX as u32 {
+        return Err(NcError::new_msg(&format![
+            "NcVisual.{}({}, {}): invalid size",
+            method, rows, cols
+        ]));
+    }
+    Ok(())
+}
+
+/// Returns an error if a buffer of `len` bytes can't hold `rows` lines of
+/// `rowstride` bytes, each with `cols` pixels of `pixel_bytes`.
+fn check_buffer(
+    method: &str,
+    len: usize,
+    rows: u32,
+    rowstride: u32,
+    cols: u32,
+    pixel_bytes: u32,
+) -> NcResult<()> {
+    let min_stride = cols as u64 * pixel_bytes as u64;
+    if (rowstride as u64) < min_stride {
+        return Err(NcError::new_msg(&format![
+            "NcVisual::{}(): rowstride {} is less than {} cols * {} bytes",
+            method, rowstride, cols, pixel_bytes
+        ]));
+    }
+    let size = rows as u64 * rowstride as u64;
+    if (len as u64) < size {
+        return Err(NcError::new_msg(&format![
+            "NcVisual::{}(): buffer of {} bytes is less than {} rows * {} rowstride",
+            method, len, rows, rowstride
+        ]));
+    }
+    Ok(())
+}
+
+/// Saves `rows` * `cols` RGBA pixels as a PNG image at `path`.
+#[cfg(feature = "image")]
+pub(crate) fn save_rgba_png(
+    rgba: Vec<u8>,
+    rows: u32,
+    cols: u32,
+    path: &std::path::Path,
+) -> Result<(), String> {
+    let len = rgba.len();
+    let image = image::RgbaImage::from_raw(cols, rows, rgba)
+        .ok_or_else(|| format!["{} bytes are not {}x{} pixels", len, rows, cols])?;
+    image
+        .save_with_format(path, image::ImageFormat::Png)
+        .map_err(|e| e.to_string())
+}
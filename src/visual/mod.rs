@@ -1,12 +1,12 @@
 // functions already exported by bindgen : 24
 // -----------------------------------------
-// (W) wrap: 20
+// (W) wrap: 23
 // (#) test: 0
 // -----------------------------------------
 //W  ncdirectf_free
 //W  ncdirectf_from_file
-//   ncdirectf_geom
-//   ncdirectf_render
+//W  ncdirectf_geom
+//W  ncdirectf_render
 //W  ncvisual_at_yx
 //W  ncvisual_blit
 //W  ncvisual_decode
@@ -26,7 +26,7 @@
 //W  ncvisual_rotate
 //W  ncvisual_set_yx
 //W  ncvisual_simple_streamer
-//~  ncvisual_stream
+//W  ncvisual_stream
 //W  ncvisual_subtitle_plane
 
 // functions manually reimplemented: 45
@@ -40,13 +40,20 @@
 #[allow(unused_imports)] // for doc comments
 use crate::{c_api::NcResult_i32, NcBlitter, NcChannel, NcPlane, NcScale, NcTime};
 
+mod frames;
 mod geometry;
 mod methods;
 pub(crate) mod options;
+mod player;
 mod reimplemented;
 
+pub use frames::NcVisualFrames;
 pub use geometry::NcVisualGeometry;
 pub use options::{NcVisualFlag, NcVisualOptions, NcVisualOptionsBuilder};
+pub use player::{NcPlayer, NcPlayerControl, NcPlayerFrame};
+
+#[cfg(feature = "image")]
+pub(crate) use methods::save_rgba_png;
 
 /// A visual bit of multimedia.
 ///
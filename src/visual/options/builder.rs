@@ -1,6 +1,6 @@
-//!
+//! `NcVisualOptionsBuilder`
 
-use crate::{NcAlign, NcBlitter, NcPlane, NcRgba, NcScale, NcVisualFlag, NcVisualOptions};
+use crate::{NcAlign, NcBlitter, NcPlane, NcRgb, NcRgba, NcScale, NcVisualFlag, NcVisualOptions};
 
 /// Builder object for [`NcVisualOptions`].
 ///
@@ -61,7 +61,7 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
         }
 
         if o.does_alpha() {
-            builder = builder.transcolor(Some(o.transcolor))
+            builder = builder.transcolor(Some(NcRgb(o.transcolor)))
         }
         if o.does_blend() {
             builder = builder.blend(true);
@@ -117,13 +117,13 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
     /// If false, the blitting will occur in the provided [`plane`], if any,
     /// or in a newly created `NcPlane` otherwise.
     ///
-    /// Default: *false* (no child plaane).
+    /// Default: *false* (no child plane).
     ///
-    /// Effect: Sets the [`CHILDPLANE`] flag.
+    /// Effect: Sets the [`ChildPlane`] flag.
     ///
     /// See also: *[`plane`]*, *[`parent`]*.
     ///
-    /// [`CHILDPLANE`]: NcVisualOptions#associatedconstant.CHILDPLANE
+    /// [`ChildPlane`]: NcVisualFlag#associatedconstant.ChildPlane
     /// [`plane`]: NcVisualOptionsBuilder#method.plane
     /// [`parent`]: NcVisualOptionsBuilder#method.parent
     pub fn child(mut self, child: bool) -> Self {
@@ -152,11 +152,11 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
 
     /// Unsets the `NcPlane`.
     ///
-    /// Effect: unsets the plane & the [`CHILDPLANE`] flag.
+    /// Effect: unsets the plane & the [`ChildPlane`] flag.
     ///
     /// Default: *`None`* (no plane).
     ///
-    /// [`CHILDPLANE`]: NcVisualOptions#associatedconstant.CHILDPLANE
+    /// [`ChildPlane`]: NcVisualFlag#associatedconstant.ChildPlane
     pub fn no_plane(mut self) -> Self {
         self.plane = None;
         self.flags &= !NcVisualFlag::ChildPlane;
@@ -177,7 +177,7 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
     ///
     /// Effect: Sets the *y* coordinate, and unsets the [`VerAligned`] flag.
     ///
-    /// [`VerAligned`]: NcVisualOptions#associatedconstant.VerAligned
+    /// [`VerAligned`]: NcVisualFlag#associatedconstant.VerAligned
     pub fn y(mut self, y: i32) -> Self {
         self.y = y;
         self.flags &= !NcVisualFlag::VerAligned;
@@ -190,7 +190,7 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
     ///
     /// Effect: Sets the *x* coordinate, and unsets the [`HorAligned`] flag.
     ///
-    /// [`HorAligned`]: NcVisualOptions#associatedconstant.HorAligned
+    /// [`HorAligned`]: NcVisualFlag#associatedconstant.HorAligned
     pub fn x(mut self, x: i32) -> Self {
         self.x = x;
         self.flags &= !NcVisualFlag::HorAligned;
@@ -204,8 +204,8 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
     /// Effect: Sets the *`y` & `x`* coordinates and unsets the [`VerAligned`]
     /// & [`HorAligned`] flags.
     ///
-    /// [`VerAligned`]: NcVisualOptions#associatedconstant.VerAligned
-    /// [`HorAligned`]: NcVisualOptions#associatedconstant.HorAligned
+    /// [`VerAligned`]: NcVisualFlag#associatedconstant.VerAligned
+    /// [`HorAligned`]: NcVisualFlag#associatedconstant.HorAligned
     pub fn yx(mut self, y: i32, x: i32) -> Self {
         self.y = y;
         self.x = x;
@@ -273,13 +273,14 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
 
     /// Choose the color to be considered transparent, or `None`.
     ///
+    /// Its alpha component is ignored.
+    ///
     /// Default: *none*.
     ///
-    /// Efect: (Un)Sets the transparent color, and the [`AddAlpha`] flag.
+    /// Effect: (Un)Sets the transparent color, and the [`AddAlpha`] flag.
     ///
     /// [`AddAlpha`]: NcVisualFlag#associatedconstant.AddAlpha
     pub fn transcolor(mut self, color: Option<impl Into<NcRgba>>) -> Self {
-        // if color.is_none() {
         if let Some(color) = color {
             self.transcolor = color.into();
             self.flags |= NcVisualFlag::AddAlpha;
@@ -314,7 +315,7 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
     ///
     /// Default: *true* (degrades).
     ///
-    /// Effect: Sets the [`NoDegrade`] flag.
+    /// Effect: (Un)Sets the [`NoDegrade`] flag.
     ///
     /// See also: the [*rules of degradation*].
     ///
@@ -329,10 +330,13 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
         self
     }
 
-    /// Sets the `NoInterpolate` flag.
+    /// Choose whether to interpolate when scaling, or to use nearest-neighbor
+    /// sampling.
     ///
     /// Default: *true* (interpolates).
     ///
+    /// Effect: (Un)Sets the [`NoInterpolate`] flag.
+    ///
     /// [`NoInterpolate`]: NcVisualFlag#associatedconstant.NoInterpolate
     pub fn interpolate(mut self, interpolate: bool) -> Self {
         if interpolate {
@@ -343,21 +347,49 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
         self
     }
 
-    /// Sets the region to be rendered.
+    /// Sets the region of the visual to be rendered, starting at `beg_y`,
+    /// `beg_x` and spanning `len_y` * `len_x` pixels.
     ///
-    /// (start_y, start_x, len_y, len_x)
+    /// A length of `0` extends the region to the end of the visual.
+    ///
+    /// Default: *the whole visual*.
+    ///
+    /// See also: *[`no_region`]*.
+    ///
+    /// [`no_region`]: NcVisualOptionsBuilder#method.no_region
     pub fn region(mut self, beg_y: u32, beg_x: u32, len_y: u32, len_x: u32) -> Self {
         self.region_yx_lenyx = Some((beg_y, beg_x, len_y, len_x));
         self
     }
 
-    /// Sets the pixel offset within the [`NcCell`][crate::NcCell].
+    /// Unsets the region, so that the whole visual is rendered.
+    ///
+    /// Default: *the whole visual*.
+    pub fn no_region(mut self) -> Self {
+        self.region_yx_lenyx = None;
+        self
+    }
+
+    /// Sets the pixel offset within the upper-left [`NcCell`][crate::NcCell].
+    ///
+    /// Only used with [`NcBlitter::Pixel`]. It's an error if it exceeds the
+    /// cell-pixel geometry.
     ///
+    /// Default: *`(0, 0)`*.
     pub fn cell_offset(mut self, y: u32, x: u32) -> Self {
         self.cell_offset_yx = Some((y, x));
         self
     }
 
+    /// Sets all the [`NcVisualFlag`]s at once, overriding the ones set by
+    /// other methods.
+    ///
+    /// Default: *[`NcVisualFlag::None`]*.
+    pub fn flags(mut self, flags: impl Into<NcVisualFlag>) -> Self {
+        self.flags = flags.into();
+        self
+    }
+
     /// Finishes the building and returns [`NcVisualOptions`].
     pub fn build(self) -> NcVisualOptions {
         NcVisualOptions::new(
@@ -373,3 +405,44 @@ impl<'ncplane> NcVisualOptionsBuilder<'ncplane> {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{NcAlign, NcBlitter, NcRgba, NcScale, NcVisualFlag, NcVisualOptions};
+
+    #[test]
+    fn visual_options_builder() {
+        let options = NcVisualOptions::builder()
+            .scale(NcScale::Stretch)
+            .align(NcAlign::Center, NcAlign::Right)
+            .blitter(NcBlitter::Pixel)
+            .region(1, 2, 3, 4)
+            .cell_offset(5, 6)
+            .transcolor(Some(NcRgba::new(0x11, 0x22, 0x33, 0xff)))
+            .degrade(false)
+            .interpolate(false)
+            .build();
+        assert![!options.does_plane()];
+        assert![options.is_veraligned() && options.is_horaligned()];
+        assert![options.does_alpha() && !options.does_blend()];
+        assert![!options.does_degrade() && !options.does_interpolate()];
+        assert_eq![
+            (1, 2, 3, 4),
+            (options.begy, options.begx, options.leny, options.lenx)
+        ];
+        assert_eq![(5, 6), (options.pxoffy, options.pxoffx)];
+        assert_eq![0x112233, options.transcolor];
+
+        let options = NcVisualOptions::builder()
+            .align(NcAlign::Center, NcAlign::Right)
+            .yx(1, 2)
+            .region(1, 2, 3, 4)
+            .no_region()
+            .flags(NcVisualFlag::Blend)
+            .build();
+        assert![!options.is_veraligned() && !options.is_horaligned()];
+        assert![options.does_blend() && options.does_degrade()];
+        assert_eq![(1, 2), (options.y, options.x)];
+        assert_eq![(0, 0), (options.leny, options.lenx)];
+    }
+}
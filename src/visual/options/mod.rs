@@ -92,7 +92,7 @@ pub use builder::NcVisualOptionsBuilder;
 /// [`pxoffx`]: crate::c_api::ffi::ncvisual_options#structfield.pxoffx
 /// [`AddAlpha`]: NcVisualFlag#associatedconstant.AddAlpha
 /// [`Childplane`]: NcVisualFlag#associatedconstant.Childplane
-/// [`VerAligned`]:NcVisualFlag#associatedconstant.VerAligned
+/// [`VerAligned`]: NcVisualFlag#associatedconstant.VerAligned
 /// [`HorAligned`]: NcVisualFlag#associatedconstant.HorAligned
 pub type NcVisualOptions = crate::c_api::ffi::ncvisual_options;
 
@@ -125,7 +125,7 @@ impl<'ncplane> NcVisualOptions {
     ///   the `NcVisual` will be on that plane, in the *x* axis.
     ///
     ///   Otherwise it specifies where the created `NcPlane` will be placed,
-    ///   in the *y* axis, relative to the standard plane's origin.
+    ///   in the *x* axis, relative to the standard plane's origin.
     ///
     ///   If [`HorAligned`] is set, this will be interpreted as an [`NcAlign`]
     ///   value.
@@ -150,7 +150,7 @@ impl<'ncplane> NcVisualOptions {
     /// * `flags` - [`NcVisualFlag`].
     ///
     /// * `transcolor` - treats this color as transparent when the [`AddAlpha`]
-    ///   flag is active
+    ///   flag is active. Its alpha component is ignored.
     ///
     /// # Notes
     ///
@@ -163,9 +163,9 @@ impl<'ncplane> NcVisualOptions {
     /// [`NcPixelGeometry.cell_x`]: crate::NcPixelGeometry#structfield.cell_x
     /// [`NcVisualGeometry.cdim_yx`]: crate::NcVisualGeometry#structfield.cdim_yx
     /// [`AddAlpha`]: NcVisualFlag#associatedconstant.AddAlpha
-    /// [`Childplane`]: NcVisualFlag#associatedconstant.Childplane
-    /// [`VerAligned`]:NcVisualFlag#associatedconstant.VerALigned
-    /// [`HorAligned`]: NcVisualFlag#associatedconstant.HorALigned
+    /// [`Childplane`]: NcVisualFlag#associatedconstant.ChildPlane
+    /// [`VerAligned`]: NcVisualFlag#associatedconstant.VerAligned
+    /// [`HorAligned`]: NcVisualFlag#associatedconstant.HorAligned
     pub fn new(
         plane: Option<&mut NcPlane>,
         scale: impl Into<NcScale>,
@@ -198,7 +198,8 @@ impl<'ncplane> NcVisualOptions {
 
             flags: flags.into().into(),
 
-            transcolor: transcolor.into().into(),
+            // notcurses compares it as RGB
+            transcolor: transcolor.into().to_rgb().into(),
 
             pxoffy,
             pxoffx,
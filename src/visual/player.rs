@@ -0,0 +1,261 @@
+//! `NcPlayer`
+
+use core::{ffi::c_void, ptr::null_mut};
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use crate::{
+    c_api::{self, NcResult_i32},
+    error::catch_panic,
+    Nc, NcBlitter, NcError, NcPlane, NcResult, NcScale, NcTime, NcVisual, NcVisualOptions,
+};
+
+/// A media player, that streams an [`NcVisual`] into its own plane,
+/// calling a closure for each frame.
+///
+/// The plane is created as a child of the standard plane, covering the whole
+/// terminal, and is destroyed when the player is dropped.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let visual = NcVisual::from_file("video.mp4")?;
+/// let mut player = NcPlayer::new(nc)?;
+/// player.play(visual, |frame| {
+///     // `q` stops, and space pauses until another key is pressed
+///     match frame.nc().get_nblock(None)? {
+///         NcReceived::Char('q') => return Ok(NcPlayerControl::Stop),
+///         NcReceived::Char(' ') => {
+///             frame.nc().get_blocking(None)?;
+///         }
+///         _ => (),
+///     }
+///     Ok(NcPlayerControl::Continue)
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct NcPlayer<'nc> {
+    nc: &'nc mut Nc,
+    plane: &'nc mut NcPlane,
+    timescale: f32,
+    scale: NcScale,
+    blitter: NcBlitter,
+    subtitles: bool,
+}
+
+/// What an [`NcPlayer`] does with a frame after calling its closure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NcPlayerControl {
+    /// Displays the frame at its time, and goes on with the next one.
+    Continue,
+    /// Doesn't display the frame, and goes on with the next one right away.
+    Skip,
+    /// Stops the playback.
+    Stop,
+}
+
+/// A frame being played by an [`NcPlayer`], already blitted into its plane.
+#[derive(Debug)]
+pub struct NcPlayerFrame<'frame> {
+    nc: &'frame mut Nc,
+    visual: &'frame mut NcVisual,
+    plane: &'frame mut NcPlane,
+    time: &'frame NcTime,
+    index: u64,
+}
+
+/// # Constructors
+impl<'nc> NcPlayer<'nc> {
+    /// New player with a plane covering the standard plane of `nc`.
+    ///
+    /// Defaults to a timescale of 1, scaling the visual to the plane, with the
+    /// default blitter, and without subtitles.
+    pub fn new(nc: &'nc mut Nc) -> NcResult<NcPlayer<'nc>> {
+        let stdplane = unsafe { nc.stdplane() };
+        let (rows, cols) = stdplane.dim_yx();
+        let plane = NcPlane::new_child_sized(stdplane, 0, 0, rows, cols)?;
+        Ok(Self {
+            nc,
+            plane,
+            timescale: 1.0,
+            scale: NcScale::Scale,
+            blitter: NcBlitter::Default,
+            subtitles: false,
+        })
+    }
+}
+
+/// # Methods
+impl<'nc> NcPlayer<'nc> {
+    /// Returns the plane where the frames are blitted.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Sets the scaling of the frame durations.
+    ///
+    /// For a visual naturally running at 30FPS, a `timescale` of 0.1 results
+    /// in 300FPS, and a `timescale` of 10 results in 3FPS.
+    ///
+    /// Returns an error if `timescale` is not positive.
+    pub fn set_timescale(&mut self, timescale: f32) -> NcResult<()> {
+        if timescale.is_nan() || timescale <= 0.0 {
+            return Err(NcError::new_msg(&format![
+                "NcPlayer.set_timescale({})",
+                timescale
+            ]));
+        }
+        self.timescale = timescale;
+        Ok(())
+    }
+
+    /// Sets how the frames are scaled to the plane.
+    pub fn set_scale(&mut self, scale: impl Into<NcScale>) {
+        self.scale = scale.into();
+    }
+
+    /// Sets the blitter used for the frames.
+    pub fn set_blitter(&mut self, blitter: impl Into<NcBlitter>) {
+        self.blitter = blitter.into();
+    }
+
+    /// Sets whether to display the subtitles of the visual.
+    pub fn set_subtitles(&mut self, subtitles: bool) {
+        self.subtitles = subtitles;
+    }
+
+    /// Plays the `visual` from its current frame until the end, or until
+    /// `on_frame` stops it or returns an error, which is then returned.
+    /// If it panics, an error is returned as well.
+    ///
+    /// `on_frame` is called for each frame after it has been blitted, and
+    /// before it's rendered and displayed at its time.
+    ///
+    /// To pause the playback, block inside `on_frame`, e.g. waiting for input
+    /// with the [`nc`][NcPlayerFrame#method.nc] of the frame.
+    /// The timing of the media is kept, so the frames after a pause are
+    /// displayed without delay until the playback catches up.
+    ///
+    /// *C style function: [ncvisual_stream()][c_api::ncvisual_stream].*
+    pub fn play<F>(&mut self, visual: &mut NcVisual, on_frame: F) -> NcResult<()>
+    where
+        F: FnMut(&mut NcPlayerFrame) -> NcResult<NcPlayerControl>,
+    {
+        let subtitles = if self.subtitles { self.plane as *mut NcPlane } else { null_mut() };
+        let options = NcVisualOptions::builder()
+            .plane(self.plane)
+            .scale(self.scale)
+            .blitter(self.blitter)
+            .build();
+        let nc: *mut Nc = self.nc;
+        let mut state = NcPlayerState { on_frame, nc, subtitles, index: 0, error: None };
+
+        let res = unsafe {
+            c_api::ncvisual_stream(
+                nc,
+                visual,
+                self.timescale,
+                Some(ncplayer_streamer::<F>),
+                &options,
+                &mut state as *mut NcPlayerState<F> as *mut c_void,
+            )
+        };
+        if let Some(error) = state.error {
+            return Err(error);
+        }
+        if res < c_api::NCRESULT_OK {
+            return Err(NcError::with_msg(res, "NcPlayer.play()"));
+        }
+        Ok(())
+    }
+}
+
+/// # Methods
+impl<'frame> NcPlayerFrame<'frame> {
+    /// Returns the notcurses context, e.g. for getting input.
+    pub fn nc(&mut self) -> &mut Nc {
+        self.nc
+    }
+
+    /// Returns the visual being played.
+    pub fn visual(&mut self) -> &mut NcVisual {
+        self.visual
+    }
+
+    /// Returns the plane where the frame has been blitted.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Returns the absolute time at which the frame is to be displayed.
+    pub fn time(&self) -> &NcTime {
+        self.time
+    }
+
+    /// Returns the number of frames played before this one.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+}
+
+/// The state of a playback, passed to [`ncplayer_streamer`] as its curry.
+struct NcPlayerState<F> {
+    on_frame: F,
+    nc: *mut Nc,
+    subtitles: *mut NcPlane,
+    index: u64,
+    error: Option<NcError>,
+}
+
+/// The C stream callback that calls the closure of the playback.
+///
+/// Returns a positive number to stop the stream on purpose, and saves the
+/// errors returned by the closure, or by its panics.
+unsafe extern "C" fn ncplayer_streamer<F>(
+    visual: *mut NcVisual,
+    options: *mut NcVisualOptions,
+    time: *const NcTime,
+    curry: *mut c_void,
+) -> NcResult_i32
+where
+    F: FnMut(&mut NcPlayerFrame) -> NcResult<NcPlayerControl>,
+{
+    let state = &mut *(curry as *mut NcPlayerState<F>);
+    let mut frame = NcPlayerFrame {
+        nc: &mut *state.nc,
+        visual: &mut *visual,
+        plane: &mut *(*options).n,
+        time: &*time,
+        index: state.index,
+    };
+    state.index += 1;
+
+    let on_frame = &mut state.on_frame;
+    match catch_panic("NcPlayer on_frame", || on_frame(&mut frame)) {
+        Ok(NcPlayerControl::Continue) => {
+            c_api::ncvisual_simple_streamer(visual, options, time, state.subtitles as *mut c_void)
+        }
+        Ok(NcPlayerControl::Skip) => c_api::NCRESULT_OK,
+        Ok(NcPlayerControl::Stop) => 1,
+        Err(error) => {
+            state.error = Some(error);
+            c_api::NCRESULT_ERR
+        }
+    }
+}
+
+mod core_impls {
+    use super::NcPlayer;
+
+    impl<'nc> Drop for NcPlayer<'nc> {
+        /// Destroys the plane of the player.
+        fn drop(&mut self) {
+            let _ = self.plane.destroy();
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! `NcFocusManager` & `NcFocusIndicator`.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{widgets::NcWidget, NcInput, NcInputType, NcKey, NcPlane, NcResult};
+
+/// How an [`NcFocusManager`] shows which widget is focused.
+#[allow(clippy::type_complexity)]
+pub enum NcFocusIndicator<'a> {
+    /// Nothing is drawn.
+    None,
+
+    /// The `marker` is drawn at `y`, `x` of the plane of the focused widget,
+    /// again after each input offered, since the widget may redraw over it.
+    ///
+    /// Nothing is restored when the widget loses the focus, so it must be
+    /// somewhere the widget redraws, or that it doesn't use.
+    Marker {
+        /// The character drawn.
+        marker: char,
+        /// The row of the plane.
+        y: u32,
+        /// The column of the plane.
+        x: u32,
+    },
+
+    /// A closure that receives the plane of a widget, and whether it has
+    /// just gained (`true`) or lost (`false`) the focus.
+    Custom(Box<dyn FnMut(&mut NcPlane, bool) -> NcResult<()> + 'a>),
+}
+
+/// Nothing is drawn.
+impl Default for NcFocusIndicator<'_> {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Composes several [`NcWidget`]s, keeping track of the focused one.
+///
+/// - *Tab* focuses the next widget, and *Shift-Tab* the previous one.
+/// - Any other input is offered to the focused widget first, and then to the
+///   rest in order, until one finds it relevant. E.g. a mouse click on an
+///   unfocused widget still reaches it.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let stdplane = unsafe { nc.stdplane() };
+/// let size = NcSelector::builder()
+///     .item("S", "small")
+///     .item("L", "large")
+///     .finish(NcPlane::new_child_sized(stdplane, 0, 2, 8, 20)?)?;
+/// let extras = NcMultiSelector::builder()
+///     .item("cheese", "", false)
+///     .finish(NcPlane::new_child_sized(stdplane, 0, 24, 8, 20)?)?;
+///
+/// let mut form = NcFocusManager::new();
+/// form.push(size)?;
+/// form.push(extras)?;
+///
+/// let mut input = NcInput::new_empty();
+/// loop {
+///     nc.get_blocking(Some(&mut input))?;
+///     if input.id == NcKey::Enter.into() {
+///         break;
+///     }
+///     form.offer_input(&input)?;
+///     nc.render()?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[doc(alias = "FocusManager")]
+pub struct NcFocusManager<'a> {
+    widgets: Vec<&'a mut dyn NcWidget>,
+    focused: Option<usize>,
+    indicator: NcFocusIndicator<'a>,
+}
+
+/// # Constructors
+impl<'a> NcFocusManager<'a> {
+    /// New empty `NcFocusManager`, with the default indicator.
+    pub fn new() -> Self {
+        Self::with_indicator(NcFocusIndicator::default())
+    }
+
+    /// New empty `NcFocusManager`, with the provided `indicator`.
+    pub fn with_indicator(indicator: NcFocusIndicator<'a>) -> Self {
+        Self { widgets: Vec::new(), focused: None, indicator }
+    }
+}
+
+/// # Methods
+impl<'a> NcFocusManager<'a> {
+    /// Adds the `widget` at the end, focusing it if it's the first one.
+    pub fn push(&mut self, widget: &'a mut dyn NcWidget) -> NcResult<()> {
+        self.widgets.push(widget);
+        if self.focused.is_none() {
+            self.focus(self.widgets.len() - 1)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of widgets.
+    pub fn len(&self) -> usize {
+        self.widgets.len()
+    }
+
+    /// Returns `true` if there are no widgets.
+    pub fn is_empty(&self) -> bool {
+        self.widgets.is_empty()
+    }
+
+    /// Returns the widget at `index`, if any.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut dyn NcWidget> {
+        match self.widgets.get_mut(index) {
+            Some(widget) => Some(&mut **widget),
+            None => None,
+        }
+    }
+
+    /// Returns the index of the focused widget, if any.
+    pub fn focused_index(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Returns the focused widget, if any.
+    pub fn focused(&mut self) -> Option<&mut dyn NcWidget> {
+        self.get_mut(self.focused?)
+    }
+
+    /// Focuses the widget at `index`, if it exists.
+    pub fn focus(&mut self, index: usize) -> NcResult<()> {
+        if index >= self.widgets.len() || self.focused == Some(index) {
+            return Ok(());
+        }
+        if let Some(old) = self.focused.take() {
+            self.indicate(old, false)?;
+        }
+        self.focused = Some(index);
+        self.indicate(index, true)
+    }
+
+    /// Focuses the next widget, cycling back to the first one.
+    pub fn focus_next(&mut self) -> NcResult<()> {
+        match self.focused {
+            Some(i) => self.focus((i + 1) % self.widgets.len()),
+            None => self.focus(0),
+        }
+    }
+
+    /// Focuses the previous widget, cycling back to the last one.
+    pub fn focus_prev(&mut self) -> NcResult<()> {
+        let len = self.widgets.len();
+        match self.focused {
+            Some(i) => self.focus((i + len - 1) % len),
+            None => self.focus(len.saturating_sub(1)),
+        }
+    }
+
+    /// Offers the `input`, handling *Tab* and *Shift-Tab*, and forwarding
+    /// anything else to the focused widget first.
+    ///
+    /// Afterwards the [`Marker`] indicator is drawn again on the focused widget.
+    ///
+    /// Returns `true` if it was relevant to the manager or to any widget.
+    ///
+    /// [`Marker`]: NcFocusIndicator#variant.Marker
+    pub fn offer_input(&mut self, input: &NcInput) -> NcResult<bool> {
+        if input.id == NcKey::Tab.into() {
+            if NcInputType::from(input.evtype) != NcInputType::Release {
+                if input.shift_p() {
+                    self.focus_prev()?;
+                } else {
+                    self.focus_next()?;
+                }
+            }
+            return Ok(true);
+        }
+        let focused = self.focused;
+        let relevant = self
+            .focused()
+            .map_or(false, |widget| widget.offer_input(input))
+            || self
+                .widgets
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| Some(*i) != focused)
+                .any(|(_, widget)| widget.offer_input(input));
+        if let (Some(index), NcFocusIndicator::Marker { .. }) = (focused, &self.indicator) {
+            self.indicate(index, true)?;
+        }
+        Ok(relevant)
+    }
+
+    /// Draws or clears the indicator on the widget at `index`.
+    fn indicate(&mut self, index: usize, focused: bool) -> NcResult<()> {
+        let plane = match &mut self.indicator {
+            NcFocusIndicator::None => return Ok(()),
+            _ => self.widgets[index].plane()?,
+        };
+        match &mut self.indicator {
+            NcFocusIndicator::None => (),
+            NcFocusIndicator::Marker { marker, y, x } => {
+                if focused {
+                    plane.putchar_yx(*y, *x, *marker)?;
+                }
+            }
+            NcFocusIndicator::Custom(draw) => draw(plane, focused)?,
+        }
+        Ok(())
+    }
+}
+
+impl Default for NcFocusManager<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NcFocusIndicator, NcFocusManager};
+    use crate::{widgets::NcWidget, NcError, NcInput, NcPlane, NcResult};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    /// A widget that takes the inputs of a single char.
+    struct Mock {
+        takes: char,
+        got: Vec<char>,
+    }
+
+    impl NcWidget for Mock {
+        fn plane(&mut self) -> NcResult<&mut NcPlane> {
+            Err(NcError::new())
+        }
+        fn offer_input(&mut self, input: &NcInput) -> bool {
+            let ch = char::from_u32(input.id).unwrap();
+            self.got.push(ch);
+            ch == self.takes
+        }
+        fn destroy(&mut self) {}
+    }
+
+    #[test]
+    fn focus_manager() -> NcResult<()> {
+        let (mut a, mut b, mut c) = (
+            Mock { takes: 'a', got: Vec::new() },
+            Mock { takes: 'b', got: Vec::new() },
+            Mock { takes: 'c', got: Vec::new() },
+        );
+        let mut fm = NcFocusManager::with_indicator(NcFocusIndicator::None);
+        assert_eq![None, fm.focused_index()];
+        fm.push(&mut a)?;
+        fm.push(&mut b)?;
+        fm.push(&mut c)?;
+        assert_eq![Some(0), fm.focused_index()];
+
+        assert![fm.offer_input(&NcInput::new('\t'))?];
+        assert_eq![Some(1), fm.focused_index()];
+        assert![fm.offer_input(&NcInput::with_shift('\t'))?];
+        assert![fm.offer_input(&NcInput::with_shift('\t'))?];
+        assert_eq![Some(2), fm.focused_index()];
+
+        // the focused widget goes first, then the rest in order
+        assert![fm.offer_input(&NcInput::new('b'))?];
+        assert![!fm.offer_input(&NcInput::new('x'))?];
+        drop(fm);
+        assert_eq![
+            (vec!['b', 'x'], vec!['b', 'x'], vec!['b', 'x']),
+            (a.got, b.got, c.got)
+        ];
+        Ok(())
+    }
+}
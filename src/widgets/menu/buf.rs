@@ -0,0 +1,178 @@
+//! `NcMenuItemBuf` & `NcMenuSectionBuf`
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::ptr::null;
+
+use crate::{
+    widgets::{NcMenuItem, NcMenuSection},
+    NcInput, NcString,
+};
+
+/// An owned [`NcMenuItem`], with its own description string.
+///
+/// Unlike the raw `NcMenuItem`, it doesn't leak nor dangle its description.
+#[derive(Clone, Debug)]
+pub struct NcMenuItemBuf {
+    desc: Option<NcString>,
+    shortcut: NcInput,
+}
+
+/// An owned [`NcMenuSection`], with its own name string and items.
+///
+/// Unlike the raw `NcMenuSection`, it doesn't leak nor dangle its name
+/// nor its items.
+#[derive(Clone, Debug)]
+pub struct NcMenuSectionBuf {
+    name: Option<NcString>,
+    shortcut: NcInput,
+    items: Vec<NcMenuItemBuf>,
+}
+
+/// # `NcMenuItemBuf` Constructors
+impl NcMenuItemBuf {
+    /// New item with a description and an optional `shortcut`.
+    ///
+    /// The shortcuts of all the items should be distinct.
+    pub fn new(desc: impl AsRef<str>, shortcut: impl Into<Option<NcInput>>) -> Self {
+        Self {
+            desc: Some(NcString::new(desc.as_ref())),
+            shortcut: shortcut.into().unwrap_or_else(NcInput::new_empty),
+        }
+    }
+
+    /// New horizontal separator.
+    pub fn separator() -> Self {
+        Self { desc: None, shortcut: NcInput::new_empty() }
+    }
+}
+
+/// # `NcMenuItemBuf` Methods
+impl NcMenuItemBuf {
+    /// Returns the description, or `None` for a separator.
+    pub fn desc(&self) -> Option<&str> {
+        self.desc.as_ref().map(NcString::as_str)
+    }
+
+    /// Returns the shortcut.
+    pub fn shortcut(&self) -> NcInput {
+        self.shortcut
+    }
+
+    /// Sets the `shortcut`.
+    pub fn set_shortcut(&mut self, shortcut: impl Into<Option<NcInput>>) {
+        self.shortcut = shortcut.into().unwrap_or_else(NcInput::new_empty);
+    }
+
+    /// Returns `true` if it's a separator.
+    pub fn is_separator(&self) -> bool {
+        self.desc.is_none()
+    }
+
+    /// Returns the raw item, which borrows the description.
+    pub(crate) fn as_raw(&self) -> NcMenuItem {
+        NcMenuItem {
+            desc: self.desc.as_ref().map_or(null(), NcString::as_ptr),
+            shortcut: self.shortcut,
+        }
+    }
+}
+
+/// # `NcMenuSectionBuf` Constructors
+impl NcMenuSectionBuf {
+    /// New empty section with a name and an optional `shortcut`.
+    ///
+    /// The `shortcut` will be underlined if present in the `name`.
+    pub fn new(name: impl AsRef<str>, shortcut: impl Into<Option<NcInput>>) -> Self {
+        Self {
+            name: Some(NcString::new(name.as_ref())),
+            shortcut: shortcut.into().unwrap_or_else(NcInput::new_empty),
+            items: Vec::new(),
+        }
+    }
+
+    /// New unnamed section, which aligns the sections after it to the right.
+    pub fn right_aligned() -> Self {
+        Self { name: None, shortcut: NcInput::new_empty(), items: Vec::new() }
+    }
+
+    /// Returns the section with the `item` added.
+    pub fn item(mut self, item: NcMenuItemBuf) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// # `NcMenuSectionBuf` Methods
+impl NcMenuSectionBuf {
+    /// Returns the name, or `None` for the right alignment.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(NcString::as_str)
+    }
+
+    /// Returns the shortcut.
+    pub fn shortcut(&self) -> NcInput {
+        self.shortcut
+    }
+
+    /// Returns the items.
+    pub fn items(&self) -> &[NcMenuItemBuf] {
+        &self.items
+    }
+
+    /// Returns the items, mutably.
+    pub fn items_mut(&mut self) -> &mut Vec<NcMenuItemBuf> {
+        &mut self.items
+    }
+
+    /// Adds an `item` at the end.
+    pub fn push(&mut self, item: NcMenuItemBuf) {
+        self.items.push(item);
+    }
+
+    /// Returns the raw items, which borrow their descriptions.
+    pub(crate) fn raw_items(&self) -> Vec<NcMenuItem> {
+        self.items.iter().map(NcMenuItemBuf::as_raw).collect()
+    }
+
+    /// Returns the raw section, which borrows its name and the raw `items`.
+    pub(crate) fn as_raw(&self, items: &mut [NcMenuItem]) -> NcMenuSection {
+        NcMenuSection {
+            name: self.name.as_ref().map_or(null(), NcString::as_ptr),
+            itemcount: items.len() as i32,
+            items: items.as_mut_ptr(),
+            shortcut: self.shortcut,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NcMenuItemBuf, NcMenuSectionBuf};
+    use crate::NcInput;
+    use core::ffi::CStr;
+
+    #[test]
+    fn menu_section_buf() {
+        let section = NcMenuSectionBuf::new("File", NcInput::with_alt('f'))
+            .item(NcMenuItemBuf::new("Open", None))
+            .item(NcMenuItemBuf::separator());
+        assert_eq![Some("File"), section.name()];
+        assert_eq![Some("Open"), section.items()[0].desc()];
+        assert![section.items()[1].is_separator()];
+
+        let mut items = section.raw_items();
+        let raw = section.as_raw(&mut items);
+        assert_eq![2, raw.itemcount];
+        assert_eq![
+            Ok("Open"),
+            unsafe { CStr::from_ptr(items[0].desc) }.to_str()
+        ];
+        assert![items[1].desc.is_null()];
+        assert![NcMenuSectionBuf::right_aligned()
+            .as_raw(&mut [])
+            .name
+            .is_null()];
+    }
+}
@@ -0,0 +1,215 @@
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+use crate::{
+    widgets::{
+        NcMenu, NcMenuHandle, NcMenuItem, NcMenuItemBuf, NcMenuOptions, NcMenuSection,
+        NcMenuSectionBuf,
+    },
+    NcChannels, NcError, NcInput, NcPlane, NcResult,
+};
+
+/// A handy builder for [`NcMenu`].
+///
+/// It owns the names of the sections and the descriptions of the items,
+/// which notcurses copies when the menu is created.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let menu = NcMenuBuilder::new()
+///     .section("File", NcInput::with_alt('f'))
+///     .item("Open", NcInput::with_ctrl('o'))
+///     .separator()
+///     .item("Quit", NcInput::with_ctrl('q'))
+///     .section("Help", NcInput::with_alt('h'))
+///     .item("About", None)
+///     .hiding(true)
+///     .finish(unsafe { nc.stdplane() })?;
+/// // …
+/// menu.destroy();
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Debug)]
+pub struct NcMenuBuilder {
+    sections: Vec<NcMenuSectionBuf>,
+    // the number of items added before any section.
+    orphan_items: usize,
+    header_channels: NcChannels,
+    section_channels: NcChannels,
+    flags: u64,
+}
+
+impl NcMenuBuilder {
+    /// New `NcMenuBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a section, to which the following items are added.
+    ///
+    /// The `shortcut` will be underlined if present in the `name`.
+    pub fn section(mut self, name: &str, shortcut: impl Into<Option<NcInput>>) -> Self {
+        self.sections.push(NcMenuSectionBuf::new(name, shortcut));
+        self
+    }
+
+    /// Adds an already built `section`.
+    pub fn push_section(mut self, section: NcMenuSectionBuf) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Aligns the sections added after this one to the right.
+    pub fn right_aligned(mut self) -> Self {
+        self.sections.push(NcMenuSectionBuf::right_aligned());
+        self
+    }
+
+    /// Adds an item to the last section.
+    ///
+    /// The shortcuts of all the items should be distinct.
+    ///
+    /// Adding it before any section makes [`finish`][Self#method.finish] fail.
+    pub fn item(mut self, desc: &str, shortcut: impl Into<Option<NcInput>>) -> Self {
+        self.push_item(NcMenuItemBuf::new(desc, shortcut));
+        self
+    }
+
+    /// Adds a horizontal separator to the last section.
+    ///
+    /// Adding it before any section makes [`finish`][Self#method.finish] fail.
+    pub fn separator(mut self) -> Self {
+        self.push_item(NcMenuItemBuf::separator());
+        self
+    }
+
+    /// Sets the `NcChannels` for the header.
+    pub fn header_channels(mut self, channels: impl Into<NcChannels>) -> Self {
+        self.header_channels = channels.into();
+        self
+    }
+
+    /// Sets the `NcChannels` for the sections.
+    pub fn section_channels(mut self, channels: impl Into<NcChannels>) -> Self {
+        self.section_channels = channels.into();
+        self
+    }
+
+    /// If `true`, places the menu on the bottom row instead of the top row.
+    ///
+    /// See [`NcMenuOptions::BOTTOM`].
+    pub fn bottom(mut self, bottom: bool) -> Self {
+        self.set_flag(NcMenuOptions::BOTTOM, bottom);
+        self
+    }
+
+    /// If `true`, hides the menu when not unrolled.
+    ///
+    /// See [`NcMenuOptions::HIDING`].
+    pub fn hiding(mut self, hiding: bool) -> Self {
+        self.set_flag(NcMenuOptions::HIDING, hiding);
+        self
+    }
+
+    /// Finishes the builder and returns the `NcMenu`.
+    ///
+    /// Returns an error if there are no sections, if a named section
+    /// has no items, or if any item was added before the first section.
+    pub fn finish<'a>(self, plane: &mut NcPlane) -> NcResult<&'a mut NcMenu> {
+        self.check_orphans()?;
+        create_menu(
+            plane,
+            &self.sections,
+            self.header_channels,
+            self.section_channels,
+            self.flags,
+        )
+    }
+
+    /// Finishes the builder and returns the `NcMenu` in an [`NcMenuHandle`],
+    /// which keeps the sections and borrows the parent `plane`, so the items
+    /// can be changed afterwards.
+    ///
+    /// Returns the same errors as [`finish`][Self#method.finish].
+    pub fn finish_handle(self, plane: &mut NcPlane) -> NcResult<NcMenuHandle<'_>> {
+        self.check_orphans()?;
+        let menu = create_menu(
+            plane,
+            &self.sections,
+            self.header_channels,
+            self.section_channels,
+            self.flags,
+        )?;
+        Ok(NcMenuHandle {
+            menu,
+            plane,
+            sections: self.sections,
+            header_channels: self.header_channels,
+            section_channels: self.section_channels,
+            flags: self.flags,
+        })
+    }
+
+    fn check_orphans(&self) -> NcResult<()> {
+        if self.orphan_items > 0 {
+            return Err(NcError::new_msg(&format![
+                "NcMenuBuilder: {} items added before any section",
+                self.orphan_items
+            ]));
+        }
+        Ok(())
+    }
+
+    fn push_item(&mut self, item: NcMenuItemBuf) {
+        match self.sections.last_mut() {
+            Some(section) => section.push(item),
+            None => self.orphan_items += 1,
+        }
+    }
+
+    fn set_flag(&mut self, flag: u64, set: bool) {
+        if set {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+}
+
+/// Creates an `NcMenu` from owned `sections`.
+pub(crate) fn create_menu<'a>(
+    plane: &mut NcPlane,
+    sections: &[NcMenuSectionBuf],
+    header_channels: NcChannels,
+    section_channels: NcChannels,
+    flags: u64,
+) -> NcResult<&'a mut NcMenu> {
+    if sections.is_empty() {
+        return Err(NcError::new_msg("NcMenu: no sections"));
+    }
+    if let Some(empty) = sections
+        .iter()
+        .find(|s| s.name().is_some() && s.items().is_empty())
+    {
+        return Err(NcError::new_msg(&format![
+            "NcMenu: section {:?} has no items",
+            empty.name()
+        ]));
+    }
+
+    let mut items: Vec<Vec<NcMenuItem>> = sections.iter().map(|s| s.raw_items()).collect();
+    let mut raw_sections: Vec<NcMenuSection> = sections
+        .iter()
+        .zip(items.iter_mut())
+        .map(|(s, items)| s.as_raw(items))
+        .collect();
+
+    let options =
+        NcMenuOptions::with_all_args(&mut raw_sections, header_channels, section_channels, flags);
+    NcMenu::new(plane, &options)
+}
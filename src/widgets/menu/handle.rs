@@ -0,0 +1,237 @@
+//! `NcMenuHandle`
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use core::mem;
+
+use super::builder::create_menu;
+use crate::{
+    widgets::{NcMenu, NcMenuItemBuf, NcMenuSectionBuf},
+    NcChannels, NcError, NcInput, NcPlane, NcResult,
+};
+
+/// An [`NcMenu`] that remembers its sections, so its items can be changed.
+///
+/// Notcurses can't change the items of a menu, so the methods that do it
+/// rebuild the menu from the changed sections, in the same parent plane,
+/// which is borrowed meanwhile. The rebuilt menu is rolled up.
+///
+/// The menu is destroyed when the handle is dropped.
+///
+/// It's created by [`NcMenuBuilder.finish_handle`].
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// # let nc = unsafe { Nc::new()? };
+/// let mut menu = NcMenuBuilder::new()
+///     .section("Recent", None)
+///     .item("a.txt", None)
+///     .finish_handle(unsafe { nc.stdplane() })?;
+/// menu.add_item("Recent", NcMenuItemBuf::new("b.txt", None))?;
+/// menu.remove_item("Recent", "a.txt")?;
+/// menu.unroll(0)?;
+/// drop(menu);
+/// # unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`NcMenuBuilder.finish_handle`]: crate::widgets::NcMenuBuilder#method.finish_handle
+#[derive(Debug)]
+pub struct NcMenuHandle<'plane> {
+    pub(super) menu: &'plane mut NcMenu,
+    pub(super) plane: &'plane mut NcPlane,
+    pub(super) sections: Vec<NcMenuSectionBuf>,
+    pub(super) header_channels: NcChannels,
+    pub(super) section_channels: NcChannels,
+    pub(super) flags: u64,
+}
+
+impl<'plane> Drop for NcMenuHandle<'plane> {
+    fn drop(&mut self) {
+        self.menu.destroy();
+    }
+}
+
+/// # Methods
+impl<'plane> NcMenuHandle<'plane> {
+    /// Returns the sections.
+    pub fn sections(&self) -> &[NcMenuSectionBuf] {
+        &self.sections
+    }
+
+    /// Adds an `item` at the end of the `section`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn add_item(&mut self, section: &str, item: NcMenuItemBuf) -> NcResult<()> {
+        self.rebuild(
+            &format!["NcMenuHandle.add_item({:?}, {:?})", section, item.desc()],
+            |sections| {
+                sections
+                    .iter_mut()
+                    .find(|s| s.name() == Some(section))
+                    .map(|s| s.push(item))
+            },
+        )
+    }
+
+    /// Removes the first item with the description `item` from the `section`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn remove_item(&mut self, section: &str, item: &str) -> NcResult<()> {
+        self.rebuild(
+            &format!["NcMenuHandle.remove_item({:?}, {:?})", section, item],
+            |sections| {
+                let items = sections
+                    .iter_mut()
+                    .find(|s| s.name() == Some(section))?
+                    .items_mut();
+                let index = items.iter().position(|i| i.desc() == Some(item))?;
+                items.remove(index);
+                Some(())
+            },
+        )
+    }
+
+    /// Sets the `shortcut` of the first item with the description `item`
+    /// in the `section`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_item_shortcut(
+        &mut self,
+        section: &str,
+        item: &str,
+        shortcut: impl Into<Option<NcInput>>,
+    ) -> NcResult<()> {
+        self.rebuild(
+            &format!["NcMenuHandle.set_item_shortcut({:?}, {:?})", section, item],
+            |sections| {
+                sections
+                    .iter_mut()
+                    .find(|s| s.name() == Some(section))?
+                    .items_mut()
+                    .iter_mut()
+                    .find(|i| i.desc() == Some(item))
+                    .map(|i| i.set_shortcut(shortcut))
+            },
+        )
+    }
+
+    /// Disables or enables an item.
+    ///
+    /// Any method that changes the items enables all of them again.
+    ///
+    /// *C style function: [ncmenu_item_set_status()][crate::c_api::ncmenu_item_set_status].*
+    pub fn item_set_status(&mut self, section: &str, item: &str, enabled: bool) -> NcResult<()> {
+        self.menu.item_set_status(section, item, enabled)
+    }
+
+    /// Returns the item description corresponding to the mouse `click`.
+    ///
+    /// See [`NcMenu.mouse_selected`][NcMenu#method.mouse_selected].
+    ///
+    /// *C style function: [ncmenu_mouse_selected()][crate::c_api::ncmenu_mouse_selected].*
+    pub fn mouse_selected(
+        &self,
+        click: NcInput,
+        shortcut: Option<&mut NcInput>,
+    ) -> NcResult<String> {
+        self.menu.mouse_selected(click, shortcut)
+    }
+
+    /// Moves to the next item within the currently unrolled section.
+    ///
+    /// *C style function: [ncmenu_nextitem()][crate::c_api::ncmenu_nextitem].*
+    pub fn nextitem(&mut self) -> NcResult<()> {
+        self.menu.nextitem()
+    }
+
+    /// Unrolls the next section (relative to current unrolled).
+    ///
+    /// *C style function: [ncmenu_nextsection()][crate::c_api::ncmenu_nextsection].*
+    pub fn nextsection(&mut self) -> NcResult<()> {
+        self.menu.nextsection()
+    }
+
+    /// Offers the `input` to the menu, returning true if it was relevant.
+    ///
+    /// See [`NcMenu.offer_input`][NcMenu#method.offer_input].
+    ///
+    /// *C style function: [ncmenu_offer_input()][crate::c_api::ncmenu_offer_input].*
+    pub fn offer_input(&mut self, input: NcInput) -> bool {
+        self.menu.offer_input(input)
+    }
+
+    /// Returns the [`NcPlane`] backing the menu.
+    ///
+    /// It's replaced by any method that changes the items.
+    ///
+    /// *C style function: [ncmenu_plane()][crate::c_api::ncmenu_plane].*
+    pub fn plane(&mut self) -> NcResult<&NcPlane> {
+        self.menu.plane()
+    }
+
+    /// Moves to the previous item within the currently unrolled section.
+    ///
+    /// *C style function: [ncmenu_previtem()][crate::c_api::ncmenu_previtem].*
+    pub fn previtem(&mut self) -> NcResult<()> {
+        self.menu.previtem()
+    }
+
+    /// Unrolls the previous section (relative to current unrolled).
+    ///
+    /// *C style function: [ncmenu_prevsection()][crate::c_api::ncmenu_prevsection].*
+    pub fn prevsection(&mut self) -> NcResult<()> {
+        self.menu.prevsection()
+    }
+
+    /// Rolls up any unrolled section, and hides the menu if using hiding.
+    ///
+    /// *C style function: [ncmenu_rollup()][crate::c_api::ncmenu_rollup].*
+    pub fn rollup(&mut self) -> NcResult<()> {
+        self.menu.rollup()
+    }
+
+    /// Returns the selected item description, if there's an unrolled section.
+    ///
+    /// See [`NcMenu.selected`][NcMenu#method.selected].
+    ///
+    /// *C style function: [ncmenu_selected()][crate::c_api::ncmenu_selected].*
+    pub fn selected(&mut self, shortcut: Option<&mut NcInput>) -> Option<String> {
+        self.menu.selected(shortcut)
+    }
+
+    /// Unrolls the section at `sectionindex`, making the menu visible.
+    ///
+    /// *C style function: [ncmenu_unroll()][crate::c_api::ncmenu_unroll].*
+    pub fn unroll(&mut self, sectionindex: u32) -> NcResult<()> {
+        self.menu.unroll(sectionindex)
+    }
+
+    /// Applies `change` to a copy of the sections, and replaces the menu with
+    /// a new one made from them.
+    ///
+    /// If `change` returns `None`, or the new menu can't be created,
+    /// the menu is left untouched.
+    fn rebuild(
+        &mut self,
+        errmsg: &str,
+        change: impl FnOnce(&mut Vec<NcMenuSectionBuf>) -> Option<()>,
+    ) -> NcResult<()> {
+        let mut sections = self.sections.clone();
+        change(&mut sections).ok_or_else(|| NcError::new_msg(errmsg))?;
+        let menu = create_menu(
+            self.plane,
+            &sections,
+            self.header_channels,
+            self.section_channels,
+            self.flags,
+        )?;
+        mem::replace(&mut self.menu, menu).destroy();
+        self.sections = sections;
+        Ok(())
+    }
+}
@@ -33,6 +33,8 @@ impl NcMenu {
     ///
     /// *C style function: [ncmenu_destroy()][c_api::ncmenu_destroy].*
     pub fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncmenu_plane(self) });
         unsafe { c_api::ncmenu_destroy(self) }
     }
 }
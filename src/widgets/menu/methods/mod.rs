@@ -16,6 +16,10 @@ pub use options::*;
 /// # `NcMenuItem` Constructors
 impl NcMenuItem {
     /// New NcMenuItem for [`NcMenu`].
+    ///
+    /// The description is leaked. See [`NcMenuItemBuf`] for an owned item.
+    ///
+    /// [`NcMenuItemBuf`]: crate::widgets::NcMenuItemBuf
     pub fn new(desc: &str, shortcut: NcInput) -> Self {
         let cs = cstring![desc];
         Self {
@@ -38,6 +42,11 @@ impl NcMenuItem {
 // Must contain at least 1 NcMenuItem.
 impl NcMenuSection {
     /// New `NcMenuSection` for [`NcMenu`].
+    ///
+    /// The name is leaked, and the `items` must outlive the section.
+    /// See [`NcMenuSectionBuf`] for an owned section.
+    ///
+    /// [`NcMenuSectionBuf`]: crate::widgets::NcMenuSectionBuf
     pub fn new(name: &str, items: &mut [NcMenuItem], shortcut: NcInput) -> Self {
         let cs = cstring![name];
         Self {
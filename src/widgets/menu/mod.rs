@@ -21,8 +21,15 @@
 
 use crate::c_api::ffi;
 
+mod buf;
+mod builder;
+mod handle;
 mod methods;
 
+pub use buf::{NcMenuItemBuf, NcMenuSectionBuf};
+pub use builder::NcMenuBuilder;
+pub use handle::NcMenuHandle;
+
 /// menus on the top or bottom rows
 ///
 /// An [Nc][crate::Nc] instance supports menu bars on the top or bottom row
@@ -41,6 +48,9 @@ mod methods;
 /// - [destroy()][NcMenu#method.destroy]
 ///     removes a menu bar, and frees all associated resources.
 ///
+/// It can be created with an [`NcMenuBuilder`], which can also wrap it in an
+/// [`NcMenuHandle`] for changing its items.
+///
 /// `type in C: ncmenu (struct)`
 pub type NcMenu = ffi::ncmenu;
 
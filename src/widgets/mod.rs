@@ -1,5 +1,6 @@
 //! The notcurses widgets.
 
+mod focus;
 pub(crate) mod menu;
 pub(crate) mod multiselector;
 pub(crate) mod plot;
@@ -9,7 +10,9 @@ pub(crate) mod reel;
 pub(crate) mod selector;
 pub(crate) mod tabbed;
 pub(crate) mod tree;
+mod widget;
 
+pub use focus::{NcFocusIndicator, NcFocusManager};
 pub use menu::*;
 pub use multiselector::*;
 pub use plot::*;
@@ -19,3 +22,4 @@ pub use reel::*;
 pub use selector::{NcSelector, NcSelectorBuilder, NcSelectorItem, NcSelectorOptions};
 pub use tabbed::*;
 pub use tree::*;
+pub use widget::NcWidget;
@@ -0,0 +1,168 @@
+use core::ptr::null;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::{
+    widgets::{NcMultiSelector, NcMultiSelectorItem, NcMultiSelectorOptions},
+    NcChannels, NcPlane, NcResult, NcString,
+};
+
+/// A handy builder for [`NcMultiSelector`].
+///
+/// It owns the strings of the title, the secondary title, the footer and
+/// the items, which notcurses copies when the multiselector is created.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let plane = NcPlane::new_child_sized(unsafe { nc.stdplane() }, 2, 2, 10, 40)?;
+/// let mselector = NcMultiSelector::builder()
+///     .title("Toppings")
+///     .item("cheese", "mozzarella", true)
+///     .item("basil", "fresh", false)
+///     .finish(plane)?;
+/// // …
+/// # #[cfg(feature = "std")]
+/// let chosen = mselector.selected_indices()?;
+/// mselector.destroy();
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct NcMultiSelectorBuilder {
+    title: Option<NcString>,
+    secondary: Option<NcString>,
+    footer: Option<NcString>,
+    items: Vec<(NcString, NcString, bool)>,
+    max_display: u32,
+    channels: [NcChannels; 5],
+}
+
+impl NcMultiSelectorBuilder {
+    /// New `NcMultiSelectorBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an item, and whether it's initially `selected`.
+    pub fn item(mut self, o: &str, d: &str, selected: bool) -> Self {
+        self.items
+            .push((NcString::new(o), NcString::new(d), selected));
+        self
+    }
+
+    /// Adds several items, as tuples of option, description and whether
+    /// it's initially selected.
+    pub fn items<'s>(mut self, items: impl IntoIterator<Item = (&'s str, &'s str, bool)>) -> Self {
+        self.items.extend(
+            items
+                .into_iter()
+                .map(|(o, d, s)| (NcString::new(o), NcString::new(d), s)),
+        );
+        self
+    }
+
+    /// Selects the maximum number of items to display at once.
+    ///
+    /// 0 uses all available space.
+    pub fn max_display(mut self, max: u32) -> Self {
+        self.max_display = max;
+        self
+    }
+
+    /// Sets the title string.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(NcString::new(title));
+        self
+    }
+
+    /// Sets the secondary title string.
+    pub fn secondary(mut self, secondary: &str) -> Self {
+        self.secondary = Some(NcString::new(secondary));
+        self
+    }
+
+    /// Sets the footer string.
+    pub fn footer(mut self, footer: &str) -> Self {
+        self.footer = Some(NcString::new(footer));
+        self
+    }
+
+    /// Sets all the `NcChannels`.
+    pub fn all_channels(
+        mut self,
+        item_opt: impl Into<NcChannels>,
+        item_desc: impl Into<NcChannels>,
+        seltitle: impl Into<NcChannels>,
+        selfooter: impl Into<NcChannels>,
+        selbox: impl Into<NcChannels>,
+    ) -> Self {
+        self.channels = [
+            item_opt.into(),
+            item_desc.into(),
+            seltitle.into(),
+            selfooter.into(),
+            selbox.into(),
+        ];
+        self
+    }
+
+    /// Sets the `NcChannels` for the item.
+    pub fn item_channels(
+        mut self,
+        opt: impl Into<NcChannels>,
+        desc: impl Into<NcChannels>,
+    ) -> Self {
+        self.channels[0] = opt.into();
+        self.channels[1] = desc.into();
+        self
+    }
+
+    /// Sets the `NcChannels` for the title.
+    pub fn title_channels(mut self, title: impl Into<NcChannels>) -> Self {
+        self.channels[2] = title.into();
+        self
+    }
+
+    /// Sets the `NcChannels` for the secondary title and the footer.
+    pub fn secondary_channels(mut self, secondary: impl Into<NcChannels>) -> Self {
+        self.channels[3] = secondary.into();
+        self
+    }
+
+    /// Sets the `NcChannels` for the box title.
+    pub fn box_channels(mut self, r#box: impl Into<NcChannels>) -> Self {
+        self.channels[4] = r#box.into();
+        self
+    }
+
+    /// Finishes the builder and returns the `NcMultiSelector`.
+    pub fn finish(self, plane: &mut NcPlane) -> NcResult<&mut NcMultiSelector> {
+        let mut items: Vec<NcMultiSelectorItem> = self
+            .items
+            .iter()
+            .map(|(o, d, s)| NcMultiSelectorItem::new(o, d, *s))
+            .collect();
+        items.push(NcMultiSelectorItem::new_empty());
+
+        let ptr = |s: &Option<NcString>| s.as_ref().map_or(null(), NcString::as_ptr);
+        let options = NcMultiSelectorOptions {
+            title: ptr(&self.title),
+            secondary: ptr(&self.secondary),
+            footer: ptr(&self.footer),
+            items: items.as_ptr(),
+            maxdisplay: self.max_display,
+            opchannels: self.channels[0].into(),
+            descchannels: self.channels[1].into(),
+            titlechannels: self.channels[2].into(),
+            footchannels: self.channels[3].into(),
+            boxchannels: self.channels[4].into(),
+            flags: 0,
+        };
+        NcMultiSelector::new(plane, &options)
+    }
+}
@@ -0,0 +1,131 @@
+use core::ptr::null;
+
+use crate::{
+    c_api, error_ref_mut,
+    widgets::{
+        NcMultiSelector, NcMultiSelectorBuilder, NcMultiSelectorItem, NcMultiSelectorOptions,
+    },
+    NcInput, NcPlane, NcResult, NcString,
+};
+
+#[cfg(feature = "std")]
+use crate::NcError;
+
+/// # `NcMultiSelector` constructors & destructors
+impl NcMultiSelector {
+    /// Creates a multiselector over a `plane`.
+    ///
+    /// The multiselector will take care of destroying the plane.
+    ///
+    /// *C style function: [ncmultiselector_create()][c_api::ncmultiselector_create].*
+    pub fn new<'a>(
+        plane: &mut NcPlane,
+        options: &NcMultiSelectorOptions,
+    ) -> NcResult<&'a mut Self> {
+        let res = unsafe { c_api::ncmultiselector_create(plane, options) };
+        // notcurses doesn't expose the number of items, needed by `selections`
+        #[cfg(feature = "std")]
+        if !res.is_null() {
+            let mut count = 0;
+            if !options.items.is_null() {
+                while !unsafe { (*options.items.add(count as usize)).option.is_null() } {
+                    count += 1;
+                }
+            }
+            crate::plane::side_table::with(unsafe { c_api::ncmultiselector_plane(res) }, |d| {
+                d.selector_items = Some(count)
+            });
+        }
+        error_ref_mut![res, "NcMultiSelector::new()"]
+    }
+
+    /// Starts the builder.
+    pub fn builder() -> NcMultiSelectorBuilder {
+        NcMultiSelectorBuilder::new()
+    }
+
+    /// Destroys the `NcMultiSelector`.
+    ///
+    /// Note that this also destroys the [`NcPlane`].
+    ///
+    /// *C style function: [ncmultiselector_destroy()][c_api::ncmultiselector_destroy].*
+    pub fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncmultiselector_plane(self) });
+        unsafe { c_api::ncmultiselector_destroy(self) };
+    }
+}
+
+/// # `NcMultiSelector` methods
+impl NcMultiSelector {
+    /// Offers an input to the multiselector.
+    ///
+    /// If it's relevant, this function returns true, and the input ought not be
+    /// processed further. If it's irrelevant to the multiselector, false is
+    /// returned.
+    ///
+    /// Relevant inputs include:
+    /// - a mouse click on an item.
+    /// - a mouse scrollwheel event.
+    /// - a mouse click on the scrolling arrows.
+    /// - up, down, pgup, or pgdown (navigates among items).
+    /// - space (toggles the selection of the current item).
+    ///
+    /// *C style function: [ncmultiselector_offer_input()][c_api::ncmultiselector_offer_input].*
+    pub fn offer_input(&mut self, input: impl Into<NcInput>) -> bool {
+        unsafe { c_api::ncmultiselector_offer_input(self, &input.into()) }
+    }
+
+    /// Returns whether each item is selected, in order.
+    ///
+    /// The number of items is the one it was created with by [`new`], or by
+    /// the [builder]. It's an error if it was created some other way.
+    ///
+    /// *C style function: [ncmultiselector_selected()][c_api::ncmultiselector_selected].*
+    ///
+    /// [`new`]: NcMultiSelector#method.new
+    /// [builder]: NcMultiSelector#method.builder
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn selections(&mut self) -> NcResult<Vec<bool>> {
+        let plane = unsafe { c_api::ncmultiselector_plane(self) };
+        let count = crate::plane::side_table::with(plane, |d| d.selector_items)
+            .ok_or_else(|| NcError::new_msg("NcMultiSelector.selections(): unknown items"))?;
+        let mut selected = vec![false; count as usize];
+        let res = unsafe { c_api::ncmultiselector_selected(self, selected.as_mut_ptr(), count) };
+        if res >= 0 {
+            Ok(selected)
+        } else {
+            Err(NcError::with_msg(res, "NcMultiSelector.selections()"))
+        }
+    }
+
+    /// Returns the indices of the selected items.
+    ///
+    /// See [`selections`][NcMultiSelector#method.selections].
+    ///
+    /// *C style function: [ncmultiselector_selected()][c_api::ncmultiselector_selected].*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn selected_indices(&mut self) -> NcResult<Vec<usize>> {
+        Ok(self
+            .selections()?
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, selected)| selected.then_some(i))
+            .collect())
+    }
+}
+
+/// # `NcMultiSelectorItem` constructors
+impl NcMultiSelectorItem {
+    /// New item, which borrows the `option` and the `desc`ription.
+    pub fn new(option: &NcString, desc: &NcString, selected: bool) -> Self {
+        Self { option: option.as_ptr(), desc: desc.as_ptr(), selected }
+    }
+
+    /// New empty `NcMultiSelectorItem`, which terminates the list of items.
+    pub fn new_empty() -> Self {
+        Self { option: null(), desc: null(), selected: false }
+    }
+}
@@ -2,7 +2,15 @@
 
 use crate::c_api::ffi;
 
+mod builder;
+mod methods;
+
+pub use builder::NcMultiSelectorBuilder;
+
 /// high-level widget for selecting items from a set
+///
+/// Unlike [`NcSelector`][crate::widgets::NcSelector], zero to all of the
+/// items can be selected, but items can't be added or removed at runtime.
 pub type NcMultiSelector = ffi::ncmultiselector;
 
 /// an item for [`NcMultiSelector`]
@@ -0,0 +1,193 @@
+use core::ptr::null;
+
+use crate::{
+    widgets::{NcPlotF64, NcPlotOptions, NcPlotU64},
+    NcBlitter, NcChannels, NcPlane, NcResult, NcString, NcStyle,
+};
+
+/// A handy builder for [`NcPlotU64`] and [`NcPlotF64`].
+///
+/// It owns the title, which notcurses copies when the plot is created.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let plane = NcPlane::new_child_sized(unsafe { nc.stdplane() }, 0, 0, 10, 60)?;
+/// let plot = NcPlotOptions::builder()
+///     .title("requests/s")
+///     .channels(0xff0000, 0x00ff00)
+///     .blitter(NcBlitter::Braille)
+///     .label_ticks(true)
+///     .range_x(120)
+///     .build_u64(plane)?;
+/// plot.add_sample(0, 42)?;
+/// // …
+/// plot.destroy();
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NcPlotBuilder {
+    max_channels: NcChannels,
+    min_channels: NcChannels,
+    legend_style: NcStyle,
+    blitter: NcBlitter,
+    range_x: u32,
+    title: Option<NcString>,
+    flags: u64,
+    range_u64: (u64, u64),
+    range_f64: (f64, f64),
+}
+
+impl NcPlotBuilder {
+    /// New `NcPlotBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `NcChannels` for the maximum and minimum levels.
+    ///
+    /// Linear or exponential interpolation is applied across the domain
+    /// between these two.
+    pub fn channels(mut self, max: impl Into<NcChannels>, min: impl Into<NcChannels>) -> Self {
+        self.max_channels = max.into();
+        self.min_channels = min.into();
+        self
+    }
+
+    /// Sets the style of the legend, shown if [`label_ticks`] is set.
+    ///
+    /// [`label_ticks`]: NcPlotBuilder#method.label_ticks
+    pub fn legend_style(mut self, style: impl Into<NcStyle>) -> Self {
+        self.legend_style = style.into();
+        self
+    }
+
+    /// Sets the blitter, which determines the number of "pixels" per cell.
+    pub fn blitter(mut self, blitter: impl Into<NcBlitter>) -> Self {
+        self.blitter = blitter.into();
+        self
+    }
+
+    /// Sets the length of the range of the independent variable.
+    ///
+    /// E.g. for the previous hour sampled with second resolution, it'd be
+    /// 3600. 0 sets it dynamically to the number of columns.
+    pub fn range_x(mut self, len: u32) -> Self {
+        self.range_x = len;
+        self
+    }
+
+    /// Sets the domain of the dependent variable for [`build_u64`].
+    ///
+    /// Both 0 (the default) autodiscovers it.
+    ///
+    /// [`build_u64`]: NcPlotBuilder#method.build_u64
+    pub fn range_u64(mut self, min: u64, max: u64) -> Self {
+        self.range_u64 = (min, max);
+        self
+    }
+
+    /// Sets the domain of the dependent variable for [`build_f64`].
+    ///
+    /// Both 0 (the default) autodiscovers it.
+    ///
+    /// [`build_f64`]: NcPlotBuilder#method.build_f64
+    pub fn range_f64(mut self, min: f64, max: f64) -> Self {
+        self.range_f64 = (min, max);
+        self
+    }
+
+    /// Sets the title, printed by the labels.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(NcString::new(title));
+        self
+    }
+
+    /// Sets the flags.
+    pub fn flags(mut self, flags: u64) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// If `true`, shows labels for the dependent axis.
+    ///
+    /// See [`NcPlotOptions::LABELTICKSD`].
+    pub fn label_ticks(self, label: bool) -> Self {
+        self.flag(NcPlotOptions::LABELTICKSD, label)
+    }
+
+    /// If `true`, the dependent axis is exponential.
+    ///
+    /// See [`NcPlotOptions::EXPONENTIALD`].
+    pub fn exponential(self, exponential: bool) -> Self {
+        self.flag(NcPlotOptions::EXPONENTIALD, exponential)
+    }
+
+    /// If `true`, the independent axis is vertical.
+    ///
+    /// See [`NcPlotOptions::VERTICALI`].
+    pub fn vertical(self, vertical: bool) -> Self {
+        self.flag(NcPlotOptions::VERTICALI, vertical)
+    }
+
+    /// If `true`, doesn't degrade the blitter when it's not supported.
+    ///
+    /// See [`NcPlotOptions::NODEGRADE`].
+    pub fn no_degrade(self, no_degrade: bool) -> Self {
+        self.flag(NcPlotOptions::NODEGRADE, no_degrade)
+    }
+
+    /// If `true`, uses domain detection only for the maximum.
+    ///
+    /// See [`NcPlotOptions::DETECTMAXONLY`].
+    pub fn detect_max_only(self, max_only: bool) -> Self {
+        self.flag(NcPlotOptions::DETECTMAXONLY, max_only)
+    }
+
+    /// If `true`, prints the most recent sample.
+    ///
+    /// See [`NcPlotOptions::PRINTSAMPLE`].
+    pub fn print_sample(self, print: bool) -> Self {
+        self.flag(NcPlotOptions::PRINTSAMPLE, print)
+    }
+
+    /// Finishes the builder and returns the `NcPlotOptions`.
+    ///
+    /// The options borrow the title, so they must not outlive the builder.
+    fn options(&self) -> NcPlotOptions {
+        NcPlotOptions {
+            maxchannels: self.max_channels.into(),
+            minchannels: self.min_channels.into(),
+            legendstyle: self.legend_style.into(),
+            gridtype: self.blitter.into(),
+            rangex: self.range_x as i32,
+            title: self.title.as_ref().map_or(null(), NcString::as_ptr),
+            flags: self.flags,
+        }
+    }
+
+    /// Finishes the builder and returns an [`NcPlotU64`] over the `plane`.
+    pub fn build_u64<'a>(self, plane: &mut NcPlane) -> NcResult<&'a mut NcPlotU64> {
+        let (min, max) = self.range_u64;
+        NcPlotU64::new(plane, &self.options(), min, max)
+    }
+
+    /// Finishes the builder and returns an [`NcPlotF64`] over the `plane`.
+    pub fn build_f64<'a>(self, plane: &mut NcPlane) -> NcResult<&'a mut NcPlotF64> {
+        let (min, max) = self.range_f64;
+        NcPlotF64::new(plane, &self.options(), min, max)
+    }
+
+    fn flag(mut self, flag: u32, set: bool) -> Self {
+        if set {
+            self.flags |= flag as u64;
+        } else {
+            self.flags &= !(flag as u64);
+        }
+        self
+    }
+}
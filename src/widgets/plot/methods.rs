@@ -0,0 +1,281 @@
+//! `NcPlot*` methods and associated functions.
+
+use core::{fmt::Display, ops::RangeInclusive};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+use crate::{
+    c_api::{self, NcResult_i32},
+    error, error_ref_mut,
+    widgets::{plot::window::window_around, NcPlotBuilder, NcPlotF64, NcPlotOptions, NcPlotU64},
+    NcError, NcPlane, NcResult,
+};
+
+/// # `NcPlotOptions` constructors
+impl NcPlotOptions {
+    /// Starts the builder.
+    pub fn builder() -> NcPlotBuilder {
+        NcPlotBuilder::new()
+    }
+}
+
+/// # `NcPlotU64` constructors & destructors
+impl NcPlotU64 {
+    /// Creates a plot over the `plane`, with a dependent variable domain
+    /// between `min_y` and `max_y`.
+    ///
+    /// For domain autodiscovery, set both `min_y` and `max_y` to 0.
+    ///
+    /// The plot will make free use of the entirety of the plane,
+    /// and will take care of destroying it.
+    ///
+    /// *C style function: [ncuplot_create()][c_api::ncuplot_create].*
+    pub fn new<'a>(
+        plane: &mut NcPlane,
+        options: &NcPlotOptions,
+        min_y: u64,
+        max_y: u64,
+    ) -> NcResult<&'a mut Self> {
+        error_ref_mut![
+            unsafe { c_api::ncuplot_create(plane, options, min_y, max_y) },
+            &format!["NcPlotU64::new(plane, options, {}, {})", min_y, max_y]
+        ]
+    }
+
+    /// Destroys the plot, and its plane.
+    ///
+    /// *C style function: [ncuplot_destroy()][c_api::ncuplot_destroy].*
+    pub fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncuplot_plane(self) });
+        unsafe { c_api::ncuplot_destroy(self) }
+    }
+}
+
+/// # `NcPlotU64` methods
+impl NcPlotU64 {
+    /// Adds `y` to the sample at `x`.
+    ///
+    /// *C style function: [ncuplot_add_sample()][c_api::ncuplot_add_sample].*
+    pub fn add_sample(&mut self, x: u64, y: u64) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncuplot_add_sample(self, x, y) },
+            &format!["NcPlotU64.add_sample({}, {})", x, y]
+        ]
+    }
+
+    /// Replaces the sample at `x` with `y`.
+    ///
+    /// *C style function: [ncuplot_set_sample()][c_api::ncuplot_set_sample].*
+    pub fn set_sample(&mut self, x: u64, y: u64) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncuplot_set_sample(self, x, y) },
+            &format!["NcPlotU64.set_sample({}, {})", x, y]
+        ]
+    }
+
+    /// Adds each `(x, y)` pair of `samples`, in order.
+    ///
+    /// A failing sample doesn't stop the rest from being added. In that case
+    /// the error message lists the indices of the samples that failed.
+    ///
+    /// *C style function: [ncuplot_add_sample()][c_api::ncuplot_add_sample].*
+    pub fn add_samples(&mut self, samples: &[(u64, u64)]) -> NcResult<()> {
+        apply_samples("NcPlotU64.add_samples", samples, |x, y| unsafe {
+            c_api::ncuplot_add_sample(self, x, y)
+        })
+    }
+
+    /// Replaces the sample at each `x` with its `y`, in order.
+    ///
+    /// A failing sample doesn't stop the rest from being set. In that case
+    /// the error message lists the indices of the samples that failed.
+    ///
+    /// *C style function: [ncuplot_set_sample()][c_api::ncuplot_set_sample].*
+    pub fn set_samples(&mut self, samples: &[(u64, u64)]) -> NcResult<()> {
+        apply_samples("NcPlotU64.set_samples", samples, |x, y| unsafe {
+            c_api::ncuplot_set_sample(self, x, y)
+        })
+    }
+
+    /// Returns the sample at `x`.
+    ///
+    /// Errors if `x` is outside the currently displayed x-window.
+    ///
+    /// *C style function: [ncuplot_sample()][c_api::ncuplot_sample].*
+    pub fn sample(&self, x: u64) -> NcResult<u64> {
+        let mut y = 0;
+        error![
+            unsafe { c_api::ncuplot_sample(self, x, &mut y) },
+            &format!["NcPlotU64.sample({})", x],
+            y
+        ]
+    }
+
+    /// Returns the range of x values currently displayed, provided `near`
+    /// is one of them, e.g. the last x a sample was added at.
+    ///
+    /// It takes a logarithmic number of calls to [`sample`][Self#method.sample].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn x_window(&self, near: u64) -> Option<RangeInclusive<u64>> {
+        window_around(near, |x| self.sample(x).is_ok())
+    }
+
+    /// Slides the x-window forward until it includes `x`,
+    /// without modifying any sample.
+    ///
+    /// The samples that fall out of the window are discarded. Errors if `x`
+    /// is below the window, since it can't slide backwards.
+    ///
+    /// *C style function: [ncuplot_add_sample()][c_api::ncuplot_add_sample].*
+    pub fn slide_to(&mut self, x: u64) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncuplot_add_sample(self, x, 0) },
+            &format!["NcPlotU64.slide_to({})", x]
+        ]
+    }
+}
+
+/// # `NcPlotF64` constructors & destructors
+impl NcPlotF64 {
+    /// Creates a plot over the `plane`, with a dependent variable domain
+    /// between `min_y` and `max_y`.
+    ///
+    /// For domain autodiscovery, set both `min_y` and `max_y` to 0.
+    ///
+    /// The plot will make free use of the entirety of the plane,
+    /// and will take care of destroying it.
+    ///
+    /// *C style function: [ncdplot_create()][c_api::ncdplot_create].*
+    pub fn new<'a>(
+        plane: &mut NcPlane,
+        options: &NcPlotOptions,
+        min_y: f64,
+        max_y: f64,
+    ) -> NcResult<&'a mut Self> {
+        error_ref_mut![
+            unsafe { c_api::ncdplot_create(plane, options, min_y, max_y) },
+            &format!["NcPlotF64::new(plane, options, {}, {})", min_y, max_y]
+        ]
+    }
+
+    /// Destroys the plot, and its plane.
+    ///
+    /// *C style function: [ncdplot_destroy()][c_api::ncdplot_destroy].*
+    pub fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncdplot_plane(self) });
+        unsafe { c_api::ncdplot_destroy(self) }
+    }
+}
+
+/// # `NcPlotF64` methods
+impl NcPlotF64 {
+    /// Adds `y` to the sample at `x`.
+    ///
+    /// *C style function: [ncdplot_add_sample()][c_api::ncdplot_add_sample].*
+    pub fn add_sample(&mut self, x: u64, y: f64) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncdplot_add_sample(self, x, y) },
+            &format!["NcPlotF64.add_sample({}, {})", x, y]
+        ]
+    }
+
+    /// Replaces the sample at `x` with `y`.
+    ///
+    /// *C style function: [ncdplot_set_sample()][c_api::ncdplot_set_sample].*
+    pub fn set_sample(&mut self, x: u64, y: f64) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncdplot_set_sample(self, x, y) },
+            &format!["NcPlotF64.set_sample({}, {})", x, y]
+        ]
+    }
+
+    /// Adds each `(x, y)` pair of `samples`, in order.
+    ///
+    /// A failing sample doesn't stop the rest from being added. In that case
+    /// the error message lists the indices of the samples that failed.
+    ///
+    /// *C style function: [ncdplot_add_sample()][c_api::ncdplot_add_sample].*
+    pub fn add_samples(&mut self, samples: &[(u64, f64)]) -> NcResult<()> {
+        apply_samples("NcPlotF64.add_samples", samples, |x, y| unsafe {
+            c_api::ncdplot_add_sample(self, x, y)
+        })
+    }
+
+    /// Replaces the sample at each `x` with its `y`, in order.
+    ///
+    /// A failing sample doesn't stop the rest from being set. In that case
+    /// the error message lists the indices of the samples that failed.
+    ///
+    /// *C style function: [ncdplot_set_sample()][c_api::ncdplot_set_sample].*
+    pub fn set_samples(&mut self, samples: &[(u64, f64)]) -> NcResult<()> {
+        apply_samples("NcPlotF64.set_samples", samples, |x, y| unsafe {
+            c_api::ncdplot_set_sample(self, x, y)
+        })
+    }
+
+    /// Returns the sample at `x`.
+    ///
+    /// Errors if `x` is outside the currently displayed x-window.
+    ///
+    /// *C style function: [ncdplot_sample()][c_api::ncdplot_sample].*
+    pub fn sample(&self, x: u64) -> NcResult<f64> {
+        let mut y = 0.0;
+        error![
+            unsafe { c_api::ncdplot_sample(self, x, &mut y) },
+            &format!["NcPlotF64.sample({})", x],
+            y
+        ]
+    }
+
+    /// Returns the range of x values currently displayed, provided `near`
+    /// is one of them, e.g. the last x a sample was added at.
+    ///
+    /// It takes a logarithmic number of calls to [`sample`][Self#method.sample].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn x_window(&self, near: u64) -> Option<RangeInclusive<u64>> {
+        window_around(near, |x| self.sample(x).is_ok())
+    }
+
+    /// Slides the x-window forward until it includes `x`,
+    /// without modifying any sample.
+    ///
+    /// The samples that fall out of the window are discarded. Errors if `x`
+    /// is below the window, since it can't slide backwards.
+    ///
+    /// *C style function: [ncdplot_add_sample()][c_api::ncdplot_add_sample].*
+    pub fn slide_to(&mut self, x: u64) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncdplot_add_sample(self, x, 0.0) },
+            &format!["NcPlotF64.slide_to({})", x]
+        ]
+    }
+}
+
+/// Calls `f` for every sample, collecting the indices of the failed ones.
+fn apply_samples<T: Copy + Display>(
+    name: &str,
+    samples: &[(u64, T)],
+    mut f: impl FnMut(u64, T) -> NcResult_i32,
+) -> NcResult<()> {
+    let failed: Vec<usize> = samples
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &(x, y))| (f(x, y) < 0).then_some(i))
+        .collect();
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(NcError::new_msg(&format![
+            "{}(): {} of {} samples failed, at indices {:?}",
+            name,
+            failed.len(),
+            samples.len(),
+            failed
+        ]))
+    }
+}
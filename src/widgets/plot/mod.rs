@@ -2,6 +2,12 @@
 
 use crate::c_api::ffi;
 
+mod builder;
+mod methods;
+mod window;
+
+pub use builder::NcPlotBuilder;
+
 /// A histogram, bound to an [`NcPlane`][crate::NcPlane]
 /// (uses non-negative `f64`s)
 pub type NcPlotF64 = ffi::ncdplot;
@@ -28,6 +34,9 @@ impl NcPlotOptions {
 
     /// Independent axis is vertical
     pub const VERTICALI: u32 = c_api::NCPLOT_OPTION_VERTICALI;
+
+    /// Prints the most recent sample
+    pub const PRINTSAMPLE: u32 = c_api::NCPLOT_OPTION_PRINTSAMPLE;
 }
 
 pub(crate) mod c_api {
@@ -47,4 +56,7 @@ pub(crate) mod c_api {
 
     /// Independent axis is vertical
     pub const NCPLOT_OPTION_VERTICALI: u32 = ffi::NCPLOT_OPTION_VERTICALI;
+
+    /// Prints the most recent sample
+    pub const NCPLOT_OPTION_PRINTSAMPLE: u32 = ffi::NCPLOT_OPTION_PRINTSAMPLE;
 }
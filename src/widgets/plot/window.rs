@@ -0,0 +1,67 @@
+//! Discovery of the x-window displayed by a plot.
+
+use core::ops::RangeInclusive;
+
+/// Returns the contiguous range of x values around `near` accepted by
+/// `contains`, or `None` if `near` itself isn't accepted.
+///
+/// It gallops away from `near` in both directions and then bisects, so it
+/// needs a logarithmic number of calls to `contains`.
+pub(crate) fn window_around(
+    near: u64,
+    contains: impl Fn(u64) -> bool,
+) -> Option<RangeInclusive<u64>> {
+    if !contains(near) {
+        return None;
+    }
+
+    let hi = near + reach(u64::MAX - near, |o| contains(near + o));
+    let lo = near - reach(near, |o| contains(near - o));
+
+    Some(lo..=hi)
+}
+
+/// Returns the largest offset up to `limit` accepted by `accepts`, which must
+/// accept 0 and keep accepting until it doesn't.
+fn reach(limit: u64, accepts: impl Fn(u64) -> bool) -> u64 {
+    // the last accepted and the first rejected offsets
+    let (mut ok, mut bad) = (0, u128::from(limit) + 1);
+    let mut step = 1_u64;
+    while ok < limit {
+        let probe = step.min(limit);
+        if !accepts(probe) {
+            bad = probe.into();
+            break;
+        }
+        ok = probe;
+        step = step.saturating_mul(2);
+    }
+    while bad - u128::from(ok) > 1 {
+        let mid = ok + ((bad - u128::from(ok)) / 2) as u64;
+        if accepts(mid) {
+            ok = mid;
+        } else {
+            bad = mid.into();
+        }
+    }
+    ok
+}
+
+#[cfg(test)]
+mod test {
+    use super::window_around;
+
+    #[test]
+    fn window() {
+        let w = |lo: u64, hi: u64, near| window_around(near, |x| (lo..=hi).contains(&x));
+        assert_eq![w(51, 100, 100), Some(51..=100)];
+        assert_eq![w(51, 100, 70), Some(51..=100)];
+        assert_eq![w(0, 0, 0), Some(0..=0)];
+        assert_eq![w(0, 37, 5), Some(0..=37)];
+        assert_eq![w(10, 20, 30), None];
+        assert_eq![
+            w(u64::MAX - 3, u64::MAX, u64::MAX),
+            Some(u64::MAX - 3..=u64::MAX)
+        ];
+    }
+}
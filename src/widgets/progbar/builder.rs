@@ -0,0 +1,112 @@
+//! `NcProgBarOptionsBuilder`.
+
+use crate::{
+    widgets::{NcProgBar, NcProgBarOptions},
+    NcChannel, NcPlane, NcResult,
+};
+
+/// Builder object for [`NcProgBarOptions`].
+///
+/// Can be constructed by calling [`NcProgBarOptions::builder()`].
+///
+/// [`NcProgBarOptions::builder()`]: NcProgBarOptions#method.builder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NcProgBarOptionsBuilder {
+    pub(crate) channels: [NcChannel; 4],
+    pub(crate) retrograde: bool,
+}
+
+mod core_impls {
+    use super::{NcProgBarOptions, NcProgBarOptionsBuilder};
+
+    impl From<NcProgBarOptionsBuilder> for NcProgBarOptions {
+        fn from(builder: NcProgBarOptionsBuilder) -> NcProgBarOptions {
+            builder.build()
+        }
+    }
+    impl From<&NcProgBarOptionsBuilder> for NcProgBarOptions {
+        fn from(builder: &NcProgBarOptionsBuilder) -> Self {
+            builder.build()
+        }
+    }
+    impl From<NcProgBarOptions> for NcProgBarOptionsBuilder {
+        fn from(options: NcProgBarOptions) -> NcProgBarOptionsBuilder {
+            Self::from_options(&options)
+        }
+    }
+}
+
+/// # Constructors
+impl NcProgBarOptionsBuilder {
+    /// New `NcProgBarOptionsBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New builder from pre-existing options.
+    pub fn from_options(options: &NcProgBarOptions) -> Self {
+        Self {
+            channels: [
+                options.ulchannel.into(),
+                options.urchannel.into(),
+                options.blchannel.into(),
+                options.brchannel.into(),
+            ],
+            retrograde: options.is_retrograde(),
+        }
+    }
+
+    /// Finishes the building and returns [`NcProgBarOptions`].
+    pub fn build(&self) -> NcProgBarOptions {
+        let mut options = NcProgBarOptions {
+            ulchannel: self.channels[0].into(),
+            urchannel: self.channels[1].into(),
+            blchannel: self.channels[2].into(),
+            brchannel: self.channels[3].into(),
+            flags: 0,
+        };
+        options.set_retrograde(self.retrograde);
+        options
+    }
+
+    /// Finishes the building and returns a new [`NcProgBar`] over the `plane`.
+    pub fn finish<'a>(&self, plane: &mut NcPlane) -> NcResult<&'a mut NcProgBar> {
+        NcProgBar::with_options(plane, &self.build())
+    }
+}
+
+/// # Methods (chainable)
+impl NcProgBarOptionsBuilder {
+    /// Sets the `NcChannel` of the four corners, which get interpolated
+    /// across the bar.
+    ///
+    /// Default: *`0, 0, 0, 0`*.
+    pub fn channels(
+        mut self,
+        ul: impl Into<NcChannel>,
+        ur: impl Into<NcChannel>,
+        bl: impl Into<NcChannel>,
+        br: impl Into<NcChannel>,
+    ) -> Self {
+        self.channels = [ul.into(), ur.into(), bl.into(), br.into()];
+        self
+    }
+
+    /// Sets the same `NcChannel` for the four corners.
+    ///
+    /// Default: *`0`*.
+    pub fn all_channels(mut self, channels: impl Into<NcChannel>) -> Self {
+        self.channels = [channels.into(); 4];
+        self
+    }
+
+    /// If `true`, the bar proceeds left/down.
+    ///
+    /// Default: *false*.
+    ///
+    /// See [`NcProgBarOptions::RETROGRADE`].
+    pub fn retrograde(mut self, retrograde: bool) -> Self {
+        self.retrograde = retrograde;
+        self
+    }
+}
@@ -1,7 +1,10 @@
 //! `NcProgBar` & `NcProgBarOptions` methods and associated functions.
 
-use super::{NcProgBar, NcProgBarOptions};
-use crate::{c_api, error, NcPlane, NcResult};
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use super::{NcProgBar, NcProgBarOptions, NcProgBarOptionsBuilder};
+use crate::{c_api, error, error_ref_mut, NcPlane, NcResult};
 
 /// # `NcProgBarOptions` Methods
 impl NcProgBarOptions {
@@ -9,6 +12,29 @@ impl NcProgBarOptions {
     pub fn new() -> Self {
         Self { ulchannel: 0, urchannel: 0, blchannel: 0, brchannel: 0, flags: 0 }
     }
+
+    /// Returns a builder object for `NcProgBarOptions`.
+    pub fn builder() -> NcProgBarOptionsBuilder {
+        NcProgBarOptionsBuilder::new()
+    }
+
+    /// Returns `true` if it has the [`RETROGRADE`] flag set.
+    ///
+    /// [`RETROGRADE`]: NcProgBarOptions#associatedconstant.RETROGRADE
+    pub fn is_retrograde(&self) -> bool {
+        self.flags & Self::RETROGRADE as u64 != 0
+    }
+
+    /// Adds or removes the [`RETROGRADE`] flag.
+    ///
+    /// [`RETROGRADE`]: NcProgBarOptions#associatedconstant.RETROGRADE
+    pub fn set_retrograde(&mut self, retrograde: bool) {
+        if retrograde {
+            self.flags |= Self::RETROGRADE as u64;
+        } else {
+            self.flags &= !(Self::RETROGRADE as u64);
+        }
+    }
 }
 
 /// # `NcProgBar` Methods
@@ -17,21 +43,29 @@ impl NcProgBar {
     ///
     /// Takes ownership of the `plane`, which will be destroyed by
     /// [destroy][NcProgBar#method.destroy](). The progress bar is initially at 0%.
-    pub fn new<'a>(plane: &mut NcPlane) -> &'a mut Self {
+    pub fn new<'a>(plane: &mut NcPlane) -> NcResult<&'a mut Self> {
         Self::with_options(plane, &NcProgBarOptions::new())
     }
 
     /// New NcProgBar. Expects an [NcProgBarOptions] struct.
     ///
     /// *C style function: [ncprogbar_create()][c_api::ncprogbar_create].*
-    pub fn with_options<'a>(plane: &mut NcPlane, options: &NcProgBarOptions) -> &'a mut Self {
-        unsafe { &mut *c_api::ncprogbar_create(plane, options) }
+    pub fn with_options<'a>(
+        plane: &mut NcPlane,
+        options: &NcProgBarOptions,
+    ) -> NcResult<&'a mut Self> {
+        error_ref_mut![
+            unsafe { c_api::ncprogbar_create(plane, options) },
+            "NcProgBar::with_options()"
+        ]
     }
 
     /// Destroy the progress bar and its underlying ncplane.
     ///
     /// *C style function: [ncprogbar_destroy()][c_api::ncprogbar_destroy].*
     pub fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncprogbar_plane(self) });
         unsafe {
             c_api::ncprogbar_destroy(self);
         }
@@ -58,6 +92,9 @@ impl NcProgBar {
     ///
     /// *C style function: [ncprogbar_set_progress()][c_api::ncprogbar_set_progress].*
     pub fn set_progress(&mut self, progress: f64) -> NcResult<()> {
-        error![unsafe { c_api::ncprogbar_set_progress(self, progress) }]
+        error![
+            unsafe { c_api::ncprogbar_set_progress(self, progress) },
+            &format!["NcProgBar.set_progress({})", progress]
+        ]
     }
 }
@@ -2,19 +2,25 @@
 
 // functions already exported by bindgen : 5
 // -----------------------------------------
-// (#) test: 0
+// (#) test: 4
 // (W) wrap: 5 / 0
 // -----------------------------------------
-//W ncprogbar_create,
-//W ncprogbar_destroy,
-//W ncprogbar_plane,
-//W ncprogbar_progress,
-//W ncprogbar_set_progress,
+//W# ncprogbar_create,
+//W# ncprogbar_destroy,
+//W  ncprogbar_plane,
+//W# ncprogbar_progress,
+//W# ncprogbar_set_progress,
 
 use crate::c_api::ffi;
 
+mod builder;
 mod methods;
 
+#[cfg(test)]
+mod test;
+
+pub use builder::NcProgBarOptionsBuilder;
+
 /// Progress bars. They proceed linearly in any of four directions.
 ///
 /// The entirety of the plane will be used -- any border should be provided by
@@ -0,0 +1,59 @@
+//! Test `NcProgBar` methods and associated functions.
+
+use crate::{
+    c_api::{ncplane_new_test, notcurses_init_test, notcurses_stop},
+    widgets::{NcProgBar, NcProgBarOptions, NcProgBarOptionsBuilder},
+    NcChannel,
+};
+use serial_test::serial;
+
+#[test]
+fn options_builder() {
+    let options = NcProgBarOptions::builder()
+        .channels(1, 2, 3, 4)
+        .retrograde(true)
+        .build();
+    assert_eq![
+        (1, 2, 3, 4),
+        (
+            options.ulchannel,
+            options.urchannel,
+            options.blchannel,
+            options.brchannel
+        )
+    ];
+    assert![options.is_retrograde()];
+
+    let builder = NcProgBarOptionsBuilder::from_options(&options).retrograde(false);
+    assert_eq![
+        [NcChannel(1), NcChannel(2), NcChannel(3), NcChannel(4)],
+        builder.channels
+    ];
+    assert![!builder.build().is_retrograde()];
+
+    let options: NcProgBarOptions = builder.all_channels(5).into();
+    assert_eq![(5, 5), (options.ulchannel, options.brchannel)];
+    assert_eq![0, options.flags];
+}
+
+#[test]
+#[serial]
+fn progress() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = ncplane_new_test(nc, 0, 0, 1, 20);
+
+        let progbar = NcProgBar::new(plane).unwrap();
+        assert_eq![0.0, progbar.progress()];
+
+        progbar.set_progress(0.5).unwrap();
+        assert_eq![0.5, progbar.progress()];
+
+        assert![progbar.set_progress(1.5).is_err()];
+        assert![progbar.set_progress(-0.1).is_err()];
+        assert_eq![0.5, progbar.progress()];
+
+        progbar.destroy();
+        notcurses_stop(nc);
+    }
+}
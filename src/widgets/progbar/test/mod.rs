@@ -0,0 +1,4 @@
+//! `NcProgBar` tests.
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,176 @@
+//! `NcReaderOptionsBuilder`.
+
+use crate::{
+    widgets::{NcReader, NcReaderOptions},
+    NcChannels, NcPlane, NcResult, NcStyle,
+};
+
+/// Builder object for [`NcReaderOptions`].
+///
+/// Can be constructed by calling [`NcReaderOptions::builder()`].
+///
+/// [`NcReaderOptions::builder()`]: NcReaderOptions#method.builder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NcReaderOptionsBuilder {
+    pub(crate) channels: NcChannels,
+    pub(crate) style: NcStyle,
+    pub(crate) flags: u64,
+}
+
+mod core_impls {
+    use super::{NcReaderOptions, NcReaderOptionsBuilder};
+
+    impl From<NcReaderOptionsBuilder> for NcReaderOptions {
+        fn from(builder: NcReaderOptionsBuilder) -> NcReaderOptions {
+            builder.build()
+        }
+    }
+    impl From<&NcReaderOptionsBuilder> for NcReaderOptions {
+        fn from(builder: &NcReaderOptionsBuilder) -> Self {
+            builder.build()
+        }
+    }
+    impl From<NcReaderOptions> for NcReaderOptionsBuilder {
+        fn from(options: NcReaderOptions) -> NcReaderOptionsBuilder {
+            Self::from_options(&options)
+        }
+    }
+}
+
+/// # Constructors
+impl NcReaderOptionsBuilder {
+    /// New `NcReaderOptionsBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New builder from pre-existing options.
+    pub fn from_options(options: &NcReaderOptions) -> Self {
+        Self {
+            channels: options.tchannels.into(),
+            style: (options.tattrword as u16).into(),
+            flags: options.flags,
+        }
+    }
+
+    /// Finishes the building and returns [`NcReaderOptions`].
+    pub fn build(&self) -> NcReaderOptions {
+        NcReaderOptions {
+            tchannels: self.channels.into(),
+            tattrword: u16::from(self.style) as u32,
+            flags: self.flags,
+        }
+    }
+
+    /// Finishes the building and returns a new [`NcReader`] over the `plane`.
+    pub fn finish<'a>(&self, plane: &mut NcPlane) -> NcResult<&'a mut NcReader> {
+        NcReader::with_options(plane, &self.build())
+    }
+}
+
+/// # Methods (chainable)
+impl NcReaderOptionsBuilder {
+    /// Sets the `NcChannels` used for input.
+    ///
+    /// Default: *`0`*.
+    pub fn channels(mut self, channels: impl Into<NcChannels>) -> Self {
+        self.channels = channels.into();
+        self
+    }
+
+    /// Sets the `NcStyle` used for input.
+    ///
+    /// Default: *[`NcStyle::None`]*.
+    pub fn style(mut self, style: impl Into<NcStyle>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// If `true`, enables horizontal scrolling.
+    ///
+    /// Default: *false*.
+    ///
+    /// See [`NcReaderOptions::HORSCROLL`].
+    pub fn horizontal_scroll(self, scroll: bool) -> Self {
+        self.flag(NcReaderOptions::HORSCROLL, scroll)
+    }
+
+    /// If `true`, enables vertical scrolling.
+    ///
+    /// Default: *false*.
+    ///
+    /// See [`NcReaderOptions::VERSCROLL`].
+    pub fn vertical_scroll(self, scroll: bool) -> Self {
+        self.flag(NcReaderOptions::VERSCROLL, scroll)
+    }
+
+    /// If `true`, enables both horizontal and vertical scrolling.
+    ///
+    /// Default: *false*.
+    pub fn scroll(self, scroll: bool) -> Self {
+        self.horizontal_scroll(scroll).vertical_scroll(scroll)
+    }
+
+    /// If `true`, makes the terminal cursor visible, managed by the reader.
+    ///
+    /// Default: *false*.
+    ///
+    /// See [`NcReaderOptions::CURSOR`].
+    pub fn cursor(self, cursor: bool) -> Self {
+        self.flag(NcReaderOptions::CURSOR, cursor)
+    }
+
+    /// If `true`, disables the emacs-style editing shortcuts.
+    ///
+    /// Default: *false*.
+    ///
+    /// See [`NcReaderOptions::NOCMDKEYS`].
+    pub fn no_cmd_keys(self, no_cmd_keys: bool) -> Self {
+        self.flag(NcReaderOptions::NOCMDKEYS, no_cmd_keys)
+    }
+
+    fn flag(mut self, flag: u32, set: bool) -> Self {
+        if set {
+            self.flags |= flag as u64;
+        } else {
+            self.flags &= !(flag as u64);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NcReaderOptionsBuilder;
+    use crate::{widgets::NcReaderOptions, NcStyle};
+
+    #[test]
+    fn reader_options_builder() {
+        let options = NcReaderOptions::builder()
+            .channels(0x1122334455667788_u64)
+            .style(NcStyle::Bold | NcStyle::Underline)
+            .scroll(true)
+            .cursor(true)
+            .build();
+        assert_eq![0x1122334455667788, options.tchannels];
+        assert_eq![
+            u16::from(NcStyle::Bold | NcStyle::Underline) as u32,
+            options.tattrword
+        ];
+        assert_eq![
+            (NcReaderOptions::HORSCROLL | NcReaderOptions::VERSCROLL | NcReaderOptions::CURSOR)
+                as u64,
+            options.flags
+        ];
+
+        let builder = NcReaderOptionsBuilder::from_options(&options)
+            .horizontal_scroll(false)
+            .no_cmd_keys(true);
+        assert_eq![NcStyle::Bold | NcStyle::Underline, builder.style];
+        assert_eq![
+            (NcReaderOptions::VERSCROLL | NcReaderOptions::CURSOR | NcReaderOptions::NOCMDKEYS)
+                as u64,
+            builder.build().flags
+        ];
+    }
+}
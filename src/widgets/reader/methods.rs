@@ -1,6 +1,6 @@
 //! `NcReader*` methods and associated functions.
 
-use super::{NcReader, NcReaderOptions};
+use super::{NcReader, NcReaderOptions, NcReaderOptionsBuilder};
 use crate::{c_api::ncreader_create, error_ref_mut, NcPlane, NcResult};
 
 /// # `NcReaderOptions` Constructors
@@ -16,6 +16,11 @@ impl NcReaderOptions {
             flags: 0,
         }
     }
+
+    /// Returns a builder object for `NcReaderOptions`.
+    pub fn builder() -> NcReaderOptionsBuilder {
+        NcReaderOptionsBuilder::new()
+    }
 }
 
 /// # `NcReader` Constructors
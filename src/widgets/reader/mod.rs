@@ -16,8 +16,11 @@
 
 use crate::c_api::ffi;
 
+mod builder;
 mod methods;
 
+pub use builder::NcReaderOptionsBuilder;
+
 /// Provides a freeform input in a (possibly multiline) region
 ///
 /// Supports optional readline keybindings (opt out using
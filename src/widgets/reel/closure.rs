@@ -0,0 +1,95 @@
+//! Side table of the tablets added with a draw closure by
+//! [`NcReel.add`][crate::widgets::NcReel#method.add].
+//!
+//! Each of these tablets owns a [`TabletSlot`], which is its user pointer, so
+//! that [`nctabletcb_closure`] can find its closure, and that it can hold
+//! typed data.
+
+use core::{any::Any, ffi::c_int};
+
+use crate::{
+    c_api::{self, NCRESULT_ERR},
+    error::catch_panic,
+    widgets::{NcReel, NcTablet},
+    NcResult,
+};
+use std::{boxed::Box, cell::RefCell, collections::BTreeMap, rc::Rc, vec::Vec};
+
+/// A tablet draw closure.
+pub(crate) type NcTabletClosure = Rc<RefCell<dyn FnMut(&mut NcTablet, bool) -> NcResult<u32>>>;
+
+/// What a tablet added with a closure points to.
+pub(crate) struct TabletSlot {
+    pub(crate) draw: NcTabletClosure,
+    pub(crate) data: Option<Box<dyn Any>>,
+}
+
+thread_local! {
+    /// The slots, keyed by their address, with the address of their reel.
+    static SLOTS: RefCell<BTreeMap<usize, (usize, *mut TabletSlot)>> =
+        RefCell::new(BTreeMap::new());
+}
+
+/// Returns a new slot for a tablet of the `reel`.
+pub(crate) fn new_slot(reel: *const NcReel, draw: NcTabletClosure) -> *mut TabletSlot {
+    let slot = Box::into_raw(Box::new(TabletSlot { draw, data: None }));
+    SLOTS.with(|s| s.borrow_mut().insert(slot as usize, (reel as usize, slot)));
+    slot
+}
+
+/// Frees the `slot`, if it's one.
+pub(crate) fn free_slot(slot: *mut TabletSlot) {
+    if let Some((_, slot)) = SLOTS.with(|s| s.borrow_mut().remove(&(slot as usize))) {
+        drop(unsafe { Box::from_raw(slot) });
+    }
+}
+
+/// Frees all the slots of the `reel`.
+pub(crate) fn free_reel(reel: *const NcReel) {
+    let slots: Vec<*mut TabletSlot> = SLOTS.with(|s| {
+        let mut s = s.borrow_mut();
+        let keys: Vec<usize> = s
+            .iter()
+            .filter(|(_, (r, _))| *r == reel as usize)
+            .map(|(k, _)| *k)
+            .collect();
+        keys.iter()
+            .filter_map(|k| s.remove(k))
+            .map(|(_, slot)| slot)
+            .collect()
+    });
+    for slot in slots {
+        drop(unsafe { Box::from_raw(slot) });
+    }
+}
+
+/// Returns the slot of the `tablet`, if it has one.
+pub(crate) fn slot(tablet: &mut NcTablet) -> Option<*mut TabletSlot> {
+    let ptr = unsafe { c_api::nctablet_userptr(tablet) } as usize;
+    SLOTS.with(|s| s.borrow().get(&ptr).map(|(_, slot)| *slot))
+}
+
+/// The C tablet callback that calls the closure of the tablet.
+///
+/// A closure that is already running is not called, and one that panics
+/// fails. The lines it returns are clamped to the rows of the tablet plane.
+pub(crate) unsafe extern "C" fn nctabletcb_closure(
+    tablet: *mut NcTablet,
+    drawfromtop: bool,
+) -> c_int {
+    let draw = match slot(&mut *tablet) {
+        Some(slot) => (*slot).draw.clone(),
+        None => return NCRESULT_ERR,
+    };
+    let res = match draw.try_borrow_mut() {
+        Ok(mut draw) => catch_panic("NcTablet draw", || draw(&mut *tablet, drawfromtop)),
+        Err(_) => return NCRESULT_ERR,
+    };
+    match res {
+        Ok(lines) => {
+            let rows = (*tablet).plane().map_or(0, |plane| plane.dim_y());
+            lines.min(rows) as c_int
+        }
+        Err(_) => NCRESULT_ERR,
+    }
+}
@@ -0,0 +1,261 @@
+//! `NcReel` & `NcTablet` methods and associated functions.
+
+use crate::{
+    c_api, error, error_ref_mut,
+    widgets::{NcReel, NcReelOptions, NcTablet},
+    NcInput, NcPlane, NcResult,
+};
+
+#[cfg(feature = "std")]
+use crate::widgets::reel::closure;
+#[cfg(feature = "std")]
+use {
+    crate::NcError,
+    core::ptr::null_mut,
+    std::{boxed::Box, cell::RefCell, rc::Rc},
+};
+
+/// # `NcReelOptions` constructors
+impl NcReelOptions {
+    /// New `NcReelOptions` with no borders masked, default channels
+    /// and no flags.
+    pub fn new() -> Self {
+        Self::with_flags(0)
+    }
+
+    /// New `NcReelOptions` with the provided `flags`.
+    ///
+    /// See [`CIRCULAR`] and [`INFINITESCROLL`].
+    ///
+    /// [`CIRCULAR`]: NcReelOptions#associatedconstant.CIRCULAR
+    /// [`INFINITESCROLL`]: NcReelOptions#associatedconstant.INFINITESCROLL
+    pub fn with_flags(flags: u32) -> Self {
+        Self {
+            bordermask: 0,
+            borderchan: 0,
+            tabletmask: 0,
+            tabletchan: 0,
+            focusedchan: 0,
+            flags: flags as u64,
+        }
+    }
+}
+
+/// # `NcReel` constructors & destructors
+impl NcReel {
+    /// Creates a reel over the `plane`.
+    ///
+    /// The reel takes ownership of the plane, which will be destroyed
+    /// by [`destroy`][NcReel#method.destroy].
+    ///
+    /// *C style function: [ncreel_create()][c_api::ncreel_create].*
+    pub fn new<'a>(plane: &mut NcPlane, options: &NcReelOptions) -> NcResult<&'a mut Self> {
+        error_ref_mut![
+            unsafe { c_api::ncreel_create(plane, options) },
+            "NcReel::new()"
+        ]
+    }
+
+    /// Destroys the reel, its tablets, and its plane.
+    ///
+    /// *C style function: [ncreel_destroy()][c_api::ncreel_destroy].*
+    pub fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncreel_plane(self) });
+        unsafe { c_api::ncreel_destroy(self) };
+        #[cfg(feature = "std")]
+        closure::free_reel(self);
+    }
+}
+
+/// # `NcReel` methods
+impl NcReel {
+    /// Adds a new tablet, after the `after` tablet or before the `before`
+    /// tablet, and returns it.
+    ///
+    /// If both are `None` it's placed after the focused tablet, or it becomes
+    /// the focused tablet if there's none.
+    ///
+    /// The `draw` closure receives the tablet and whether the output ought to
+    /// be drawn from the top, and returns the number of lines drawn, which
+    /// is clamped to the rows of the [plane][NcTablet#method.plane]
+    /// of the tablet. If it panics the reel fails to redraw.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use libnotcurses_sys::{*, widgets::*};
+    /// # fn main() -> NcResult<()> {
+    /// let nc = unsafe { Nc::new()? };
+    /// let plane = NcPlane::new_child_sized(unsafe { nc.stdplane() }, 0, 0, 20, 40)?;
+    /// let reel = NcReel::new(plane, &NcReelOptions::new())?;
+    /// for name in ["alpha", "beta"] {
+    ///     let tablet = reel.add(None, None, |tablet, _drawfromtop| {
+    ///         let name = *tablet.data::<&str>().unwrap_or(&mut "?");
+    ///         tablet.plane()?.putstr_yx(Some(0), Some(0), name)?;
+    ///         Ok(1)
+    ///     })?;
+    ///     tablet.set_data(name)?;
+    /// }
+    /// reel.redraw()?;
+    /// reel.destroy();
+    /// unsafe { nc.stop()? };
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// *C style function: [ncreel_add()][c_api::ncreel_add].*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn add<'a>(
+        &mut self,
+        after: Option<&mut NcTablet>,
+        before: Option<&mut NcTablet>,
+        draw: impl FnMut(&mut NcTablet, bool) -> NcResult<u32> + 'static,
+    ) -> NcResult<&'a mut NcTablet> {
+        let slot = closure::new_slot(self, Rc::new(RefCell::new(draw)));
+        let res = unsafe {
+            c_api::ncreel_add(
+                self,
+                after.map_or(null_mut(), |t| t),
+                before.map_or(null_mut(), |t| t),
+                Some(closure::nctabletcb_closure),
+                slot as *mut _,
+            )
+        };
+        if res.is_null() {
+            closure::free_slot(slot);
+        }
+        error_ref_mut![res, "NcReel.add()"]
+    }
+
+    /// Deletes the `tablet`.
+    ///
+    /// *C style function: [ncreel_del()][c_api::ncreel_del].*
+    pub fn del(&mut self, tablet: &mut NcTablet) -> NcResult<()> {
+        #[cfg(feature = "std")]
+        let slot = closure::slot(tablet);
+        let res = unsafe { c_api::ncreel_del(self, tablet) };
+        #[cfg(feature = "std")]
+        if let Some(slot) = slot {
+            closure::free_slot(slot);
+        }
+        error![res, "NcReel.del()"]
+    }
+
+    /// Returns the number of tablets.
+    ///
+    /// *C style function: [ncreel_tabletcount()][c_api::ncreel_tabletcount].*
+    pub fn tablet_count(&self) -> u32 {
+        unsafe { c_api::ncreel_tabletcount(self) as u32 }
+    }
+
+    /// Returns the focused tablet, if any.
+    ///
+    /// *C style function: [ncreel_focused()][c_api::ncreel_focused].*
+    pub fn focused(&mut self) -> Option<&mut NcTablet> {
+        unsafe { c_api::ncreel_focused(self).as_mut() }
+    }
+
+    /// Changes the focus to the next tablet, and returns it, if any.
+    ///
+    /// *C style function: [ncreel_next()][c_api::ncreel_next].*
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&mut NcTablet> {
+        unsafe { c_api::ncreel_next(self).as_mut() }
+    }
+
+    /// Changes the focus to the previous tablet, and returns it, if any.
+    ///
+    /// *C style function: [ncreel_prev()][c_api::ncreel_prev].*
+    pub fn prev(&mut self) -> Option<&mut NcTablet> {
+        unsafe { c_api::ncreel_prev(self).as_mut() }
+    }
+
+    /// Offers the `input` to the reel.
+    ///
+    /// If it's relevant, this function returns true, and the input ought not
+    /// be processed further. If it's irrelevant to the reel, false is returned.
+    ///
+    /// Relevant inputs include:
+    /// - a mouse click on a tablet (focuses tablet)
+    /// - a mouse scrollwheel event (rolls reel)
+    /// - up, down, pgup, or pgdown (navigates among items)
+    ///
+    /// *C style function: [ncreel_offer_input()][c_api::ncreel_offer_input].*
+    pub fn offer_input(&mut self, input: impl Into<NcInput>) -> bool {
+        unsafe { c_api::ncreel_offer_input(self, &input.into()) }
+    }
+
+    /// Returns the plane on which this reel lives.
+    ///
+    /// *C style function: [ncreel_plane()][c_api::ncreel_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncreel_plane(self) }, "NcReel.plane()"]
+    }
+
+    /// Redraws the reel in its entirety.
+    ///
+    /// The reel will be cleared, and tablets will be laid out, using the
+    /// focused tablet as a fulcrum. Tablet draw callbacks will be invoked for
+    /// each visible tablet.
+    ///
+    /// *C style function: [ncreel_redraw()][c_api::ncreel_redraw].*
+    pub fn redraw(&mut self) -> NcResult<()> {
+        error![unsafe { c_api::ncreel_redraw(self) }, "NcReel.redraw()"]
+    }
+}
+
+/// # `NcTablet` methods
+impl NcTablet {
+    /// Returns the plane of the tablet, for drawing into.
+    ///
+    /// *C style function: [nctablet_plane()][c_api::nctablet_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::nctablet_plane(self) }, "NcTablet.plane()"]
+    }
+
+    /// Sets the `data` of the tablet, replacing any previous one.
+    ///
+    /// Errors if the tablet wasn't added by [`NcReel.add`], since only those
+    /// have room for it.
+    ///
+    /// [`NcReel.add`]: NcReel#method.add
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn set_data<T: 'static>(&mut self, data: T) -> NcResult<()> {
+        let slot = closure::slot(self).ok_or_else(|| NcError::new_msg("NcTablet.set_data()"))?;
+        unsafe { (*slot).data = Some(Box::new(data)) };
+        Ok(())
+    }
+
+    /// Returns the data of the tablet, if it has been [set][Self#method.set_data]
+    /// and is of type `T`.
+    ///
+    /// It can be called from the draw closure of the tablet, to recover
+    /// its model.
+    ///
+    /// *C style function: [nctablet_userptr()][c_api::nctablet_userptr].*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn data<T: 'static>(&mut self) -> Option<&mut T> {
+        let slot = closure::slot(self)?;
+        unsafe { (*slot).data.as_mut()?.downcast_mut::<T>() }
+    }
+
+    /// Removes the data of the tablet and returns it, if it's of type `T`.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn take_data<T: 'static>(&mut self) -> Option<T> {
+        let slot = closure::slot(self)?;
+        let data = unsafe { &mut (*slot).data };
+        if data.as_ref()?.is::<T>() {
+            data.take()?.downcast::<T>().ok().map(|data| *data)
+        } else {
+            None
+        }
+    }
+}
@@ -1,7 +1,31 @@
 //! `NcReel` widget.
 
+// functions already exported by bindgen : 13
+// ------------------------------------------
+// (#) test:  0
+// (W) wrap: 13
+// ------------------------------------------
+//W  ncreel_add
+//W  ncreel_create
+//W  ncreel_del
+//W  ncreel_destroy
+//W  ncreel_focused
+//W  ncreel_next
+//W  ncreel_offer_input
+//W  ncreel_plane
+//W  ncreel_prev
+//W  ncreel_redraw
+//W  ncreel_tabletcount
+//W  nctablet_plane
+//W  nctablet_userptr
+
 use crate::c_api::ffi;
 
+mod methods;
+
+#[cfg(feature = "std")]
+mod closure;
+
 /// A wheel with [`NcTablet`]s on the outside.
 ///
 /// An `NcReel` is projected onto the 2d rendering area, showing some portion of
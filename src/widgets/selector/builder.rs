@@ -10,7 +10,29 @@ use crate::{
 
 /// A handy builder for [`NcSelector`].
 ///
-#[derive(Default, Debug)]
+/// It owns the strings of the title, the secondary title, the footer and
+/// the items, which notcurses copies when the selector is created.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let plane = NcPlane::new_child_sized(unsafe { nc.stdplane() }, 2, 2, 10, 40)?;
+/// let selector = NcSelector::builder()
+///     .title("Pick a blitter")
+///     .items([("half", "2x1"), ("quadrant", "2x2"), ("sextant", "3x2")])
+///     .default_item(1)
+///     .max_display(2)
+///     .item_channels(0x88aa00, 0x666666)
+///     .finish(plane)?;
+/// // …
+/// selector.destroy()?;
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Debug)]
 pub struct NcSelectorBuilder {
     title: Option<NcString>,
     secondary: Option<NcString>,
@@ -34,11 +56,20 @@ impl NcSelectorBuilder {
         self
     }
 
+    /// Adds several items, as pairs of option and description.
+    pub fn items<'s>(mut self, items: impl IntoIterator<Item = (&'s str, &'s str)>) -> Self {
+        self.items.extend(
+            items
+                .into_iter()
+                .map(|(o, d)| (NcString::new(o), NcString::new(d))),
+        );
+        self
+    }
+
     /// Selects the default item
     ///
-    /// It is selected at the start and must be between 0 and itemcount-1.
-    //
-    // TODO: check when finish it's n-1 at max
+    /// It is selected at the start and must be between 0 and itemcount-1,
+    /// otherwise the last item is selected.
     pub fn default_item(mut self, item: u32) -> Self {
         self.default_item = item;
         self
@@ -132,7 +163,8 @@ impl NcSelectorBuilder {
         }
         selitems.push(NcSelectorItem::new_empty());
 
-        let default_item = min(self.default_item, selitems.len() as u32 - 1);
+        // the last one is the terminating empty item
+        let default_item = min(self.default_item, (selitems.len() as u32).saturating_sub(2));
 
         let options = NcSelectorOptions::with_all_options(
             self.title.as_ref(),
@@ -4,18 +4,24 @@ use core::{
 };
 
 #[cfg(not(feature = "std"))]
-use alloc::string::{String, ToString};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 
 use crate::{
-    c_api, cstring, error, error_ref_mut, error_str,
+    c_api, cstring, error, error_ref_mut, error_str, rstring_free,
     widgets::{NcSelector, NcSelectorBuilder, NcSelectorItem, NcSelectorOptions},
     NcChannels, NcInput, NcPlane, NcResult, NcString,
 };
 
+/// # `NcSelector` constructors & destructors
 impl NcSelector {
     /// Creates a selector over a `plane`.
     ///
     /// The selector will take care of destroying the plane.
+    ///
+    /// *C style function: [ncselector_create()][c_api::ncselector_create].*
     pub fn new<'a>(plane: &mut NcPlane, options: &NcSelectorOptions) -> NcResult<&'a mut Self> {
         error_ref_mut![
             unsafe { c_api::ncselector_create(plane, options) },
@@ -28,6 +34,39 @@ impl NcSelector {
         NcSelectorBuilder::new()
     }
 
+    /// Destroys the `NcSelector`.
+    ///
+    /// Note that this also destroys the [`NcPlane`].
+    ///
+    /// *C style function: [ncselector_destroy()][c_api::ncselector_destroy].*
+    pub fn destroy(&mut self) -> NcResult<()> {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncselector_plane(self) });
+        unsafe { c_api::ncselector_destroy(self, null_mut()) };
+        Ok(())
+    }
+
+    /// Destroys the `NcSelector`, returning the last selected option,
+    /// if there was one.
+    ///
+    /// Note that this also destroys the [`NcPlane`].
+    ///
+    /// *C style function: [ncselector_destroy()][c_api::ncselector_destroy].*
+    pub fn destroy_selected(&mut self) -> Option<String> {
+        let mut item: *mut c_char = null_mut();
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncselector_plane(self) });
+        unsafe { c_api::ncselector_destroy(self, &mut item) };
+        if item.is_null() {
+            None
+        } else {
+            Some(rstring_free![item])
+        }
+    }
+}
+
+/// # `NcSelector` methods
+impl NcSelector {
     /// Offers an input to the selector.
     ///
     /// If it's relevant, this function returns true, and the input ought not be
@@ -38,46 +77,54 @@ impl NcSelector {
     /// - a mouse scrollwheel event.
     /// - a mouse click on the scrolling arrows.
     /// - up, down, pgup, or pgdown on an unrolled menu (navigates among items).
+    ///
+    /// *C style function: [ncselector_offer_input()][c_api::ncselector_offer_input].*
     pub fn offer_input(&mut self, input: impl Into<NcInput>) -> bool {
         unsafe { c_api::ncselector_offer_input(self, &input.into()) }
     }
 
-    /// Destroys the `NcSelector`.
+    /// Adds an item, with an `option` and its `desc`ription.
     ///
-    /// Note that this also destroys the [`NcPlane`].
-    //
-    // If 'item' is not NULL, the last selected option will
-    // be strdup()ed and assigned to '*item' (and must be free()d by the caller).
-    pub fn destroy(&mut self) -> NcResult<()> {
-        unsafe { c_api::ncselector_destroy(self, null_mut()) };
-        Ok(())
-    }
-
-    /// Adds an item.
-    //
-    // CHECK whether this works for multiple items.
-    pub fn additem(&mut self, item: NcSelectorItem) -> NcResult<i32> {
+    /// *C style function: [ncselector_additem()][c_api::ncselector_additem].*
+    pub fn add_item(&mut self, option: &str, desc: &str) -> NcResult<()> {
+        let (option_cs, desc_cs) = (cstring![option], cstring![desc]);
+        let item = NcSelectorItem { option: option_cs.as_ptr(), desc: desc_cs.as_ptr() };
         error![
             unsafe { c_api::ncselector_additem(self, &item) },
-            "Calling selector.additem", -1
+            &format!["NcSelector.add_item({:?}, {:?})", option, desc]
         ]
     }
 
-    /// Deletes an item.
+    /// Deletes the item with the `option`.
     ///
     /// *C style function: [ncselector_delitem()][c_api::ncselector_delitem].*
-    pub fn delitem(&mut self, item: &str) -> NcResult<i32> {
-        let cs = cstring![item];
+    pub fn del_item(&mut self, option: &str) -> NcResult<()> {
+        let cs = cstring![option];
         error![
             unsafe { c_api::ncselector_delitem(self, cs.as_ptr()) },
-            "Calling selector.delitem", -1
+            &format!["NcSelector.del_item({:?})", option]
         ]
     }
 
+    #[doc(hidden)]
+    #[deprecated = "use the `add_item` method instead."]
+    pub fn additem(&mut self, item: NcSelectorItem) -> NcResult<i32> {
+        error![
+            unsafe { c_api::ncselector_additem(self, &item) },
+            "Calling selector.additem", -1
+        ]
+    }
+
+    #[doc(hidden)]
+    #[deprecated = "use the `del_item` method instead."]
+    pub fn delitem(&mut self, item: &str) -> NcResult<i32> {
+        self.del_item(item).map(|_| -1)
+    }
+
     /// Returns the selected option if there is one.
     ///
-    /// *C style function: [ncselector_delitem()][c_api::ncselector_delitem].*
-    pub fn selected(&mut self) -> Option<String> {
+    /// *C style function: [ncselector_selected()][c_api::ncselector_selected].*
+    pub fn selected(&self) -> Option<String> {
         // MAYBE turn this into a macro (option_str![])
         let res = unsafe { c_api::ncselector_selected(self) };
         if res.is_null() {
@@ -93,18 +140,24 @@ impl NcSelector {
     //     error_ref_mut![unsafe { c_api::ncselector_plane(self) }, "Calling selector.plane"]
     // }
 
-    /// Move down in the list. A reference to the newly-selected item is
-    /// returned, or NULL if there are no items in the list.
+    /// Moves down in the list, returning the newly selected option.
+    ///
+    /// Returns an error if there are no items in the list.
+    ///
+    /// *C style function: [ncselector_nextitem()][c_api::ncselector_nextitem].*
     pub fn nextitem(&mut self) -> NcResult<String> {
         let cstr: *const c_char = unsafe { c_api::ncselector_nextitem(self) };
-        error_str![cstr, "Calling selector.nextitem"]
+        error_str![cstr, "NcSelector.nextitem()"]
     }
 
-    /// Move up in the list. A reference to the newly-selected item is
-    /// returned, or NULL if there are no items in the list.
+    /// Moves up in the list, returning the newly selected option.
+    ///
+    /// Returns an error if there are no items in the list.
+    ///
+    /// *C style function: [ncselector_previtem()][c_api::ncselector_previtem].*
     pub fn previtem(&mut self) -> NcResult<String> {
         let cstr: *const c_char = unsafe { c_api::ncselector_previtem(self) };
-        error_str![cstr, "Calling selector.previtem"]
+        error_str![cstr, "NcSelector.previtem()"]
     }
 }
 
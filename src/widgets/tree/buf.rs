@@ -0,0 +1,134 @@
+//! `NcTreeItemBuf`
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::{ffi::c_void, ptr::null_mut};
+
+use crate::{widgets::NcTreeItem, NcString};
+
+/// An owned [`NcTreeItem`], with its own label and subitems.
+///
+/// The label is used as the curry of the raw item, so it's what the item
+/// callback gets, and what [`label`] returns for the curries returned by
+/// [`focused`], [`next`], [`prev`] and [`goto`].
+///
+/// [`label`]: crate::widgets::NcTree#method.label
+/// [`focused`]: crate::widgets::NcTree#method.focused
+/// [`next`]: crate::widgets::NcTree#method.next
+/// [`prev`]: crate::widgets::NcTree#method.prev
+/// [`goto`]: crate::widgets::NcTree#method.goto
+#[derive(Clone, Debug)]
+pub struct NcTreeItemBuf {
+    label: NcString,
+    subs: Vec<NcTreeItemBuf>,
+}
+
+/// # `NcTreeItemBuf` Constructors
+impl NcTreeItemBuf {
+    /// New item with a `label` and no subitems.
+    pub fn new(label: impl AsRef<str>) -> Self {
+        Self { label: NcString::new(label.as_ref()), subs: Vec::new() }
+    }
+
+    /// Adds a subitem (chainable).
+    pub fn sub_item(mut self, item: NcTreeItemBuf) -> Self {
+        self.subs.push(item);
+        self
+    }
+
+    /// Adds several subitems (chainable).
+    pub fn sub_items(mut self, items: impl IntoIterator<Item = NcTreeItemBuf>) -> Self {
+        self.subs.extend(items);
+        self
+    }
+}
+
+/// # `NcTreeItemBuf` Methods
+impl NcTreeItemBuf {
+    /// Returns the label.
+    pub fn label(&self) -> &str {
+        self.label.as_str()
+    }
+
+    /// Returns the subitems.
+    pub fn subs(&self) -> &[NcTreeItemBuf] {
+        &self.subs
+    }
+
+    /// Returns the subitems, mutably.
+    pub fn subs_mut(&mut self) -> &mut Vec<NcTreeItemBuf> {
+        &mut self.subs
+    }
+
+    /// Adds a subitem.
+    pub fn push(&mut self, item: NcTreeItemBuf) {
+        self.subs.push(item);
+    }
+
+    /// Returns the raw `items`, which borrow the labels, and whose subitems
+    /// live in the `arrays`.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub(crate) fn raw_items(
+        items: &[NcTreeItemBuf],
+        arrays: &mut Vec<Vec<NcTreeItem>>,
+    ) -> Vec<NcTreeItem> {
+        items
+            .iter()
+            .map(|item| {
+                let mut subs = Self::raw_items(&item.subs, arrays);
+                let raw = NcTreeItem {
+                    curry: item.label.as_ptr() as *mut c_void,
+                    subs: if subs.is_empty() { null_mut() } else { subs.as_mut_ptr() },
+                    subcount: subs.len() as u32,
+                };
+                // moving the vector doesn't move its buffer
+                arrays.push(subs);
+                raw
+            })
+            .collect()
+    }
+
+    /// Appends the addresses of the labels of the `items` and all their
+    /// subitems to `curries`.
+    #[cfg(feature = "std")]
+    pub(crate) fn curries(items: &[NcTreeItemBuf], curries: &mut Vec<usize>) {
+        for item in items {
+            curries.push(item.label.as_ptr() as usize);
+            Self::curries(&item.subs, curries);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NcTreeItemBuf;
+    use core::ffi::CStr;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    #[test]
+    fn tree_item_buf() {
+        let items = [
+            NcTreeItemBuf::new("src")
+                .sub_item(NcTreeItemBuf::new("lib.rs"))
+                .sub_item(NcTreeItemBuf::new("widgets").sub_item(NcTreeItemBuf::new("tree.rs"))),
+            NcTreeItemBuf::new("README.md"),
+        ];
+        assert_eq!["widgets", items[0].subs()[1].label()];
+
+        let mut arrays = vec![];
+        let raw = NcTreeItemBuf::raw_items(&items, &mut arrays);
+        assert_eq![(2, 0), (raw[0].subcount, raw[1].subcount)];
+        assert![raw[1].subs.is_null()];
+
+        let widgets = unsafe { &*raw[0].subs.add(1) };
+        assert_eq![1, widgets.subcount];
+        let tree = unsafe { &*widgets.subs };
+        assert_eq![
+            Ok("tree.rs"),
+            unsafe { CStr::from_ptr(tree.curry as *const _) }.to_str()
+        ];
+    }
+}
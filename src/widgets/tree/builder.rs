@@ -0,0 +1,120 @@
+//! `NcTreeBuilder`
+
+use core::cell::RefCell;
+use std::{rc::Rc, vec::Vec};
+
+use crate::{
+    widgets::{tree::closure, NcTree, NcTreeItemBuf, NcTreeOptions},
+    NcError, NcPlane, NcResult,
+};
+
+/// A handy builder for [`NcTree`], from an [`NcTreeItemBuf`] hierarchy.
+///
+/// The tree keeps the items alive until it's [`destroy`]ed.
+///
+/// By default each item just prints its label. An [`on_item`] closure can
+/// draw it instead. Items added later with [`NcTree.add`] aren't drawn.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let plane = NcPlane::new_child_sized(unsafe { nc.stdplane() }, 0, 0, 20, 40)?;
+/// let tree = NcTree::builder()
+///     .item(
+///         NcTreeItemBuf::new("src")
+///             .sub_item(NcTreeItemBuf::new("lib.rs"))
+///             .sub_item(NcTreeItemBuf::new("main.rs")),
+///     )
+///     .item(NcTreeItemBuf::new("Cargo.toml"))
+///     .indent_cols(2)
+///     .on_item(|plane, label, pos| {
+///         let mark = if pos == 0 { '>' } else { ' ' };
+///         plane.putstr_yx(Some(0), Some(0), &format!["{mark} {label}"])?;
+///         Ok(())
+///     })
+///     .finish(plane)?;
+/// let curry = tree.next()?;
+/// assert_eq![Some("lib.rs"), tree.label(curry)];
+/// tree.destroy();
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`destroy`]: NcTree#method.destroy
+/// [`NcTree.add`]: NcTree#method.add
+/// [`on_item`]: NcTreeBuilder#method.on_item
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+#[derive(Clone, Default)]
+pub struct NcTreeBuilder {
+    items: Vec<NcTreeItemBuf>,
+    indent_cols: u32,
+    on_item: Option<closure::NcTreeItemClosure>,
+}
+
+impl NcTreeBuilder {
+    /// New `NcTreeBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a top-level item.
+    pub fn item(mut self, item: NcTreeItemBuf) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Adds several top-level items.
+    pub fn items(mut self, items: impl IntoIterator<Item = NcTreeItemBuf>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Sets the columns to indent per level of hierarchy.
+    ///
+    /// Default: *`0`*.
+    pub fn indent_cols(mut self, cols: u32) -> Self {
+        self.indent_cols = cols;
+        self
+    }
+
+    /// Sets a closure for drawing each visible item.
+    ///
+    /// It receives the plane of the item, its label and its position
+    /// relative to the focused item, which is at 0.
+    pub fn on_item(
+        mut self,
+        closure: impl FnMut(&mut NcPlane, &str, i32) -> NcResult<()> + 'static,
+    ) -> Self {
+        self.on_item = Some(Rc::new(RefCell::new(closure)));
+        self
+    }
+
+    /// Finishes the builder and returns the `NcTree`.
+    ///
+    /// Errors if there are no items.
+    pub fn finish<'a>(self, plane: &mut NcPlane) -> NcResult<&'a mut NcTree> {
+        if self.items.is_empty() {
+            return Err(NcError::new_msg("NcTreeBuilder.finish(): no items"));
+        }
+
+        let mut arrays = Vec::new();
+        let items = NcTreeItemBuf::raw_items(&self.items, &mut arrays);
+        let mut options = NcTreeOptions::new(&items, self.indent_cols);
+        options.nctreecb = Some(closure::nctreecb_closure);
+
+        let curries = closure::set(&self.items, self.on_item);
+        match NcTree::new(plane, options) {
+            Ok(tree) => {
+                closure::insert(tree, self.items, curries);
+                Ok(tree)
+            }
+            Err(e) => {
+                closure::unset(&curries);
+                Err(e)
+            }
+        }
+    }
+}
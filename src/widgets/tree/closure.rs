@@ -0,0 +1,118 @@
+//! Side tables of the item closures and the items of the trees created by an
+//! [`NcTreeBuilder`][crate::widgets::NcTreeBuilder].
+//!
+//! The closures are keyed by the address of the label of each item, which is
+//! the curry [`nctreecb_closure`] gets, since notcurses can already call it
+//! while creating the tree. Only the curries found there are known to be
+//! labels, since items with any other curry can be added to the tree later.
+
+use core::ffi::{c_char, c_int, c_void, CStr};
+
+use crate::{
+    c_api::{NCRESULT_ERR, NCRESULT_OK},
+    error::catch_panic,
+    widgets::{NcTree, NcTreeItemBuf},
+    NcPlane, NcResult,
+};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc, vec::Vec};
+
+/// An item closure, shareable between builders.
+pub(crate) type NcTreeItemClosure = Rc<RefCell<dyn FnMut(&mut NcPlane, &str, i32) -> NcResult<()>>>;
+
+/// What a tree needs to keep alive.
+struct TreeDef {
+    _items: Vec<NcTreeItemBuf>,
+    curries: Vec<usize>,
+}
+
+thread_local! {
+    static CLOSURES: RefCell<BTreeMap<usize, Option<NcTreeItemClosure>>> =
+        RefCell::new(BTreeMap::new());
+    static TREES: RefCell<BTreeMap<usize, TreeDef>> = RefCell::new(BTreeMap::new());
+}
+
+/// Sets the `closure` for all the `items`, returning their curries.
+///
+/// Without a closure the items just print their label.
+pub(crate) fn set(items: &[NcTreeItemBuf], closure: Option<NcTreeItemClosure>) -> Vec<usize> {
+    let mut curries = Vec::new();
+    NcTreeItemBuf::curries(items, &mut curries);
+    CLOSURES.with(|c| {
+        let mut c = c.borrow_mut();
+        for &curry in &curries {
+            c.insert(curry, closure.clone());
+        }
+    });
+    curries
+}
+
+/// Forgets the closures of the `curries`.
+pub(crate) fn unset(curries: &[usize]) {
+    CLOSURES.with(|c| {
+        let mut c = c.borrow_mut();
+        for curry in curries {
+            c.remove(curry);
+        }
+    });
+}
+
+/// Keeps the `items` alive for as long as the `tree`.
+pub(crate) fn insert(tree: *const NcTree, items: Vec<NcTreeItemBuf>, curries: Vec<usize>) {
+    TREES.with(|t| {
+        t.borrow_mut()
+            .insert(tree as usize, TreeDef { _items: items, curries })
+    });
+}
+
+/// Returns `true` if the `curry` is the label of an item of the `tree`.
+pub(crate) fn is_label(tree: *const NcTree, curry: *mut c_void) -> bool {
+    TREES.with(|t| {
+        t.borrow()
+            .get(&(tree as usize))
+            .map_or(false, |def| def.curries.contains(&(curry as usize)))
+    })
+}
+
+/// Forgets the items and closures of the `tree`, if any.
+pub(crate) fn remove(tree: *const NcTree) {
+    if let Some(def) = TREES.with(|t| t.borrow_mut().remove(&(tree as usize))) {
+        unset(&def.curries);
+    }
+}
+
+/// The C item callback that calls the closure of the item.
+///
+/// The closure is called outside of the side table borrow. A closure that is
+/// already running is not called, and one that panics fails.
+///
+/// Items whose curry is not a known label are not drawn.
+pub(crate) unsafe extern "C" fn nctreecb_closure(
+    plane: *mut NcPlane,
+    curry: *mut c_void,
+    pos: c_int,
+) -> c_int {
+    let closure = match CLOSURES.with(|c| c.borrow().get(&(curry as usize)).cloned()) {
+        Some(closure) => closure,
+        None => return NCRESULT_OK,
+    };
+    // the label is alive, since it's only forgotten along with its tree
+    let label = match CStr::from_ptr(curry as *const c_char).to_str() {
+        Ok(label) => label,
+        Err(_) => return NCRESULT_ERR,
+    };
+    let res = match closure {
+        Some(closure) => match closure.try_borrow_mut() {
+            Ok(mut closure) => catch_panic("NcTree item", || closure(&mut *plane, label, pos)),
+            Err(_) => return NCRESULT_ERR,
+        },
+        // a label wider than the plane is just truncated
+        None => {
+            let _ = (*plane).putstr_yx(Some(0), Some(0), label);
+            Ok(())
+        }
+    };
+    match res {
+        Ok(()) => NCRESULT_OK,
+        Err(_) => NCRESULT_ERR,
+    }
+}
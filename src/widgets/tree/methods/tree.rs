@@ -1,10 +1,20 @@
+use core::ffi::c_void;
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
 use crate::{
-    c_api::{self, nctree_create, NCRESULT_ERR},
+    c_api::{self, nctree_create},
     error, error_ref_mut,
     widgets::{NcTree, NcTreeItem, NcTreeOptions},
     NcError, NcInput, NcPlane, NcResult,
 };
 
+#[cfg(feature = "std")]
+use crate::widgets::{tree::closure, NcTreeBuilder};
+#[cfg(feature = "std")]
+use core::ffi::{c_char, CStr};
+
 /// # `NcTree` constructors & destructors
 impl NcTree {
     /// Creates an [NcTree] with the specified options.
@@ -14,36 +24,47 @@ impl NcTree {
         error_ref_mut![unsafe { nctree_create(plane, &options) }, "Creating NcTree"]
     }
 
-    /// Destroys an NcTree created with [new()][NcTree#method.new].
+    /// Starts the builder.
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn builder() -> NcTreeBuilder {
+        NcTreeBuilder::new()
+    }
+
+    /// Destroys an NcTree created with [new()][NcTree#method.new]
+    /// or with an [`NcTreeBuilder`][crate::widgets::NcTreeBuilder].
     ///
     /// *C style function: [nctree_destroy()][c_api::nctree_destroy].*
     pub fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::nctree_plane(self) });
         unsafe { c_api::nctree_destroy(self) };
+        #[cfg(feature = "std")]
+        closure::remove(self);
     }
 }
 
 /// # `NcTree` methods
 impl NcTree {
-    // NOTE: not implemented yet in C API
-    //
-    // /// Goes to the item specified by the array |spec|, terminated by UINT_MAX.
-    // ///
-    // /// If the spec is invalid, returns an error and the depth of the first
-    // /// invalid spec is written to *|failspec|.
-    // ///
-    // /// Otherwise, the true depth is written to *|failspec|,
-    // /// and the curry is returned (|failspec| is necessary because the
-    // /// curry could itself be NULL).
-    // ///
-    // /// *C style function: [nctree_goto()][c_api::nctree_goto].*
-    // pub fn goto(&mut self, spec: ... , failspec: ...) -> NcResult<&mut NcTreeItem> {
-    //     let res = unsafe { c_api::nctree_goto(self) };
-    //     if !res.is_null() {
-    //         Ok(unsafe { &mut *(res as *mut NcTreeItem) })
-    //     } else {
-    //         Err(NcError::with_msg(NCRESULT_ERR, "NcTree.goto()"))
-    //     }
-    // }
+    /// Goes to the item at `spec`, and returns its curry.
+    ///
+    /// The `spec` is a path of indices, each one identifying a subitem of the
+    /// previous one, starting with the top-level items.
+    ///
+    /// If the spec is invalid the error message includes the depth of the
+    /// first invalid index. A null curry is also returned as an error.
+    ///
+    /// *C style function: [nctree_goto()][c_api::nctree_goto].*
+    pub fn goto(&mut self, spec: &[u32]) -> NcResult<*mut c_void> {
+        let mut path: Vec<u32> = spec.to_vec();
+        path.push(u32::MAX);
+        let mut failspec = 0;
+        let res = unsafe { c_api::nctree_goto(self, path.as_ptr(), &mut failspec) };
+        non_null(
+            res,
+            &format!["NcTree.goto({:?}): invalid at depth {}", spec, failspec],
+        )
+    }
 
     /// Inserts `item` at `spec`.
     ///
@@ -62,43 +83,70 @@ impl NcTree {
         error![unsafe { c_api::nctree_del(self, spec) }]
     }
 
-    /// Returns the focused item, if any items are present.
+    /// Returns the curry of the focused item, if any items are present.
+    ///
+    /// A null curry is also returned as an error.
     ///
-    /// This is not a copy; be careful to use it only for the duration of a
-    /// critical section.
+    /// The labels of the items of an [`NcTreeBuilder`] can be got back with
+    /// [`label`][NcTree#method.label].
     ///
     /// *C style function: [nctree_focused()][c_api::nctree_focused].*
-    pub fn focused(&mut self) -> NcResult<&mut NcTreeItem> {
-        let res = unsafe { c_api::nctree_focused(self) };
-        if !res.is_null() {
-            Ok(unsafe { &mut *(res as *mut NcTreeItem) })
-        } else {
-            Err(NcError::with_msg(NCRESULT_ERR, "NcTree.focused()"))
-        }
+    ///
+    /// [`NcTreeBuilder`]: crate::widgets::NcTreeBuilder
+    pub fn focused(&mut self) -> NcResult<*mut c_void> {
+        non_null(unsafe { c_api::nctree_focused(self) }, "NcTree.focused()")
     }
 
-    /// Changes the focus to the next item, and returns it.
+    /// Changes the focus to the next item, and returns its curry.
+    ///
+    /// See [`focused`][NcTree#method.focused].
     ///
     /// *C style function: [nctree_next()][c_api::nctree_next].*
     #[allow(clippy::should_implement_trait)]
-    pub fn next(&mut self) -> NcResult<&mut NcTreeItem> {
-        let res = unsafe { c_api::nctree_next(self) };
-        if !res.is_null() {
-            Ok(unsafe { &mut *(res as *mut NcTreeItem) })
-        } else {
-            Err(NcError::with_msg(NCRESULT_ERR, "NcTree.next()"))
-        }
+    pub fn next(&mut self) -> NcResult<*mut c_void> {
+        non_null(unsafe { c_api::nctree_next(self) }, "NcTree.next()")
     }
 
-    /// Changes the focus to the previous item, and returns it.
+    /// Changes the focus to the previous item, and returns its curry.
+    ///
+    /// See [`focused`][NcTree#method.focused].
     ///
     /// *C style function: [nctree_prev()][c_api::nctree_prev].*
-    pub fn prev(&mut self) -> NcResult<&mut NcTreeItem> {
-        let res = unsafe { c_api::nctree_prev(self) };
-        if !res.is_null() {
-            Ok(unsafe { &mut *(res as *mut NcTreeItem) })
+    pub fn prev(&mut self) -> NcResult<*mut c_void> {
+        non_null(unsafe { c_api::nctree_prev(self) }, "NcTree.prev()")
+    }
+
+    /// Returns the label of the item with the `curry`, if it's one of the
+    /// items this tree was built with by an [`NcTreeBuilder`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use libnotcurses_sys::{*, widgets::*};
+    /// # fn main() -> NcResult<()> {
+    /// # let nc = unsafe { Nc::new()? };
+    /// # let plane = unsafe { nc.stdplane() };
+    /// let tree = NcTree::builder().item(NcTreeItemBuf::new("a")).finish(plane)?;
+    /// let curry = tree.focused()?;
+    /// assert_eq![Some("a"), tree.label(curry)];
+    /// # tree.destroy();
+    /// # unsafe { nc.stop()? };
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`NcTreeBuilder`]: crate::widgets::NcTreeBuilder
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn label(&self, curry: *mut c_void) -> Option<&str> {
+        if closure::is_label(self, curry) {
+            // the label lives as long as the tree
+            unsafe { CStr::from_ptr(curry as *const c_char) }
+                .to_str()
+                .ok()
         } else {
-            Err(NcError::with_msg(NCRESULT_ERR, "NcTree.prev()"))
+            None
         }
     }
 
@@ -138,3 +186,12 @@ impl NcTree {
         error![unsafe { c_api::nctree_redraw(self) }, "NcTree.redraw()"]
     }
 }
+
+/// Returns the `curry`, erroring with `msg` if it's null.
+fn non_null(curry: *mut c_void, msg: &str) -> NcResult<*mut c_void> {
+    if curry.is_null() {
+        Err(NcError::new_msg(msg))
+    } else {
+        Ok(curry)
+    }
+}
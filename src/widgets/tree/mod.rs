@@ -3,14 +3,14 @@
 // functions already exported by bindgen : 11
 // ------------------------------------------
 // (#) test:  0
-// (W) wrap: 10
+// (W) wrap: 11
 // ------------------------------------------
 //W  nctree_add
 //W  nctree_create
 //W  nctree_destroy
 //W  nctree_del
 //W  nctree_focused
-//~  nctree_goto
+//W  nctree_goto
 //W  nctree_next
 //W  nctree_offer_input
 //W  nctree_plane
@@ -21,8 +21,18 @@ use core::ffi::{c_int, c_void};
 
 use crate::{c_api::ffi, NcPlane};
 
+mod buf;
 mod methods;
 
+#[cfg(feature = "std")]
+mod builder;
+#[cfg(feature = "std")]
+mod closure;
+
+pub use buf::NcTreeItemBuf;
+#[cfg(feature = "std")]
+pub use builder::NcTreeBuilder;
+
 /// High-level hierarchical line-based data.
 ///
 /// `NcTree`s organize static hierarchical items, and allow them to be browsed.
@@ -0,0 +1,185 @@
+//! `NcWidget` trait.
+
+use core::ptr::null_mut;
+
+use crate::{
+    c_api, error_ref_mut,
+    widgets::{
+        NcMenu, NcMultiSelector, NcPlotF64, NcPlotU64, NcProgBar, NcReader, NcReel, NcSelector,
+        NcTabbed, NcTree,
+    },
+    NcInput, NcPlane, NcResult,
+};
+
+/// The common interface of the widgets.
+///
+/// It allows storing different widgets together, and routing the input
+/// to them generically.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let stdplane = unsafe { nc.stdplane() };
+/// let selector = NcSelector::builder()
+///     .item("a", "first")
+///     .finish(NcPlane::new_child_sized(stdplane, 0, 0, 10, 20)?)?;
+/// let progbar = NcProgBar::new(NcPlane::new_child_sized(stdplane, 11, 0, 1, 20)?)?;
+///
+/// let mut widgets: [&mut dyn NcWidget; 2] = [selector, progbar];
+/// let mut input = NcInput::new_empty();
+/// nc.get_blocking(Some(&mut input))?;
+/// let consumed = widgets.iter_mut().any(|w| w.offer_input(&input));
+/// for widget in widgets.iter_mut() {
+///     widget.destroy();
+/// }
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+pub trait NcWidget {
+    /// Returns the plane the widget lives on.
+    fn plane(&mut self) -> NcResult<&mut NcPlane>;
+
+    /// Offers the `input` to the widget.
+    ///
+    /// Returns `true` if it was relevant, and so it ought not be processed
+    /// further. Widgets that don't take input always return `false`.
+    fn offer_input(&mut self, input: &NcInput) -> bool;
+
+    /// Destroys the widget, and its plane.
+    ///
+    /// It must not be used afterwards.
+    fn destroy(&mut self);
+}
+
+impl NcWidget for NcMenu {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncmenu_plane(self) }, "NcMenu.plane()"]
+    }
+    fn offer_input(&mut self, input: &NcInput) -> bool {
+        unsafe { c_api::ncmenu_offer_input(self, input) }
+    }
+    fn destroy(&mut self) {
+        NcMenu::destroy(self);
+    }
+}
+
+impl NcWidget for NcSelector {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![
+            unsafe { c_api::ncselector_plane(self) },
+            "NcSelector.plane()"
+        ]
+    }
+    fn offer_input(&mut self, input: &NcInput) -> bool {
+        unsafe { c_api::ncselector_offer_input(self, input) }
+    }
+    fn destroy(&mut self) {
+        let _ = NcSelector::destroy(self);
+    }
+}
+
+impl NcWidget for NcMultiSelector {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![
+            unsafe { c_api::ncmultiselector_plane(self) },
+            "NcMultiSelector.plane()"
+        ]
+    }
+    fn offer_input(&mut self, input: &NcInput) -> bool {
+        unsafe { c_api::ncmultiselector_offer_input(self, input) }
+    }
+    fn destroy(&mut self) {
+        NcMultiSelector::destroy(self);
+    }
+}
+
+impl NcWidget for NcReader {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncreader_plane(self) }, "NcReader.plane()"]
+    }
+    fn offer_input(&mut self, input: &NcInput) -> bool {
+        unsafe { c_api::ncreader_offer_input(self, input) }
+    }
+    fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::ncreader_plane(self) });
+        unsafe { c_api::ncreader_destroy(self, null_mut()) };
+    }
+}
+
+impl NcWidget for NcPlotU64 {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncuplot_plane(self) }, "NcPlotU64.plane()"]
+    }
+    fn offer_input(&mut self, _input: &NcInput) -> bool {
+        false
+    }
+    fn destroy(&mut self) {
+        NcPlotU64::destroy(self);
+    }
+}
+
+impl NcWidget for NcPlotF64 {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncdplot_plane(self) }, "NcPlotF64.plane()"]
+    }
+    fn offer_input(&mut self, _input: &NcInput) -> bool {
+        false
+    }
+    fn destroy(&mut self) {
+        NcPlotF64::destroy(self);
+    }
+}
+
+impl NcWidget for NcReel {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        NcReel::plane(self)
+    }
+    fn offer_input(&mut self, input: &NcInput) -> bool {
+        unsafe { c_api::ncreel_offer_input(self, input) }
+    }
+    fn destroy(&mut self) {
+        NcReel::destroy(self);
+    }
+}
+
+impl NcWidget for NcTree {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::nctree_plane(self) }, "NcTree.plane()"]
+    }
+    fn offer_input(&mut self, input: &NcInput) -> bool {
+        unsafe { c_api::nctree_offer_input(self, input) }
+    }
+    fn destroy(&mut self) {
+        NcTree::destroy(self);
+    }
+}
+
+impl NcWidget for NcTabbed {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::nctabbed_plane(self) }, "NcTabbed.plane()"]
+    }
+    fn offer_input(&mut self, _input: &NcInput) -> bool {
+        false
+    }
+    fn destroy(&mut self) {
+        #[cfg(feature = "std")]
+        crate::plane::side_table::forget(unsafe { c_api::nctabbed_plane(self) });
+        unsafe { c_api::nctabbed_destroy(self) };
+    }
+}
+
+impl NcWidget for NcProgBar {
+    fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncprogbar_plane(self) }, "NcProgBar.plane()"]
+    }
+    fn offer_input(&mut self, _input: &NcInput) -> bool {
+        false
+    }
+    fn destroy(&mut self) {
+        NcProgBar::destroy(self);
+    }
+}